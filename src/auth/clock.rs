@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+/**
+ * Source of timestamps for signed API requests.
+ *
+ * Signed requests carry a `timestamp` parameter that Binance checks against
+ * `recvWindow`. Injecting a clock makes signed requests reproducible in tests.
+ */
+pub trait Clock: Send + Sync + std::fmt::Debug {
+    /**
+     * Returns the current time.
+     *
+     * # Returns
+     * - `u64`: Milliseconds since the Unix epoch.
+     */
+    fn now_millis(&self) -> u64;
+}
+
+/**
+ * Clock backed by the system time.
+ */
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+}
+
+/**
+ * Clock frozen at a fixed time, for deterministic tests.
+ *
+ * # Fields
+ * - `0`: Milliseconds since the Unix epoch returned by every call.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_millis(&self) -> u64 {
+        self.0
+    }
+}
+
+/**
+ * Clock shifted by a fixed offset from another clock.
+ *
+ * Used to align request timestamps with Binance server time when the local
+ * clock drifts.
+ *
+ * # Fields
+ * - `clock`: Underlying clock.
+ * - `offset_ms`: Milliseconds added to every reading of `clock`.
+ */
+#[derive(Debug, Clone)]
+pub struct OffsetClock {
+    clock: Arc<dyn Clock>,
+    offset_ms: i64,
+}
+
+impl OffsetClock {
+    /**
+     * Creates a clock shifted from another one.
+     *
+     * # Arguments
+     * - `clock`: Underlying clock.
+     * - `offset_ms`: Milliseconds to add; negative values move the clock back.
+     *
+     * # Returns
+     * - New OffsetClock instance.
+     */
+    pub fn new(clock: Arc<dyn Clock>, offset_ms: i64) -> Self {
+        Self { clock, offset_ms }
+    }
+
+    /**
+     * Gets the offset applied to the underlying clock.
+     *
+     * # Returns
+     * - `i64`: Offset in milliseconds.
+     */
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now_millis(&self) -> u64 {
+        self.clock
+            .now_millis()
+            .saturating_add_signed(self.offset_ms)
+    }
+}
@@ -1,5 +1,7 @@
+mod clock;
 mod ed25519_signer;
 mod signature_provider;
 
+pub use clock::{Clock, FixedClock, OffsetClock, SystemClock};
 pub use ed25519_signer::Ed25519Signer;
 pub use signature_provider::SignatureProvider;
@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+use crate::Result;
+use crate::clients::r#trait::AccountClient;
+use crate::types::requests::AccountInfoSpec;
+use crate::types::responses::{AccountInfo, Balance};
+
+/**
+ * Caches account snapshots to serve single-asset balance lookups.
+ *
+ * Binance has no endpoint for the balance of a single asset, so every lookup
+ * would otherwise fetch the full account. The cache keeps the last account
+ * snapshot and only refreshes it once it is older than the configured TTL.
+ *
+ * # Fields
+ * - `client`: Client used to fetch account information.
+ * - `ttl`: Maximum age of a snapshot before it is refreshed.
+ * - `snapshot`: Last fetched account information and the time it was fetched.
+ */
+pub struct BalanceCache<C> {
+    client: C,
+    ttl: Duration,
+    snapshot: Mutex<Option<(Instant, AccountInfo)>>,
+}
+
+impl<C: AccountClient> BalanceCache<C> {
+    /**
+     * Creates an empty balance cache.
+     *
+     * # Arguments
+     * - `client`: Client used to fetch account information.
+     * - `ttl`: Maximum age of a snapshot before it is refreshed.
+     *
+     * # Returns
+     * - New BalanceCache instance.
+     */
+    pub fn new(client: C, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            snapshot: Mutex::new(None),
+        }
+    }
+
+    /**
+     * Gets the balance of a single asset, refreshing the snapshot when stale.
+     *
+     * # Arguments
+     * - `asset`: Asset symbol to look up (e.g., "BTC").
+     *
+     * # Returns
+     * - `Option<Balance>`: Balance of the asset, or None if the account does not hold it.
+     */
+    pub async fn balance(&self, asset: &str) -> Result<Option<Balance>> {
+        let mut snapshot = self.snapshot.lock().await;
+
+        let is_fresh = snapshot
+            .as_ref()
+            .is_some_and(|(fetched_at, _)| fetched_at.elapsed() < self.ttl);
+        if !is_fresh {
+            let account = self
+                .client
+                .account_info_with(AccountInfoSpec::new().build()?)
+                .await?;
+            *snapshot = Some((Instant::now(), account));
+        }
+
+        Ok(snapshot.as_ref().and_then(|(_, account)| {
+            account
+                .balances
+                .iter()
+                .find(|balance| balance.asset == asset)
+                .cloned()
+        }))
+    }
+
+    /**
+     * Discards the cached snapshot so the next lookup refreshes it.
+     */
+    pub async fn invalidate(&self) {
+        *self.snapshot.lock().await = None;
+    }
+
+    /**
+     * Gets the wrapped client.
+     *
+     * # Returns
+     * - `&C`: Client used to fetch account information.
+     */
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::clients::tests::helpers::{create_mock_rest_client, spawn_sequenced_mock_server};
+
+    const ACCOUNT_V1: &str = r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":123456789,"accountType":"SPOT","balances":[{"asset":"BTC","free":"1.00000000","locked":"0.00000000"},{"asset":"USDT","free":"100.00000000","locked":"5.00000000"}],"permissions":["SPOT"],"uid":354937868}"#;
+    const ACCOUNT_V2: &str = r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":123456999,"accountType":"SPOT","balances":[{"asset":"BTC","free":"2.00000000","locked":"0.00000000"}],"permissions":["SPOT"],"uid":354937868}"#;
+
+    /**
+     * Tests that a fresh snapshot serves lookups without another request.
+     */
+    #[tokio::test]
+    async fn test_balance_cache_hit() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![(200, ACCOUNT_V1)]).await;
+        let cache = BalanceCache::new(create_mock_rest_client(&url), Duration::from_secs(60));
+
+        // Act
+        let btc = cache.balance("BTC").await.unwrap().unwrap();
+        let usdt = cache.balance("USDT").await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(btc.free, dec!(1));
+        assert_eq!(usdt.locked, dec!(5));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    /**
+     * Tests that an asset missing from the account returns None.
+     */
+    #[tokio::test]
+    async fn test_balance_cache_miss() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![(200, ACCOUNT_V1)]).await;
+        let cache = BalanceCache::new(create_mock_rest_client(&url), Duration::from_secs(60));
+
+        // Act
+        let balance = cache.balance("ETH").await.unwrap();
+
+        // Assert
+        assert!(balance.is_none());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert!(requests.lock().unwrap()[0].starts_with("GET /api/v3/account"));
+    }
+
+    /**
+     * Tests that a stale snapshot is refreshed on the next lookup.
+     */
+    #[tokio::test]
+    async fn test_balance_cache_refreshes_when_stale() {
+        // Arrange
+        let (url, requests) =
+            spawn_sequenced_mock_server(vec![(200, ACCOUNT_V1), (200, ACCOUNT_V2)]).await;
+        let cache = BalanceCache::new(create_mock_rest_client(&url), Duration::from_millis(50));
+
+        // Act
+        let before = cache.balance("BTC").await.unwrap().unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let after = cache.balance("BTC").await.unwrap().unwrap();
+
+        // Assert
+        assert_eq!(before.free, dec!(1));
+        assert_eq!(after.free, dec!(2));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
@@ -1,8 +1,9 @@
 use anyhow::Context;
 use serde::Serialize;
 
+use crate::BinanceConfig;
 use crate::Result;
-use crate::auth::SignatureProvider;
+use crate::auth::{Clock, SignatureProvider};
 
 /**
  * Generates timestamp and signature for authenticated API requests.
@@ -10,7 +11,10 @@ use crate::auth::SignatureProvider;
  * # Arguments
  * - `params`: Serializable parameters for the request.
  * - `signer`: Signature provider for generating Ed25519 signatures.
- * - `recv_window`: Request timing window in milliseconds.
+ * - `clock`: Source of the request timestamp, used unless `params` already
+ *   carries a `timestamp`.
+ * - `recv_window`: Default request timing window in milliseconds, used unless
+ *   `params` already carries a `recvWindow`.
  * - `include_api_key`: Whether to include the API key in the signature.
  *
  * # Returns
@@ -19,14 +23,10 @@ use crate::auth::SignatureProvider;
 pub async fn generate_signature<T: Serialize>(
     params: &T,
     signer: &dyn SignatureProvider,
+    clock: &dyn Clock,
     recv_window: u64,
     include_api_key: bool,
 ) -> Result<(String, String)> {
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_millis() as u64;
-
     let params_query =
         serde_urlencoded::to_string(params).context("Failed to serialize parameters")?;
 
@@ -44,8 +44,12 @@ pub async fn generate_signature<T: Serialize>(
         all_params.insert("apiKey".to_string(), signer.get_api_key().to_string());
     }
 
-    all_params.insert("timestamp".to_string(), timestamp.to_string());
-    all_params.insert("recvWindow".to_string(), recv_window.to_string());
+    all_params
+        .entry("timestamp".to_string())
+        .or_insert_with(|| clock.now_millis().to_string());
+    all_params
+        .entry("recvWindow".to_string())
+        .or_insert_with(|| recv_window.to_string());
 
     let signature_payload = all_params
         .iter()
@@ -56,3 +60,18 @@ pub async fn generate_signature<T: Serialize>(
     let signature = signer.sign(&signature_payload).await?;
     Ok((signature, signature_payload))
 }
+
+/**
+ * Records a fresh correlation id on the current request span.
+ *
+ * The span must declare an empty `correlation_id` field; nothing is recorded
+ * when no correlation id provider is configured.
+ *
+ * # Arguments
+ * - `config`: Client configuration holding the correlation id provider.
+ */
+pub fn record_correlation_id<T>(config: &BinanceConfig<T>) {
+    if let Some(correlation_id) = config.correlation_id() {
+        tracing::Span::current().record("correlation_id", correlation_id.as_str());
+    }
+}
@@ -1,5 +1,14 @@
+mod balance_cache;
 mod common;
+mod paper_trading;
+mod rate_limiter;
 pub mod rest;
-mod tests;
+mod symbol_info_cache;
+pub(crate) mod tests;
 pub mod r#trait;
 pub mod websocket;
+
+pub use balance_cache::BalanceCache;
+pub use paper_trading::PaperTradingClient;
+pub use rate_limiter::RequestWeightLimiter;
+pub use symbol_info_cache::SymbolInfoCache;
@@ -0,0 +1,1044 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+
+use crate::Result;
+use crate::clients::r#trait::TradingClient;
+use crate::enums::{OrderSide, OrderStatus, OrderType, TimeInForce};
+use crate::streams::events::TradeStreamEvent;
+use crate::types::requests::{
+    AllOrderListsSpec, AmendOrderSpec, CancelAllOrdersSpec, CancelOrderListSpec, CancelOrderSpec,
+    CancelReplaceSpec, OcoOrderSpec, OpenOrderListsSpec, OrderListStatusSpec, OrderSpec,
+    OtoOrderSpec, OtocoOrderSpec, SorOrderSpec, Validated,
+};
+use crate::types::responses::{
+    AmendedOrder, CancelReplaceOrder, CancelledOrder, Fill, Order, OrderBook, OrderList, TestOrder,
+};
+
+/**
+ * Simulated trading client matching orders against local market data.
+ *
+ * Implements `TradingClient` with the same specification and response types as
+ * the REST and WebSocket clients, so strategy code can run unchanged against
+ * live market data without sending orders to Binance.
+ *
+ * - MARKET orders fill immediately by walking the latest order book passed to
+ *   `update_order_book`; only base asset quantities are supported.
+ * - LIMIT orders reserve their funds and first fill against book levels at
+ *   or better than their limit price. IOC orders expire any remainder, FOK
+ *   orders are rejected unless the book fills them completely, and GTC
+ *   orders rest.
+ * - LIMIT_MAKER orders are rejected if they would cross the book, otherwise
+ *   they reserve their funds and rest.
+ * - Resting orders fill at their limit price from trades passed to
+ *   `on_trade` that reach that price.
+ * - Order lists, amendments, cancel-replace and SOR orders are not simulated.
+ *
+ * Liquidity taken from the book or a trade is used up, so it cannot fill
+ * more than its quantity across orders. Commissions are not simulated.
+ *
+ * # Fields
+ * - `state`: Simulated symbols, balances, order books and orders.
+ */
+#[derive(Debug, Default)]
+pub struct PaperTradingClient {
+    state: Mutex<PaperTradingState>,
+}
+
+/**
+ * Mutable state of the paper trading client.
+ *
+ * # Fields
+ * - `symbols`: Base and quote asset of each tradable symbol.
+ * - `balances`: Free balance per asset; funds reserved by resting orders are excluded.
+ * - `order_books`: Latest order book per symbol.
+ * - `orders`: Every order placed, by order ID.
+ * - `next_order_id`: ID assigned to the next order.
+ * - `next_trade_id`: ID assigned to the next simulated fill.
+ */
+#[derive(Debug, Default)]
+struct PaperTradingState {
+    symbols: HashMap<String, (String, String)>,
+    balances: HashMap<String, Decimal>,
+    order_books: HashMap<String, OrderBook>,
+    orders: HashMap<u64, Order>,
+    next_order_id: u64,
+    next_trade_id: i64,
+}
+
+impl PaperTradingClient {
+    /**
+     * Creates a paper trading client without symbols or balances.
+     *
+     * # Returns
+     * - New PaperTradingClient instance.
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Registers a symbol that can be traded.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol (e.g., "BTCUSDT").
+     * - `base_asset`: Base asset of the symbol (e.g., "BTC").
+     * - `quote_asset`: Quote asset of the symbol (e.g., "USDT").
+     *
+     * # Returns
+     * - `Self`: Updated client.
+     */
+    pub fn with_symbol(
+        self,
+        symbol: impl Into<String>,
+        base_asset: impl Into<String>,
+        quote_asset: impl Into<String>,
+    ) -> Self {
+        self.lock().symbols.insert(
+            symbol.into().to_uppercase(),
+            (base_asset.into(), quote_asset.into()),
+        );
+        self
+    }
+
+    /**
+     * Sets the starting free balance of an asset.
+     *
+     * # Arguments
+     * - `asset`: Asset symbol (e.g., "USDT").
+     * - `amount`: Free balance of the asset.
+     *
+     * # Returns
+     * - `Self`: Updated client.
+     */
+    pub fn with_balance(self, asset: impl Into<String>, amount: Decimal) -> Self {
+        self.lock().balances.insert(asset.into(), amount);
+        self
+    }
+
+    /**
+     * Gets the simulated free balance of an asset.
+     *
+     * # Arguments
+     * - `asset`: Asset symbol (e.g., "BTC").
+     *
+     * # Returns
+     * - `Decimal`: Free balance, zero if the asset was never held.
+     */
+    pub fn balance(&self, asset: &str) -> Decimal {
+        self.lock().balances.get(asset).copied().unwrap_or_default()
+    }
+
+    /**
+     * Gets the current state of a simulated order.
+     *
+     * # Arguments
+     * - `order_id`: ID returned when the order was placed.
+     *
+     * # Returns
+     * - `Option<Order>`: The order, or None if no such order was placed.
+     */
+    pub fn order(&self, order_id: u64) -> Option<Order> {
+        self.lock().orders.get(&order_id).cloned()
+    }
+
+    /**
+     * Replaces the order book MARKET orders are matched against.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol of the book.
+     * - `order_book`: Latest order book, e.g. maintained from a diff depth stream.
+     */
+    pub fn update_order_book(&self, symbol: &str, order_book: OrderBook) {
+        self.lock()
+            .order_books
+            .insert(symbol.to_uppercase(), order_book);
+    }
+
+    /**
+     * Fills resting LIMIT orders against a public trade.
+     *
+     * Buy orders fill when the trade price is at or below their limit price and
+     * sell orders when it is at or above. Orders fill at their limit price, in
+     * order ID order, until the traded quantity is used up.
+     *
+     * # Arguments
+     * - `event`: Trade stream event for a simulated symbol.
+     *
+     * # Returns
+     * - `Vec<Order>`: Orders that received a fill, in order ID order.
+     */
+    pub fn on_trade(&self, event: &TradeStreamEvent) -> Vec<Order> {
+        let mut state = self.lock();
+        let Some((base_asset, quote_asset)) = state.symbols.get(&event.symbol).cloned() else {
+            return Vec::new();
+        };
+
+        let mut order_ids: Vec<u64> = state
+            .orders
+            .values()
+            .filter(|order| order.symbol == event.symbol && order.is_working == Some(true))
+            .map(|order| order.order_id)
+            .collect();
+        order_ids.sort_unstable();
+
+        let mut available = event.trade.quantity;
+        let mut filled = Vec::new();
+        for order_id in order_ids {
+            if available.is_zero() {
+                break;
+            }
+            let order = &state.orders[&order_id];
+            let (Some(side), Some(price)) = (order.side, order.price) else {
+                continue;
+            };
+            let crosses = match side {
+                OrderSide::Buy => event.trade.price <= price,
+                OrderSide::Sell => event.trade.price >= price,
+                OrderSide::Unknown => false,
+            };
+            if !crosses {
+                continue;
+            }
+
+            let remaining = order.original_quantity.unwrap_or_default()
+                - order.executed_quantity.unwrap_or_default();
+            let quantity = remaining.min(available);
+            if quantity.is_zero() {
+                continue;
+            }
+            available -= quantity;
+
+            let (credit_asset, credit) = match side {
+                OrderSide::Buy => (&base_asset, quantity),
+                _ => (&quote_asset, quantity * price),
+            };
+            *state.balances.entry(credit_asset.clone()).or_default() += credit;
+
+            let trade_id = state.next_trade_id();
+            let order = state.orders.get_mut(&order_id).unwrap();
+            record_fill(
+                order,
+                price,
+                quantity,
+                &base_asset,
+                trade_id,
+                event.trade.time,
+            );
+            filled.push(order.clone());
+        }
+
+        filled
+    }
+
+    /**
+     * Locks the simulated state.
+     *
+     * # Returns
+     * - `MutexGuard<PaperTradingState>`: Guard over the state.
+     */
+    fn lock(&self) -> std::sync::MutexGuard<'_, PaperTradingState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl PaperTradingState {
+    /**
+     * Gets the base and quote asset of a registered symbol.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol.
+     *
+     * # Returns
+     * - `(String, String)`: Base and quote asset.
+     */
+    fn assets(&self, symbol: &str) -> Result<(String, String)> {
+        self.symbols
+            .get(symbol)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Symbol {} is not registered for paper trading", symbol))
+    }
+
+    /**
+     * Takes an amount from an asset's free balance.
+     *
+     * # Arguments
+     * - `asset`: Asset to debit.
+     * - `amount`: Amount to take.
+     *
+     * # Returns
+     * - `()`: Ok if the free balance covered the amount.
+     */
+    fn debit(&mut self, asset: &str, amount: Decimal) -> Result<()> {
+        let balance = self.balances.entry(asset.to_string()).or_default();
+        if *balance < amount {
+            return Err(anyhow::anyhow!(
+                "Insufficient {} balance: {} available, {} required",
+                asset,
+                balance,
+                amount
+            ));
+        }
+        *balance -= amount;
+        Ok(())
+    }
+
+    /**
+     * Allocates the ID of the next simulated fill.
+     *
+     * # Returns
+     * - `i64`: Trade ID.
+     */
+    fn next_trade_id(&mut self) -> i64 {
+        self.next_trade_id += 1;
+        self.next_trade_id
+    }
+
+    /**
+     * Fills a MARKET order by walking the opposite side of the order book.
+     *
+     * # Arguments
+     * - `order`: Newly created MARKET order.
+     * - `base_asset`: Base asset of the symbol.
+     * - `quote_asset`: Quote asset of the symbol.
+     *
+     * # Returns
+     * - `()`: Ok if the book was deep enough and the balance sufficient.
+     */
+    fn fill_market_order(
+        &mut self,
+        order: &mut Order,
+        base_asset: &str,
+        quote_asset: &str,
+    ) -> Result<()> {
+        let side = order.side.unwrap_or(OrderSide::Unknown);
+        let quantity = order.original_quantity.unwrap_or_default();
+        let order_book = self
+            .order_books
+            .get(&order.symbol)
+            .ok_or_else(|| anyhow::anyhow!("No order book for {}", order.symbol))?;
+        let (average_price, _) = order_book
+            .market_impact(side, quantity)
+            .ok_or_else(|| anyhow::anyhow!("Order book for {} is too thin", order.symbol))?;
+
+        match side {
+            OrderSide::Buy => self.debit(quote_asset, quantity * average_price)?,
+            _ => self.debit(base_asset, quantity)?,
+        }
+
+        let time = order.transaction_time.unwrap_or_default();
+        for (price, filled) in self.take_liquidity(&order.symbol, side, None, quantity) {
+            let trade_id = self.next_trade_id();
+            record_fill(order, price, filled, base_asset, trade_id, time);
+        }
+
+        let (credit_asset, credit) = match side {
+            OrderSide::Buy => (base_asset, quantity),
+            _ => (
+                quote_asset,
+                order.cumulative_quote_quantity.unwrap_or_default(),
+            ),
+        };
+        *self.balances.entry(credit_asset.to_string()).or_default() += credit;
+        Ok(())
+    }
+
+    /**
+     * Fills a LIMIT order against book levels at or better than its limit price.
+     *
+     * The order's funds must already be reserved at its limit price. Buy
+     * fills below the limit price refund the difference, and any remainder
+     * of an IOC order is released. A FOK order the book cannot fill completely
+     * takes no liquidity and expires.
+     *
+     * # Arguments
+     * - `order`: Newly created LIMIT order with its funds reserved.
+     * - `base_asset`: Base asset of the symbol.
+     * - `quote_asset`: Quote asset of the symbol.
+     *
+     * # Returns
+     * - `bool`: True if the order still rests on the book.
+     */
+    fn fill_marketable_limit_order(
+        &mut self,
+        order: &mut Order,
+        base_asset: &str,
+        quote_asset: &str,
+    ) -> bool {
+        let side = order.side.unwrap_or(OrderSide::Unknown);
+        let limit = order.price.unwrap_or_default();
+        let quantity = order.original_quantity.unwrap_or_default();
+        let time_in_force = order.time_in_force.unwrap_or(TimeInForce::GTC);
+
+        let killed = time_in_force == TimeInForce::FOK
+            && self.marketable_quantity(&order.symbol, side, limit) < quantity;
+        let fills = if killed {
+            Vec::new()
+        } else {
+            self.take_liquidity(&order.symbol, side, Some(limit), quantity)
+        };
+
+        let time = order.transaction_time.unwrap_or_default();
+        for (price, filled) in fills {
+            let (credit_asset, credit) = match side {
+                OrderSide::Buy => (base_asset, filled),
+                _ => (quote_asset, filled * price),
+            };
+            *self.balances.entry(credit_asset.to_string()).or_default() += credit;
+            if side == OrderSide::Buy {
+                *self.balances.entry(quote_asset.to_string()).or_default() +=
+                    filled * (limit - price);
+            }
+            let trade_id = self.next_trade_id();
+            record_fill(order, price, filled, base_asset, trade_id, time);
+        }
+
+        let remaining = quantity - order.executed_quantity.unwrap_or_default();
+        if remaining.is_zero() {
+            return false;
+        }
+        if time_in_force != TimeInForce::GTC {
+            let (asset, amount) = match side {
+                OrderSide::Buy => (quote_asset, remaining * limit),
+                _ => (base_asset, remaining),
+            };
+            *self.balances.entry(asset.to_string()).or_default() += amount;
+            order.status = Some(OrderStatus::Expired);
+            order.is_working = Some(false);
+            return false;
+        }
+        true
+    }
+
+    /**
+     * Gets the book quantity an order could take at or better than a price.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol of the book.
+     * - `side`: Side of the taking order.
+     * - `limit`: Worst price the order accepts.
+     *
+     * # Returns
+     * - `Decimal`: Available quantity, zero without a book.
+     */
+    fn marketable_quantity(&self, symbol: &str, side: OrderSide, limit: Decimal) -> Decimal {
+        let Some(order_book) = self.order_books.get(symbol) else {
+            return Decimal::ZERO;
+        };
+        let levels = match side {
+            OrderSide::Buy => &order_book.asks,
+            _ => &order_book.bids,
+        };
+        levels
+            .iter()
+            .take_while(|level| crosses(side, level.price, limit))
+            .map(|level| level.quantity)
+            .sum()
+    }
+
+    /**
+     * Takes liquidity from the opposite side of a symbol's order book.
+     *
+     * Levels are consumed best price first and reduced by the quantity taken,
+     * so the same liquidity cannot fill another order.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol of the book.
+     * - `side`: Side of the taking order.
+     * - `limit`: Worst price to take, or None to take any price.
+     * - `quantity`: Base asset quantity to take.
+     *
+     * # Returns
+     * - `Vec<(Decimal, Decimal)>`: Price and quantity of each fill, best price first.
+     */
+    fn take_liquidity(
+        &mut self,
+        symbol: &str,
+        side: OrderSide,
+        limit: Option<Decimal>,
+        quantity: Decimal,
+    ) -> Vec<(Decimal, Decimal)> {
+        let Some(order_book) = self.order_books.get_mut(symbol) else {
+            return Vec::new();
+        };
+        let levels = match side {
+            OrderSide::Buy => &mut order_book.asks,
+            _ => &mut order_book.bids,
+        };
+
+        let mut fills = Vec::new();
+        let mut remaining = quantity;
+        for level in levels.iter_mut() {
+            if remaining.is_zero() || limit.is_some_and(|limit| !crosses(side, level.price, limit))
+            {
+                break;
+            }
+            let filled = remaining.min(level.quantity);
+            level.quantity -= filled;
+            remaining -= filled;
+            fills.push((level.price, filled));
+        }
+        levels.retain(|level| !level.quantity.is_zero());
+        fills
+    }
+
+    /**
+     * Cancels a resting order and releases its unfilled reservation.
+     *
+     * # Arguments
+     * - `order_id`: ID of the order to cancel.
+     *
+     * # Returns
+     * - `Order`: The cancelled order.
+     */
+    fn cancel(&mut self, order_id: u64) -> Result<Order> {
+        let order = self
+            .orders
+            .get(&order_id)
+            .filter(|order| order.is_working == Some(true))
+            .ok_or_else(|| anyhow::anyhow!("Unknown order sent."))?;
+        let (base_asset, quote_asset) = self.assets(&order.symbol)?;
+        let remaining = order.original_quantity.unwrap_or_default()
+            - order.executed_quantity.unwrap_or_default();
+        let (asset, amount) = match order.side {
+            Some(OrderSide::Buy) => (quote_asset, remaining * order.price.unwrap_or_default()),
+            _ => (base_asset, remaining),
+        };
+        *self.balances.entry(asset).or_default() += amount;
+
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.status = Some(OrderStatus::Canceled);
+        order.is_working = Some(false);
+        order.update_time = Some(now_millis());
+        Ok(order.clone())
+    }
+}
+
+/**
+ * Checks whether a book level can fill an order with the given limit price.
+ *
+ * # Arguments
+ * - `side`: Side of the taking order.
+ * - `level_price`: Price of the opposite book level.
+ * - `limit`: Limit price of the order.
+ *
+ * # Returns
+ * - `bool`: True if the level price is at or better than the limit price.
+ */
+fn crosses(side: OrderSide, level_price: Decimal, limit: Decimal) -> bool {
+    match side {
+        OrderSide::Buy => level_price <= limit,
+        OrderSide::Sell => level_price >= limit,
+        OrderSide::Unknown => false,
+    }
+}
+
+/**
+ * Records a fill on an order and updates its executed quantities and status.
+ *
+ * # Arguments
+ * - `order`: Order that was filled.
+ * - `price`: Fill price.
+ * - `quantity`: Filled base asset quantity.
+ * - `commission_asset`: Asset reported for the (zero) commission.
+ * - `trade_id`: Simulated trade ID.
+ * - `time`: Fill timestamp in milliseconds.
+ */
+fn record_fill(
+    order: &mut Order,
+    price: Decimal,
+    quantity: Decimal,
+    commission_asset: &str,
+    trade_id: i64,
+    time: u64,
+) {
+    let executed = order.executed_quantity.unwrap_or_default() + quantity;
+    order.executed_quantity = Some(executed);
+    order.cumulative_quote_quantity =
+        Some(order.cumulative_quote_quantity.unwrap_or_default() + quantity * price);
+    order.fills.get_or_insert_with(Vec::new).push(Fill {
+        price,
+        quantity,
+        commission: Decimal::ZERO,
+        commission_asset: commission_asset.to_string(),
+        trade_id,
+        match_type: None,
+        allocation_id: None,
+    });
+    order.update_time = Some(time);
+
+    if executed >= order.original_quantity.unwrap_or_default() {
+        order.status = Some(OrderStatus::Filled);
+        order.is_working = Some(false);
+    } else {
+        order.status = Some(OrderStatus::PartiallyFilled);
+    }
+}
+
+/**
+ * Gets the current wall-clock time.
+ *
+ * # Returns
+ * - `u64`: Milliseconds since the Unix epoch.
+ */
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/**
+ * Error returned for operations the paper trading client does not simulate.
+ *
+ * # Arguments
+ * - `operation`: Name of the unsupported operation.
+ *
+ * # Returns
+ * - `anyhow::Error`: Unsupported operation error.
+ */
+fn unsupported(operation: &str) -> anyhow::Error {
+    anyhow::anyhow!("{} is not supported in paper trading", operation)
+}
+
+#[async_trait]
+impl TradingClient for PaperTradingClient {
+    async fn place_order(&self, specification: OrderSpec<Validated>) -> Result<Order> {
+        let mut state = self.lock();
+        let (base_asset, quote_asset) = state.assets(&specification.symbol)?;
+        let quantity = specification
+            .quantity
+            .ok_or_else(|| anyhow::anyhow!("Paper trading requires a base asset quantity"))?;
+
+        state.next_order_id += 1;
+        let order_id = state.next_order_id;
+        let time = now_millis();
+        let mut order = Order {
+            symbol: specification.symbol.clone(),
+            order_id,
+            order_list_id: -1,
+            client_order_id: specification
+                .client_order_id
+                .clone()
+                .unwrap_or_else(|| format!("paper-{}", order_id)),
+            original_client_order_id: None,
+            transaction_time: Some(time),
+            time: Some(time),
+            update_time: Some(time),
+            is_working: Some(false),
+            price: specification.price,
+            original_quantity: Some(quantity),
+            executed_quantity: Some(Decimal::ZERO),
+            original_quote_order_quantity: Some(Decimal::ZERO),
+            cumulative_quote_quantity: Some(Decimal::ZERO),
+            status: Some(OrderStatus::New),
+            time_in_force: specification.time_in_force,
+            order_type: Some(specification.order_type),
+            side: Some(specification.side),
+            working_time: Some(time as i64),
+            self_trade_prevention_mode: specification.self_trade_prevention_mode,
+            fills: None,
+            stop_price: None,
+            iceberg_quantity: None,
+            strategy_id: specification.strategy_id,
+            strategy_type: specification.strategy_type,
+            trailing_delta: None,
+            trailing_time: None,
+            working_floor: None,
+            used_sor: None,
+            prevented_match_id: None,
+            prevented_quantity: None,
+            rate_limits: None,
+        };
+
+        match specification.order_type {
+            OrderType::Market => {
+                state.fill_market_order(&mut order, &base_asset, &quote_asset)?;
+            }
+            OrderType::Limit | OrderType::LimitMaker => {
+                let price = order.price.unwrap_or_default();
+                let marketable = !state
+                    .marketable_quantity(&order.symbol, specification.side, price)
+                    .is_zero();
+                if specification.order_type == OrderType::LimitMaker && marketable {
+                    return Err(anyhow::anyhow!("Order would immediately match and take."));
+                }
+                match specification.side {
+                    OrderSide::Buy => state.debit(&quote_asset, quantity * price)?,
+                    _ => state.debit(&base_asset, quantity)?,
+                }
+                order.is_working = Some(
+                    !marketable
+                        || state.fill_marketable_limit_order(&mut order, &base_asset, &quote_asset),
+                );
+            }
+            order_type => {
+                return Err(unsupported(&format!("{:?} order", order_type)));
+            }
+        }
+
+        state.orders.insert(order_id, order.clone());
+        Ok(order)
+    }
+
+    async fn test_order(&self, specification: OrderSpec<Validated>) -> Result<TestOrder> {
+        self.lock().assets(&specification.symbol)?;
+        Ok(TestOrder {
+            standard_commission_for_order: None,
+            tax_commission_for_order: None,
+            discount: None,
+        })
+    }
+
+    async fn cancel_order(&self, specification: CancelOrderSpec<Validated>) -> Result<Order> {
+        let mut state = self.lock();
+        let order_id = state
+            .orders
+            .values()
+            .find(|order| {
+                order.symbol == specification.symbol
+                    && (specification.order_id == Some(order.order_id)
+                        || specification.original_client_order_id.as_ref()
+                            == Some(&order.client_order_id))
+            })
+            .map(|order| order.order_id)
+            .ok_or_else(|| anyhow::anyhow!("Unknown order sent."))?;
+        state.cancel(order_id)
+    }
+
+    async fn cancel_all_orders(
+        &self,
+        specification: CancelAllOrdersSpec<Validated>,
+    ) -> Result<Vec<CancelledOrder>> {
+        let mut state = self.lock();
+        let mut order_ids: Vec<u64> = state
+            .orders
+            .values()
+            .filter(|order| order.symbol == specification.symbol && order.is_working == Some(true))
+            .map(|order| order.order_id)
+            .collect();
+        order_ids.sort_unstable();
+
+        order_ids
+            .into_iter()
+            .map(|order_id| state.cancel(order_id).map(CancelledOrder::Individual))
+            .collect()
+    }
+
+    async fn cancel_replace_order(
+        &self,
+        _specification: CancelReplaceSpec<Validated>,
+    ) -> Result<CancelReplaceOrder> {
+        Err(unsupported("Cancel-replace"))
+    }
+
+    async fn amend_order(&self, _specification: AmendOrderSpec<Validated>) -> Result<AmendedOrder> {
+        Err(unsupported("Order amendment"))
+    }
+
+    async fn place_oco_order(&self, _specification: OcoOrderSpec<Validated>) -> Result<OrderList> {
+        Err(unsupported("OCO order"))
+    }
+
+    async fn place_oto_order(&self, _specification: OtoOrderSpec<Validated>) -> Result<OrderList> {
+        Err(unsupported("OTO order"))
+    }
+
+    async fn place_otoco_order(
+        &self,
+        _specification: OtocoOrderSpec<Validated>,
+    ) -> Result<OrderList> {
+        Err(unsupported("OTOCO order"))
+    }
+
+    async fn cancel_order_list(
+        &self,
+        _specification: CancelOrderListSpec<Validated>,
+    ) -> Result<OrderList> {
+        Err(unsupported("Order list cancellation"))
+    }
+
+    async fn order_list_status(
+        &self,
+        _specification: OrderListStatusSpec<Validated>,
+    ) -> Result<OrderList> {
+        Err(unsupported("Order list status"))
+    }
+
+    async fn all_order_lists(
+        &self,
+        _specification: AllOrderListsSpec<Validated>,
+    ) -> Result<Vec<OrderList>> {
+        Ok(Vec::new())
+    }
+
+    async fn open_order_lists(
+        &self,
+        _specification: OpenOrderListsSpec<Validated>,
+    ) -> Result<Vec<OrderList>> {
+        Ok(Vec::new())
+    }
+
+    async fn place_sor_order(&self, _specification: SorOrderSpec<Validated>) -> Result<Order> {
+        Err(unsupported("SOR order"))
+    }
+
+    async fn test_sor_order(&self, _specification: SorOrderSpec<Validated>) -> Result<TestOrder> {
+        Err(unsupported("SOR order"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Builds a trade stream event for BTCUSDT.
+     */
+    fn trade(id: u64, price: &str, quantity: &str) -> TradeStreamEvent {
+        serde_json::from_value(serde_json::json!({
+            "e": "trade", "E": 1000 + id, "s": "BTCUSDT", "t": id,
+            "p": price, "q": quantity, "T": 1000 + id, "m": true, "M": true
+        }))
+        .unwrap()
+    }
+
+    /**
+     * Creates a paper client trading BTCUSDT with a USDT balance.
+     */
+    fn paper_client() -> PaperTradingClient {
+        PaperTradingClient::new()
+            .with_symbol("BTCUSDT", "BTC", "USDT")
+            .with_balance("USDT", dec!(10000))
+    }
+
+    /**
+     * Tests that a resting limit buy fills from trades at or below its price.
+     */
+    #[tokio::test]
+    async fn test_limit_order_filled_by_trade() {
+        // Arrange
+        let client = paper_client();
+        let specification = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(0.2))
+            .with_price(dec!(30000))
+            .build()
+            .unwrap();
+
+        // Act
+        let placed = client.place_order(specification).await.unwrap();
+        let above_limit = client.on_trade(&trade(1, "30100", "1"));
+        let partial = client.on_trade(&trade(2, "30000", "0.05"));
+        let rest = client.on_trade(&trade(3, "29950", "1"));
+
+        // Assert
+        assert_eq!(placed.status, Some(OrderStatus::New));
+        assert_eq!(client.balance("USDT"), dec!(4000));
+        assert!(above_limit.is_empty());
+        assert_eq!(partial[0].status, Some(OrderStatus::PartiallyFilled));
+        assert_eq!(rest[0].status, Some(OrderStatus::Filled));
+        let order = client.order(placed.order_id).unwrap();
+        assert_eq!(order.executed_quantity, Some(dec!(0.2)));
+        assert_eq!(order.cumulative_quote_quantity, Some(dec!(6000)));
+        assert_eq!(order.fills.unwrap().len(), 2);
+        assert_eq!(client.balance("BTC"), dec!(0.2));
+        assert_eq!(client.balance("USDT"), dec!(4000));
+    }
+
+    /**
+     * Tests that cancelling a partially filled order releases the unfilled reservation.
+     */
+    #[tokio::test]
+    async fn test_cancel_releases_reservation() {
+        // Arrange
+        let client = paper_client();
+        let placed = client
+            .place_order(
+                OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                    .with_time_in_force(TimeInForce::GTC)
+                    .with_quantity(dec!(0.2))
+                    .with_price(dec!(30000))
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        client.on_trade(&trade(1, "30000", "0.05"));
+
+        // Act
+        let cancelled = client
+            .cancel_order(
+                CancelOrderSpec::new("BTCUSDT")
+                    .with_order_id(placed.order_id)
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(cancelled.status, Some(OrderStatus::Canceled));
+        assert_eq!(client.balance("USDT"), dec!(8500));
+        assert_eq!(client.balance("BTC"), dec!(0.05));
+        assert!(client.on_trade(&trade(2, "29000", "1")).is_empty());
+    }
+
+    /**
+     * Tests that a market buy walks the order book and rejects an unaffordable order.
+     */
+    #[tokio::test]
+    async fn test_market_order_fills_against_order_book() {
+        // Arrange
+        let client = paper_client();
+        client.update_order_book(
+            "BTCUSDT",
+            serde_json::from_str(
+                r#"{"lastUpdateId":1,"bids":[["29990","1"]],"asks":[["30000","0.1"],["30010","1"]]}"#,
+            )
+            .unwrap(),
+        );
+        let market_buy = |quantity| {
+            OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+                .with_quantity(quantity)
+                .build()
+                .unwrap()
+        };
+
+        // Act
+        let filled = client.place_order(market_buy(dec!(0.3))).await.unwrap();
+        let unaffordable = client.place_order(market_buy(dec!(1))).await;
+
+        // Assert
+        assert_eq!(filled.status, Some(OrderStatus::Filled));
+        assert_eq!(filled.cumulative_quote_quantity, Some(dec!(9002)));
+        assert_eq!(filled.fills.unwrap().len(), 2);
+        assert_eq!(client.balance("BTC"), dec!(0.3));
+        assert_eq!(client.balance("USDT"), dec!(998));
+        assert!(unaffordable.is_err());
+    }
+
+    /**
+     * Creates a paper client with a BTCUSDT book asking 0.1 at 30000 and 1 at 30010.
+     */
+    fn paper_client_with_book() -> PaperTradingClient {
+        let client = paper_client();
+        client.update_order_book(
+            "BTCUSDT",
+            serde_json::from_str(
+                r#"{"lastUpdateId":1,"bids":[["29990","1"]],"asks":[["30000","0.1"],["30010","1"]]}"#,
+            )
+            .unwrap(),
+        );
+        client
+    }
+
+    /**
+     * Builds a BTCUSDT buy order specification.
+     */
+    fn limit_buy(order_type: OrderType, quantity: Decimal, price: Decimal) -> OrderSpec<Validated> {
+        let specification = OrderSpec::new("BTCUSDT", OrderSide::Buy, order_type)
+            .with_quantity(quantity)
+            .with_price(price);
+        match order_type {
+            OrderType::Limit => specification.with_time_in_force(TimeInForce::GTC),
+            _ => specification,
+        }
+        .build()
+        .unwrap()
+    }
+
+    /**
+     * Tests that one trade fills resting orders only up to its traded quantity.
+     */
+    #[tokio::test]
+    async fn test_trade_liquidity_used_up_across_orders() {
+        // Arrange
+        let client = paper_client();
+        let first = client
+            .place_order(limit_buy(OrderType::Limit, dec!(1), dec!(1000)))
+            .await
+            .unwrap();
+        let second = client
+            .place_order(limit_buy(OrderType::Limit, dec!(1), dec!(1000)))
+            .await
+            .unwrap();
+
+        // Act
+        let filled = client.on_trade(&trade(1, "1000", "1.5"));
+
+        // Assert
+        assert_eq!(filled.len(), 2);
+        let first = client.order(first.order_id).unwrap();
+        let second = client.order(second.order_id).unwrap();
+        assert_eq!(first.status, Some(OrderStatus::Filled));
+        assert_eq!(second.status, Some(OrderStatus::PartiallyFilled));
+        assert_eq!(second.executed_quantity, Some(dec!(0.5)));
+        assert_eq!(client.balance("BTC"), dec!(1.5));
+    }
+
+    /**
+     * Tests that a marketable limit buy fills against the book and uses up its liquidity.
+     */
+    #[tokio::test]
+    async fn test_marketable_limit_order_fills_against_order_book() {
+        // Arrange
+        let client = paper_client_with_book();
+
+        // Act
+        let marketable = client
+            .place_order(limit_buy(OrderType::Limit, dec!(0.2), dec!(30010)))
+            .await
+            .unwrap();
+        let resting = client
+            .place_order(limit_buy(OrderType::Limit, dec!(0.1), dec!(30000)))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(marketable.status, Some(OrderStatus::Filled));
+        assert_eq!(marketable.is_working, Some(false));
+        assert_eq!(marketable.cumulative_quote_quantity, Some(dec!(6001)));
+        assert_eq!(marketable.fills.unwrap().len(), 2);
+        assert_eq!(resting.status, Some(OrderStatus::New));
+        assert_eq!(resting.is_working, Some(true));
+        assert_eq!(client.balance("BTC"), dec!(0.2));
+        assert_eq!(client.balance("USDT"), dec!(999));
+    }
+
+    /**
+     * Tests that a LIMIT_MAKER order that would cross the book is rejected.
+     */
+    #[tokio::test]
+    async fn test_limit_maker_rejected_when_crossing() {
+        // Arrange
+        let client = paper_client_with_book();
+
+        // Act
+        let crossing = client
+            .place_order(limit_buy(OrderType::LimitMaker, dec!(0.1), dec!(30000)))
+            .await;
+        let passive = client
+            .place_order(limit_buy(OrderType::LimitMaker, dec!(0.1), dec!(29995)))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(
+            crossing
+                .unwrap_err()
+                .to_string()
+                .contains("would immediately match")
+        );
+        assert_eq!(passive.status, Some(OrderStatus::New));
+        assert_eq!(client.balance("USDT"), dec!(7000.5));
+        assert!(client.balance("BTC").is_zero());
+    }
+}
@@ -0,0 +1,325 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Method;
+use tokio::time::Instant;
+use tracing::warn;
+
+use crate::enums::RateLimitType;
+use crate::types::responses::RateLimit;
+
+/**
+ * Request weight usage within one rate limit window.
+ *
+ * # Fields
+ * - `length`: Length of the window.
+ * - `limit`: Maximum request weight allowed per window.
+ * - `used`: Request weight charged in the current window.
+ * - `started`: Start of the current window.
+ */
+#[derive(Debug)]
+struct WeightWindow {
+    length: Duration,
+    limit: u64,
+    used: u64,
+    started: Instant,
+}
+
+/**
+ * Client-side limiter for the REST request weight.
+ *
+ * Every request charges its endpoint weight against each configured window
+ * and waits until all windows have room for it. The limiter is optional: it
+ * starts without windows, in which case requests are never held back.
+ *
+ * # Fields
+ * - `windows`: Request weight windows requests are charged against.
+ */
+#[derive(Debug, Default)]
+pub struct RequestWeightLimiter {
+    windows: Mutex<Vec<WeightWindow>>,
+}
+
+impl RequestWeightLimiter {
+    /**
+     * Creates a limiter without any windows.
+     *
+     * # Returns
+     * - New RequestWeightLimiter instance.
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Replaces the request weight limits, resetting the charged weight.
+     *
+     * # Arguments
+     * - `limits`: Window length and maximum weight per window.
+     */
+    pub fn set_limits(&self, limits: impl IntoIterator<Item = (Duration, u64)>) {
+        let now = Instant::now();
+        *self.windows.lock().expect("rate limiter lock poisoned") = limits
+            .into_iter()
+            .map(|(length, limit)| WeightWindow {
+                length,
+                limit,
+                used: 0,
+                started: now,
+            })
+            .collect();
+    }
+
+    /**
+     * Sets the limits from the rate limits reported by the exchange.
+     *
+     * Only `REQUEST_WEIGHT` limits with a known interval are applied; order
+     * and connection limits are not request weight.
+     *
+     * # Arguments
+     * - `rate_limits`: Rate limits from exchange information.
+     */
+    pub fn set_rate_limits(&self, rate_limits: &[RateLimit]) {
+        self.set_limits(
+            rate_limits
+                .iter()
+                .filter(|rate_limit| rate_limit.rate_limit_type == RateLimitType::RequestWeight)
+                .filter_map(|rate_limit| Some((rate_limit.window()?, rate_limit.limit))),
+        );
+    }
+
+    /**
+     * Gets the request weight charged in the current window of a given length.
+     *
+     * # Arguments
+     * - `length`: Length of the window.
+     *
+     * # Returns
+     * - `Option<u64>`: Charged weight, or None if no window has that length.
+     */
+    pub fn used_weight(&self, length: Duration) -> Option<u64> {
+        let now = Instant::now();
+        self.windows
+            .lock()
+            .expect("rate limiter lock poisoned")
+            .iter()
+            .find(|window| window.length == length)
+            .map(|window| {
+                if now >= window.started + window.length {
+                    0
+                } else {
+                    window.used
+                }
+            })
+    }
+
+    /**
+     * Charges a request weight, waiting until every window has room for it.
+     *
+     * A weight above a window's limit is charged once that window is empty
+     * rather than waiting forever.
+     *
+     * # Arguments
+     * - `weight`: Request weight to charge.
+     */
+    pub(crate) async fn acquire(&self, weight: u32) {
+        let weight = u64::from(weight);
+        loop {
+            let wait_until = {
+                let mut windows = self.windows.lock().expect("rate limiter lock poisoned");
+                let now = Instant::now();
+                for window in windows.iter_mut() {
+                    if now >= window.started + window.length {
+                        window.started = now;
+                        window.used = 0;
+                    }
+                }
+
+                let wait_until = windows
+                    .iter()
+                    .filter(|window| window.used > 0 && window.used + weight > window.limit)
+                    .map(|window| window.started + window.length)
+                    .max();
+                if wait_until.is_none() {
+                    windows.iter_mut().for_each(|window| window.used += weight);
+                }
+                wait_until
+            };
+
+            let Some(until) = wait_until else {
+                return;
+            };
+            warn!(
+                weight,
+                delay_ms = (until - Instant::now()).as_millis(),
+                "Request weight limit reached, delaying request"
+            );
+            tokio::time::sleep_until(until).await;
+        }
+    }
+}
+
+/**
+ * Gets the documented request weight of a REST endpoint.
+ *
+ * Endpoints whose weight depends on their parameters are weighted from the
+ * query string. Endpoints not listed weigh 1.
+ *
+ * # Arguments
+ * - `method`: HTTP method of the request.
+ * - `endpoint`: Endpoint path (e.g., "/api/v3/depth").
+ * - `query`: Encoded query string of the request.
+ *
+ * # Returns
+ * - `u32`: Request weight of the endpoint.
+ */
+pub(crate) fn default_endpoint_weight(method: &Method, endpoint: &str, query: &str) -> u32 {
+    let param = |name: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    };
+    let symbol_count = || {
+        param("symbols").map(|symbols| {
+            symbols.matches("%2C").count() as u32 + symbols.matches(',').count() as u32 + 1
+        })
+    };
+
+    match (method.as_str(), endpoint) {
+        ("GET", "/api/v3/depth") => {
+            match param("limit").and_then(|limit| limit.parse::<u32>().ok()) {
+                Some(1..=100) | None => 5,
+                Some(101..=500) => 25,
+                Some(501..=1000) => 50,
+                Some(_) => 250,
+            }
+        }
+        ("GET", "/api/v3/ticker/24hr") => match (param("symbol"), symbol_count()) {
+            (Some(_), _) => 2,
+            (None, Some(1..=20)) => 2,
+            (None, Some(21..=100)) => 40,
+            _ => 80,
+        },
+        ("GET", "/api/v3/ticker/price" | "/api/v3/ticker/bookTicker")
+            if param("symbol").is_some() =>
+        {
+            2
+        }
+        ("GET", "/api/v3/ticker/price" | "/api/v3/ticker/bookTicker") => 4,
+        ("GET", "/api/v3/ticker" | "/api/v3/ticker/tradingDay") => {
+            symbol_count().map_or(4, |count| (count * 4).min(200))
+        }
+        ("GET", "/api/v3/openOrders") if param("symbol").is_some() => 6,
+        ("GET", "/api/v3/openOrders") => 80,
+        ("POST", "/api/v3/order/test" | "/api/v3/sor/order/test")
+            if param("computeCommissionRates") == Some("true") =>
+        {
+            20
+        }
+        ("GET", "/api/v3/myTrades") if param("orderId").is_some() => 5,
+        ("GET", "/api/v3/myTrades") => 20,
+        ("GET", "/api/v3/trades" | "/api/v3/historicalTrades") => 25,
+        ("GET", "/api/v3/exchangeInfo")
+        | ("GET", "/api/v3/account")
+        | ("GET", "/api/v3/account/commission")
+        | ("GET", "/api/v3/allOrders")
+        | ("GET", "/api/v3/allOrderList")
+        | ("GET", "/api/v3/myPreventedMatches")
+        | ("GET", "/api/v3/myAllocations") => 20,
+        ("GET", "/api/v3/rateLimit/order") => 40,
+        ("GET", "/api/v3/openOrderList") => 6,
+        ("GET", "/api/v3/order" | "/api/v3/orderList" | "/api/v3/aggTrades") => 4,
+        ("GET", "/api/v3/klines" | "/api/v3/uiKlines" | "/api/v3/avgPrice") => 2,
+        (_, "/api/v3/userDataStream") => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests that parameter-dependent endpoints are weighted from the query string.
+     */
+    #[test]
+    fn test_default_endpoint_weight() {
+        // Arrange
+        let cases = [
+            (Method::GET, "/api/v3/exchangeInfo", "", 20),
+            (Method::GET, "/api/v3/depth", "symbol=BTCUSDT", 5),
+            (
+                Method::GET,
+                "/api/v3/depth",
+                "symbol=BTCUSDT&limit=5000",
+                250,
+            ),
+            (Method::GET, "/api/v3/openOrders", "symbol=BTCUSDT", 6),
+            (Method::GET, "/api/v3/openOrders", "", 80),
+            (
+                Method::GET,
+                "/api/v3/ticker",
+                "symbols=%5B%22A%22%2C%22B%22%5D",
+                8,
+            ),
+            (Method::POST, "/api/v3/order", "symbol=BTCUSDT", 1),
+            (Method::GET, "/api/v3/order", "symbol=BTCUSDT", 4),
+        ];
+
+        // Act
+        let weights: Vec<u32> = cases
+            .iter()
+            .map(|(method, endpoint, query, _)| default_endpoint_weight(method, endpoint, query))
+            .collect();
+
+        // Assert
+        let expected: Vec<u32> = cases.iter().map(|(.., weight)| *weight).collect();
+        assert_eq!(weights, expected);
+    }
+
+    /**
+     * Tests that only request weight limits from exchange information become windows.
+     */
+    #[test]
+    fn test_set_rate_limits_uses_request_weight() {
+        // Arrange
+        let rate_limits: Vec<RateLimit> = serde_json::from_str(
+            r#"[
+                {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000},
+                {"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 100},
+                {"rateLimitType": "RAW_REQUESTS", "interval": "MINUTE", "intervalNum": 5, "limit": 61000}
+            ]"#,
+        )
+        .unwrap();
+        let limiter = RequestWeightLimiter::new();
+
+        // Act
+        limiter.set_rate_limits(&rate_limits);
+
+        // Assert
+        assert_eq!(limiter.used_weight(Duration::from_secs(60)), Some(0));
+        assert_eq!(limiter.used_weight(Duration::from_secs(10)), None);
+        assert_eq!(limiter.used_weight(Duration::from_secs(300)), None);
+    }
+
+    /**
+     * Tests that a request exceeding the window limit waits for the next window.
+     */
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_waits_for_next_window() {
+        // Arrange
+        let limiter = RequestWeightLimiter::new();
+        limiter.set_limits([(Duration::from_secs(60), 25)]);
+        limiter.acquire(20).await;
+
+        // Act
+        let start = Instant::now();
+        limiter.acquire(10).await;
+
+        // Assert
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+        assert_eq!(limiter.used_weight(Duration::from_secs(60)), Some(10));
+    }
+}
@@ -5,11 +5,11 @@ use crate::{
     clients::{rest::BinanceSpotRestClient, r#trait::AccountClient},
     types::{
         requests::{
-            AllOrdersSpec, AllocationSpec, CommissionRatesSpec, MyTradesSpec, OpenOrdersSpec,
-            PreventedMatchesSpec, QueryOrderSpec, Validated,
+            AccountInfoSpec, AllOrdersSpec, AllocationSpec, CommissionRatesSpec, MyTradesSpec,
+            OpenOrdersSpec, PreventedMatchesSpec, QueryOrderSpec, Validated,
         },
         responses::{
-            AccountInfo, AccountTrade, Allocation, Order, PreventedMatch, RateLimit,
+            AccountInfo, AccountTrade, Allocation, ListenKey, Order, PreventedMatch, RateLimit,
             SymbolCommissionRates,
         },
     },
@@ -17,8 +17,11 @@ use crate::{
 
 #[async_trait]
 impl AccountClient for BinanceSpotRestClient {
-    async fn account_info(&self) -> Result<AccountInfo> {
-        self.signed_request(reqwest::Method::GET, "/api/v3/account", ())
+    async fn account_info_with(
+        &self,
+        specification: AccountInfoSpec<Validated>,
+    ) -> Result<AccountInfo> {
+        self.signed_request(reqwest::Method::GET, "/api/v3/account", specification)
             .await
     }
 
@@ -78,4 +81,308 @@ impl AccountClient for BinanceSpotRestClient {
         self.signed_request(reqwest::Method::GET, "/api/v3/myAllocations", specification)
             .await
     }
+
+    async fn create_listen_key(&self) -> Result<String> {
+        let response: ListenKey = self
+            .api_key_request(reqwest::Method::POST, "/api/v3/userDataStream", ())
+            .await?;
+        Ok(response.listen_key)
+    }
+
+    async fn keepalive_listen_key(&self, listen_key: &str) -> Result<()> {
+        let _: serde_json::Value = self
+            .api_key_request(
+                reqwest::Method::PUT,
+                "/api/v3/userDataStream",
+                [("listenKey", listen_key)],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        let _: serde_json::Value = self
+            .api_key_request(
+                reqwest::Method::DELETE,
+                "/api/v3/userDataStream",
+                [("listenKey", listen_key)],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::FixedClock;
+    use crate::clients::rest;
+    use crate::clients::tests::helpers::{
+        create_mock_rest_client, create_mock_rest_config, spawn_mock_server,
+        spawn_sequenced_mock_server,
+    };
+    use crate::clients::r#trait::AccountClient;
+    use crate::types::requests::{AccountInfoSpec, MyTradesSpec};
+    use crate::types::responses::test_fixtures;
+
+    /**
+     * Builds a JSON page of consecutive SOR allocations, one second apart.
+     */
+    fn allocations_page(from_id: u64, count: u64) -> String {
+        let allocations: Vec<_> = (from_id..from_id + count)
+            .map(|id| {
+                serde_json::json!({
+                    "symbol": "BTCUSDT", "allocationId": id, "allocationType": "SOR",
+                    "orderId": 500, "orderListId": -1, "price": "1.00000000",
+                    "qty": "0.10000000", "quoteQty": "0.10000000", "commission": "0.00000000",
+                    "commissionAsset": "BTC", "time": 1_000 * id, "isBuyer": true,
+                    "isMaker": false, "isAllocator": false
+                })
+            })
+            .collect();
+        serde_json::Value::from(allocations).to_string()
+    }
+
+    /**
+     * Builds an open order JSON object of the given type for BNBBTC.
+     */
+    fn open_order(order_id: u64, order_type: &str) -> serde_json::Value {
+        serde_json::json!({
+            "symbol": "BNBBTC", "orderId": order_id, "orderListId": -1,
+            "clientOrderId": format!("open-{}", order_id), "price": "0.00100000",
+            "origQty": "1.00000000", "executedQty": "0.00000000",
+            "origQuoteOrderQty": "0.00000000", "cummulativeQuoteQty": "0.00000000",
+            "status": "NEW", "timeInForce": "GTC", "type": order_type, "side": "BUY",
+            "stopPrice": "0.00000000", "icebergQty": "0.00000000", "time": 1684804350068u64,
+            "updateTime": 1684804350068u64, "isWorking": true, "workingTime": 1684804350068u64,
+            "selfTradePreventionMode": "NONE"
+        })
+    }
+
+    /**
+     * Tests that creating a listen key POSTs to the user data stream endpoint.
+     */
+    #[tokio::test]
+    async fn test_create_listen_key() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(
+            200,
+            r#"{"listenKey":"pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}"#,
+        )
+        .await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let listen_key = client.create_listen_key().await.unwrap();
+
+        // Assert
+        assert_eq!(
+            listen_key,
+            "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+        );
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            ["POST /api/v3/userDataStream HTTP/1.1"]
+        );
+    }
+
+    /**
+     * Tests that keeping a listen key alive PUTs the key to the user data stream endpoint.
+     */
+    #[tokio::test]
+    async fn test_keepalive_listen_key() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(200, "{}").await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let result = client.keepalive_listen_key("abc123").await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            ["PUT /api/v3/userDataStream?listenKey=abc123 HTTP/1.1"]
+        );
+    }
+
+    /**
+     * Tests that closing a listen key DELETEs the key from the user data stream endpoint.
+     */
+    #[tokio::test]
+    async fn test_close_listen_key() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(200, "{}").await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let result = client.close_listen_key("abc123").await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            ["DELETE /api/v3/userDataStream?listenKey=abc123 HTTP/1.1"]
+        );
+    }
+
+    /**
+     * Tests that omitting zero balances is sent and the returned balances are all non-zero.
+     */
+    #[tokio::test]
+    async fn test_account_info_omit_zero_balances() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(
+            200,
+            r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":123456789,"accountType":"SPOT","balances":[{"asset":"BTC","free":"1.00000000","locked":"0.00000000"},{"asset":"USDT","free":"0.00000000","locked":"5.00000000"}],"permissions":["SPOT"],"uid":354937868}"#,
+        )
+        .await;
+        let client = create_mock_rest_client(&url);
+        let specification = AccountInfoSpec::new()
+            .with_omit_zero_balances(true)
+            .build()
+            .unwrap();
+
+        // Act
+        let account = client.account_info_with(specification).await.unwrap();
+
+        // Assert
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].starts_with("GET /api/v3/account?omitZeroBalances=true&"));
+        assert_eq!(account.balances.len(), 2);
+        assert!(
+            account
+                .balances
+                .iter()
+                .all(|balance| !(balance.free + balance.locked).is_zero())
+        );
+    }
+
+    /**
+     * Tests that open orders are counted against the symbol's order count filters.
+     */
+    #[tokio::test]
+    async fn test_order_count_usage() {
+        // Arrange
+        let open_orders = serde_json::json!([
+            open_order(1, "LIMIT"),
+            open_order(2, "LIMIT"),
+            open_order(3, "STOP_LOSS_LIMIT"),
+            open_order(4, "TAKE_PROFIT_LIMIT"),
+            open_order(5, "LIMIT_MAKER"),
+        ]);
+        let (url, requests) = spawn_mock_server(200, open_orders.to_string()).await;
+        let client = create_mock_rest_client(&url);
+        let symbol_info = test_fixtures::symbol_info(serde_json::json!([
+            { "filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200 },
+            { "filterType": "MAX_NUM_ALGO_ORDERS", "maxNumAlgoOrders": 5 }
+        ]));
+
+        // Act
+        let usage = client.order_count_usage(&symbol_info).await.unwrap();
+
+        // Assert
+        let request = &requests.lock().unwrap()[0];
+        assert!(request.starts_with("GET /api/v3/openOrders?"));
+        assert!(request.contains("symbol=BNBBTC"));
+        assert_eq!(usage.symbol, "BNBBTC");
+        assert_eq!(usage.open_orders, 5);
+        assert_eq!(usage.open_algo_orders, 2);
+        assert_eq!(usage.max_num_orders, Some(200));
+        assert_eq!(usage.max_num_algo_orders, Some(5));
+        assert_eq!(usage.remaining_orders(), Some(195));
+        assert_eq!(usage.remaining_algo_orders(), Some(3));
+    }
+
+    /**
+     * Tests that trades can be queried by order ID and only that order's fills are returned.
+     */
+    #[tokio::test]
+    async fn test_my_trades_by_order_id() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(
+            200,
+            r#"[{"symbol":"BNBBTC","id":28457,"orderId":100234,"orderListId":-1,"price":"4.00000100","qty":"12.00000000","quoteQty":"48.000012","commission":"10.10000000","commissionAsset":"BNB","time":1499865549590,"isBuyer":true,"isMaker":false,"isBestMatch":true},{"symbol":"BNBBTC","id":28458,"orderId":100234,"orderListId":-1,"price":"4.00000200","qty":"3.00000000","quoteQty":"12.000006","commission":"2.50000000","commissionAsset":"BNB","time":1499865549591,"isBuyer":true,"isMaker":false,"isBestMatch":true}]"#,
+        )
+        .await;
+        let client = create_mock_rest_client(&url);
+        let specification = MyTradesSpec::new("BNBBTC")
+            .with_order_id(100234)
+            .build()
+            .unwrap();
+
+        // Act
+        let trades = client.my_trades(specification).await.unwrap();
+
+        // Assert
+        let request = &requests.lock().unwrap()[0];
+        assert!(request.starts_with("GET /api/v3/myTrades?"));
+        assert!(request.contains("orderId=100234"));
+        assert_eq!(trades.len(), 2);
+        assert!(trades.iter().all(|trade| trade.order_id == 100234));
+    }
+
+    /**
+     * Tests that an order ID trades query is signed over its sorted parameters.
+     */
+    #[tokio::test]
+    async fn test_my_trades_by_order_id_signed_query() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(200, "[]").await;
+        let mut config = create_mock_rest_config(&url);
+        config.set_clock(FixedClock(1_700_000_000_000));
+        let signer = config.signer().unwrap();
+        let client = rest::client(config).unwrap();
+        let specification = MyTradesSpec::new("bnbbtc")
+            .with_order_id(100234)
+            .with_recv_window(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        // Act
+        let trades = client.my_trades(specification).await.unwrap();
+
+        // Assert
+        let payload = "orderId=100234&recvWindow=10000&symbol=BNBBTC&timestamp=1700000000000";
+        let signature = signer.sign(payload).await.unwrap();
+        assert!(trades.is_empty());
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            [format!(
+                "GET /api/v3/myTrades?{}&signature={} HTTP/1.1",
+                payload, signature
+            )]
+        );
+    }
+
+    /**
+     * Tests that allocations in a range are collected across pages without gaps.
+     */
+    #[tokio::test]
+    async fn test_allocations_range_pages() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, allocations_page(1, 1000)),
+            (200, allocations_page(1001, 5)),
+        ])
+        .await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let allocations = client
+            .allocations_range("BTCUSDT", 1_000, 1_003_000)
+            .await
+            .unwrap();
+
+        // Assert
+        let ids: Vec<u64> = allocations.iter().map(|a| a.allocation_id).collect();
+        assert_eq!(ids, (1..=1003).collect::<Vec<_>>());
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("startTime=1000"));
+        assert!(!requests[0].contains("fromAllocationId"));
+        assert!(requests[1].contains("fromAllocationId=1001"));
+        assert!(!requests[1].contains("startTime"));
+    }
 }
@@ -1,12 +1,35 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
 use anyhow::Context;
 use reqwest;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use tracing::{debug, info, instrument};
+use tokio::task::JoinHandle;
+use tokio::time::{Interval, MissedTickBehavior};
+use tracing::{debug, info, instrument, warn};
 
 use crate::Result;
-use crate::{BinanceConfig, RestConfig, clients::common::generate_signature, errors::BinanceError};
+use crate::{
+    BinanceConfig, Clock, RestConfig, ShutdownToken,
+    clients::common::{generate_signature, record_correlation_id},
+    clients::rate_limiter::default_endpoint_weight,
+    clients::r#trait::SigningClient,
+    clients::{RequestWeightLimiter, SymbolInfoCache},
+    config::wait_for_shutdown,
+    errors::BinanceError,
+};
+
+/**
+ * Cooldown applied when a rate limit response carries no `Retry-After` header.
+ */
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/**
+ * How long fetched symbol information is reused by pre-flight checks.
+ */
+const SYMBOL_INFO_CACHE_TTL: Duration = Duration::from_secs(60);
 
 /**
  * REST API client implementation with configurable HTTP settings.
@@ -14,16 +37,27 @@ use crate::{BinanceConfig, RestConfig, clients::common::generate_signature, erro
  * # Fields
  * - `config`: Binance configuration containing API credentials and REST-specific settings.
  * - `client`: HTTP client for making requests with optimized connection management.
+ * - `symbol_info_cache`: Symbol information cache used by pre-flight checks.
+ * - `keepalive_task`: Background task keeping pooled connections warm, if enabled.
+ * - `cooldown_until`: End of the current rate limit cooldown, if auto cooldown is enabled.
+ * - `rate_limiter`: Client-side request weight limiter, inactive until limits are set.
  */
 pub struct BinanceSpotRestClient {
     pub(crate) config: BinanceConfig<RestConfig>,
     pub(crate) client: reqwest::Client,
+    symbol_info_cache: SymbolInfoCache,
+    keepalive_task: Option<JoinHandle<()>>,
+    cooldown_until: Mutex<Option<tokio::time::Instant>>,
+    rate_limiter: RequestWeightLimiter,
 }
 
 impl BinanceSpotRestClient {
     /**
      * Creates a new REST client instance with configuration-based HTTP settings.
      *
+     * When a keepalive warmup interval is configured, the warmer task is spawned
+     * on the current Tokio runtime, so the client must then be created inside one.
+     *
      * # Arguments
      * - `config`: Binance configuration with API credentials and REST settings.
      *
@@ -38,13 +72,134 @@ impl BinanceSpotRestClient {
             .connect_timeout(rest_config.connection_timeout)
             .pool_max_idle_per_host(rest_config.pool_max_idle_per_host)
             .pool_idle_timeout(rest_config.pool_idle_timeout)
-            .user_agent(&rest_config.user_agent);
+            .user_agent(&rest_config.user_agent)
+            .gzip(rest_config.gzip);
 
         let client = client_builder
             .build()
             .context("Failed to create HTTP client")?;
 
-        Ok(Self { config, client })
+        let keepalive_task = rest_config
+            .keepalive_warmup
+            .map(|interval| {
+                let runtime = tokio::runtime::Handle::try_current()
+                    .context("Keepalive warmup requires a Tokio runtime")?;
+                let mut ticker =
+                    tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+                ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                let ping_url = format!("{}/api/v3/time", rest_config.url);
+                Ok::<_, anyhow::Error>(runtime.spawn(run_keepalive_warmup(
+                    client.clone(),
+                    ping_url,
+                    ticker,
+                    config.shutdown_token().cloned(),
+                )))
+            })
+            .transpose()?;
+
+        Ok(Self {
+            config,
+            client,
+            symbol_info_cache: SymbolInfoCache::new(SYMBOL_INFO_CACHE_TTL),
+            keepalive_task,
+            cooldown_until: Mutex::new(None),
+            rate_limiter: RequestWeightLimiter::new(),
+        })
+    }
+
+    /**
+     * Gets the client-side request weight limiter.
+     *
+     * The limiter holds back requests once the configured request weight is
+     * used up. It has no limits until they are set.
+     *
+     * # Returns
+     * - `&RequestWeightLimiter`: Limiter every request is charged against.
+     */
+    pub fn rate_limiter(&self) -> &RequestWeightLimiter {
+        &self.rate_limiter
+    }
+
+    /**
+     * Gets the symbol information cache used by pre-flight checks.
+     *
+     * Entries are reused for a minute; `invalidate` forces the next lookups
+     * to refetch them.
+     *
+     * # Returns
+     * - `&SymbolInfoCache`: Cache shared by `ensure_tradable` and order validation.
+     */
+    pub fn symbol_info_cache(&self) -> &SymbolInfoCache {
+        &self.symbol_info_cache
+    }
+
+    /**
+     * Gets the request weight charged for an endpoint.
+     *
+     * # Arguments
+     * - `method`: HTTP method of the request.
+     * - `endpoint`: Endpoint path (e.g., "/api/v3/depth").
+     * - `query`: Encoded query string of the request.
+     *
+     * # Returns
+     * - `u32`: Configured override, or the documented weight of the endpoint.
+     */
+    pub(crate) fn request_weight(
+        &self,
+        method: &reqwest::Method,
+        endpoint: &str,
+        query: &str,
+    ) -> u32 {
+        self.config
+            .rest_config()
+            .endpoint_weight(endpoint)
+            .unwrap_or_else(|| default_endpoint_weight(method, endpoint, query))
+    }
+
+    /**
+     * Shuts the client down and closes its pooled connections.
+     *
+     * Stops the keepalive warmer, if running, and waits for it to exit before
+     * the connection pool is dropped, so idle connections close promptly.
+     * `Drop` cannot await, so dropping the client only aborts the warmer
+     * without waiting; call this for a clean shutdown.
+     */
+    pub async fn shutdown(mut self) {
+        if let Some(task) = self.keepalive_task.take() {
+            task.abort();
+            let _ = task.await;
+        }
+    }
+
+    /**
+     * Reads a response body, enforcing the configured maximum size.
+     *
+     * # Arguments
+     * - `response`: The HTTP response to read.
+     *
+     * # Returns
+     * - `String`: Response body text.
+     */
+    async fn read_body(&self, mut response: reqwest::Response) -> Result<String> {
+        let max_bytes = self.config.rest_config().max_response_bytes;
+        let limit_error = || anyhow::anyhow!("Response body exceeds limit of {} bytes", max_bytes);
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > max_bytes as u64)
+        {
+            return Err(limit_error());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.context("Failed to read response")? {
+            if body.len() + chunk.len() > max_bytes {
+                return Err(limit_error());
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        String::from_utf8(body).context("Response body is not valid UTF-8")
     }
 
     /**
@@ -58,25 +213,20 @@ impl BinanceSpotRestClient {
      */
     pub(crate) async fn handle_response(&self, response: reqwest::Response) -> Result<Value> {
         let status = response.status();
-        let text = response.text().await.context("Failed to read response")?;
+        let text = self.read_body(response).await?;
 
         if !status.is_success() {
             if let Ok(error_json) = serde_json::from_str::<Value>(&text)
-                && let (Some(code), Some(msg)) = (error_json.get("code"), error_json.get("msg"))
-                && let (Some(code_num), Some(msg_str)) = (code.as_i64(), msg.as_str())
+                && let Some(api_error) = crate::errors::ApiError::from_json(&error_json)
             {
                 debug!(
                     error_type = "api_error",
-                    error_code = code_num,
-                    error_msg = msg_str,
+                    error_code = api_error.code,
+                    error_msg = %api_error.msg,
                     http_status = %status,
                     "Binance API error"
                 );
-                return Err(BinanceError::Api(crate::errors::ApiError::new(
-                    code_num as i32,
-                    msg_str.to_string(),
-                ))
-                .into());
+                return Err(BinanceError::Api(api_error).into());
             }
             debug!(
                 error_type = "http_error",
@@ -98,6 +248,135 @@ impl BinanceSpotRestClient {
         })
     }
 
+    /**
+     * Waits for the current rate limit cooldown, if any, to elapse.
+     */
+    async fn wait_for_cooldown(&self) {
+        let cooldown_until = *self.cooldown_until.lock().expect("cooldown lock poisoned");
+        if let Some(until) = cooldown_until
+            && until > tokio::time::Instant::now()
+        {
+            warn!(
+                remaining_ms = (until - tokio::time::Instant::now()).as_millis(),
+                "Rate limit cooldown in effect, delaying request"
+            );
+            tokio::time::sleep_until(until).await;
+        }
+    }
+
+    /**
+     * Starts a cooldown if the response signals a rate limit or IP ban.
+     *
+     * The cooldown lasts for the `Retry-After` header in seconds, or
+     * `DEFAULT_COOLDOWN` if the header is missing. An existing longer
+     * cooldown is kept.
+     *
+     * # Arguments
+     * - `response`: The HTTP response to inspect.
+     */
+    fn start_cooldown(&self, response: &reqwest::Response) {
+        let status = response.status();
+        if status != reqwest::StatusCode::TOO_MANY_REQUESTS
+            && status != reqwest::StatusCode::IM_A_TEAPOT
+        {
+            return;
+        }
+
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map_or(DEFAULT_COOLDOWN, Duration::from_secs);
+        let until = tokio::time::Instant::now() + retry_after;
+
+        let mut cooldown_until = self.cooldown_until.lock().expect("cooldown lock poisoned");
+        *cooldown_until = Some(cooldown_until.map_or(until, |current| current.max(until)));
+        warn!(
+            http_status = %status,
+            retry_after_secs = retry_after.as_secs(),
+            "Rate limited, cooling down before further requests"
+        );
+    }
+
+    /**
+     * Sends a prepared request, failing over across the configured base URLs.
+     *
+     * GET requests fail over on connection errors, timeouts and 5xx responses.
+     * Other methods fail over only on connection errors, since they may already
+     * have executed. The endpoint weight is charged once, whatever the failovers.
+     *
+     * # Arguments
+     * - `method`: HTTP method for the request.
+     * - `path`: Endpoint path including the encoded query string.
+     * - `api_key`: Optional API key sent in the `X-MBX-APIKEY` header.
+     *
+     * # Returns
+     * - `reqwest::Response`: Response from the first endpoint that did not fail over.
+     */
+    pub(crate) async fn dispatch(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        api_key: Option<&str>,
+    ) -> Result<reqwest::Response> {
+        let rest_config = self.config.rest_config();
+        let base_urls: Vec<&str> = std::iter::once(rest_config.url.as_str())
+            .chain(rest_config.failover_urls.iter().map(String::as_str))
+            .collect();
+        let (endpoint, query) = path.split_once('?').unwrap_or((path, ""));
+        let timeout_override = rest_config.endpoint_timeouts.get(endpoint).copied();
+        let idempotent = method == reqwest::Method::GET;
+
+        if rest_config.auto_cooldown {
+            self.wait_for_cooldown().await;
+        }
+        self.rate_limiter
+            .acquire(self.request_weight(&method, endpoint, query))
+            .await;
+
+        for (index, base_url) in base_urls.iter().enumerate() {
+            let has_fallback = index + 1 < base_urls.len();
+            let mut request = self
+                .client
+                .request(method.clone(), format!("{}{}", base_url, path));
+            if let Some(api_key) = api_key {
+                request = request.header("X-MBX-APIKEY", api_key);
+            }
+            if let Some(timeout) = timeout_override {
+                request = request.timeout(timeout);
+            }
+
+            match request.send().await {
+                Ok(response)
+                    if has_fallback && idempotent && response.status().is_server_error() =>
+                {
+                    warn!(
+                        base_url = base_url,
+                        http_status = %response.status(),
+                        "Server error, failing over to next endpoint"
+                    );
+                }
+                Ok(response) => {
+                    if rest_config.auto_cooldown {
+                        self.start_cooldown(&response);
+                    }
+                    return Ok(response);
+                }
+                Err(e) if has_fallback && (e.is_connect() || (idempotent && e.is_timeout())) => {
+                    warn!(
+                        base_url = base_url,
+                        error = %e,
+                        "Connection failed, failing over to next endpoint"
+                    );
+                }
+                Err(e) => return Err(anyhow::Error::from(e).context("Failed to send request")),
+            }
+        }
+
+        Err(anyhow::anyhow!("No REST endpoints configured"))
+    }
+
     /**
      * Sends a public (unsigned) request to the API.
      *
@@ -109,23 +388,25 @@ impl BinanceSpotRestClient {
      * # Returns
      * - `Value`: JSON response.
      */
-    #[instrument(skip(self, params), fields(method = %method, endpoint = endpoint))]
+    #[instrument(skip(self, params), fields(method = %method, endpoint = endpoint, correlation_id = tracing::field::Empty))]
     pub(crate) async fn send_request<T: Serialize>(
         &self,
         method: reqwest::Method,
         endpoint: &str,
         params: T,
     ) -> Result<Value> {
+        record_correlation_id(&self.config);
+
         let start = std::time::Instant::now();
         let prep_start = std::time::Instant::now();
 
         let params_query =
             serde_urlencoded::to_string(&params).context("Failed to serialize parameters")?;
 
-        let url = if params_query.is_empty() {
-            format!("{}{}", self.config.url(), endpoint)
+        let path = if params_query.is_empty() {
+            endpoint.to_string()
         } else {
-            format!("{}{}?{}", self.config.url(), endpoint, params_query)
+            format!("{}?{}", endpoint, params_query)
         };
 
         let prep_duration = prep_start.elapsed();
@@ -135,12 +416,7 @@ impl BinanceSpotRestClient {
         );
 
         let network_start = std::time::Instant::now();
-        let response = self
-            .client
-            .request(method, &url)
-            .send()
-            .await
-            .context("Failed to send request")?;
+        let response = self.dispatch(method, &path, None).await?;
         let network_duration = network_start.elapsed();
 
         let parse_start = std::time::Instant::now();
@@ -170,13 +446,15 @@ impl BinanceSpotRestClient {
      * # Returns
      * - `Value`: JSON response.
      */
-    #[instrument(skip(self, params), fields(method = %method, endpoint = endpoint))]
+    #[instrument(skip(self, params), fields(method = %method, endpoint = endpoint, correlation_id = tracing::field::Empty))]
     pub(crate) async fn send_signed_request<T: Serialize>(
         &self,
         method: reqwest::Method,
         endpoint: &str,
         params: T,
     ) -> Result<Value> {
+        record_correlation_id(&self.config);
+
         let start = std::time::Instant::now();
         let prep_start = std::time::Instant::now();
 
@@ -185,11 +463,16 @@ impl BinanceSpotRestClient {
             .signer()
             .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
 
-        let (signature, query_string) =
-            generate_signature(&params, signer.as_ref(), self.config.recv_window(), false).await?;
+        let (signature, query_string) = generate_signature(
+            &params,
+            signer.as_ref(),
+            self.config.clock(),
+            self.config.recv_window(),
+            false,
+        )
+        .await?;
 
-        let final_query = format!("{}&signature={}", query_string, signature);
-        let url = format!("{}{}?{}", self.config.url(), endpoint, final_query);
+        let path = format!("{}?{}&signature={}", endpoint, query_string, signature);
 
         let prep_duration = prep_start.elapsed();
         debug!(
@@ -199,10 +482,7 @@ impl BinanceSpotRestClient {
 
         let network_start = std::time::Instant::now();
         let response = self
-            .client
-            .request(method, &url)
-            .header("X-MBX-APIKEY", signer.get_api_key())
-            .send()
+            .dispatch(method, &path, Some(signer.get_api_key()))
             .await?;
         let network_duration = network_start.elapsed();
 
@@ -222,6 +502,48 @@ impl BinanceSpotRestClient {
         result
     }
 
+    /**
+     * Sends a request authenticated by API key only, without a signature.
+     *
+     * # Arguments
+     * - `method`: HTTP method for the request.
+     * - `endpoint`: API endpoint path.
+     * - `params`: Serializable parameters for the request.
+     *
+     * # Returns
+     * - `R`: Parsed response object.
+     */
+    #[instrument(skip(self, params), fields(method = %method, endpoint = endpoint, correlation_id = tracing::field::Empty))]
+    pub(crate) async fn api_key_request<T, R>(
+        &self,
+        method: reqwest::Method,
+        endpoint: &str,
+        params: T,
+    ) -> Result<R>
+    where
+        T: Serialize,
+        R: DeserializeOwned,
+    {
+        record_correlation_id(&self.config);
+
+        let api_key = self
+            .config
+            .api_key()
+            .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
+
+        let params_query =
+            serde_urlencoded::to_string(&params).context("Failed to serialize parameters")?;
+        let path = if params_query.is_empty() {
+            endpoint.to_string()
+        } else {
+            format!("{}?{}", endpoint, params_query)
+        };
+
+        let response = self.dispatch(method, &path, Some(api_key)).await?;
+        let value = self.handle_response(response).await?;
+        serde_json::from_value(value).context("Failed to parse response")
+    }
+
     /**
      * Helper for public endpoint calls with validation and JSON parsing.
      *
@@ -278,11 +600,63 @@ impl BinanceSpotRestClient {
     }
 }
 
+impl SigningClient for BinanceSpotRestClient {
+    fn clock(&self) -> &dyn Clock {
+        self.config.clock()
+    }
+
+    fn recv_window(&self) -> u64 {
+        self.config.recv_window()
+    }
+}
+
+impl Drop for BinanceSpotRestClient {
+    fn drop(&mut self) {
+        if let Some(task) = self.keepalive_task.take() {
+            task.abort();
+        }
+    }
+}
+
+/**
+ * Pings an endpoint on every tick to keep pooled connections open.
+ *
+ * Failures are logged and otherwise ignored, since the next regular request
+ * reports real errors.
+ *
+ * # Arguments
+ * - `client`: HTTP client whose connection pool is kept warm.
+ * - `url`: Full URL of the endpoint to ping.
+ * - `ticker`: Interval driving the pings.
+ * - `shutdown_token`: Optional shared signal that stops the warmer when triggered.
+ */
+async fn run_keepalive_warmup(
+    client: reqwest::Client,
+    url: String,
+    mut ticker: Interval,
+    shutdown_token: Option<ShutdownToken>,
+) {
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = wait_for_shutdown(shutdown_token.as_ref()) => break,
+        }
+        // The body must be drained for the connection to return to the pool.
+        match client.get(&url).send().await {
+            Ok(response) => {
+                let _ = response.bytes().await;
+            }
+            Err(e) => debug!(error = %e, "Keepalive warmup request failed"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{BinanceConfig, RestConfig, errors::BinanceError};
     use serde_json::json;
+    use std::collections::HashMap;
     use std::time::Duration;
 
     /**
@@ -571,4 +945,457 @@ mod tests {
                 .contains("No authentication configured")
         );
     }
+
+    /**
+     * Tests that an overridden endpoint weight is charged against the rate limiter
+     * and other endpoints are charged their documented weight.
+     */
+    #[tokio::test]
+    async fn test_endpoint_weight_override_charged() {
+        // Arrange
+        let (mock_url, _) =
+            crate::clients::tests::helpers::spawn_mock_server(200, r#"{"serverTime": 1}"#).await;
+        let mut config = BinanceConfig::<RestConfig>::builder()
+            .with_endpoint_weights(HashMap::from([("/api/v3/time", 7)]))
+            .build()
+            .expect("Config creation");
+        config.rest_config_mut().url = mock_url;
+        let client = BinanceSpotRestClient::new(config).unwrap();
+        let minute = Duration::from_secs(60);
+        client.rate_limiter().set_limits([(minute, 6000)]);
+
+        // Act
+        client
+            .send_request(reqwest::Method::GET, "/api/v3/time", ())
+            .await
+            .unwrap();
+        let after_override = client.rate_limiter().used_weight(minute);
+        client
+            .send_request(reqwest::Method::GET, "/api/v3/exchangeInfo", ())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(after_override, Some(7));
+        assert_eq!(client.rate_limiter().used_weight(minute), Some(27));
+    }
+
+    /**
+     * Tests that an endpoint timeout override applies only to its endpoint.
+     */
+    #[tokio::test]
+    async fn test_endpoint_timeout_override() {
+        // Arrange
+        let (mock_url, _) = crate::clients::tests::helpers::spawn_delayed_mock_server(
+            Duration::from_millis(500),
+            200,
+            "[]",
+        )
+        .await;
+        let mut config = BinanceConfig::<RestConfig>::builder()
+            .with_rest_config(
+                RestConfig::builder()
+                    .with_request_timeout(Duration::from_millis(200))
+                    .with_endpoint_timeout_override("/api/v3/allOrders", Duration::from_secs(5))
+                    .build(),
+            )
+            .build()
+            .expect("Config creation");
+        config.rest_config_mut().url = mock_url;
+        let client = BinanceSpotRestClient::new(config).unwrap();
+
+        // Act
+        let account_result = client
+            .send_request(
+                reqwest::Method::GET,
+                "/api/v3/allOrders",
+                [("symbol", "BTCUSDT")],
+            )
+            .await;
+        let ticker_result = client
+            .send_request(reqwest::Method::GET, "/api/v3/ticker/price", ())
+            .await;
+
+        // Assert
+        assert!(account_result.is_ok());
+        assert!(ticker_result.is_err());
+    }
+
+    /**
+     * Tests that a -1003 response with Retry-After delays the next request when auto cooldown is enabled.
+     */
+    #[tokio::test]
+    async fn test_auto_cooldown_delays_requests_after_rate_limit() {
+        // Arrange
+        let (url, requests) = crate::clients::tests::helpers::spawn_mock_server_with_headers(
+            "Retry-After: 1\r\n",
+            vec![
+                (
+                    429,
+                    r#"{"code":-1003,"msg":"Too many requests; current limit is 6000 request weight per 1 MINUTE."}"#,
+                ),
+                (200, r#"{"serverTime":1}"#),
+            ],
+        )
+        .await;
+        let mut config = crate::clients::tests::helpers::create_mock_rest_config(&url);
+        config.rest_config_mut().auto_cooldown = true;
+        let client = BinanceSpotRestClient::new(config).unwrap();
+
+        // Act
+        let rate_limited = client
+            .send_request(reqwest::Method::GET, "/api/v3/time", ())
+            .await;
+        let start = std::time::Instant::now();
+        let delayed = client
+            .send_request(reqwest::Method::GET, "/api/v3/time", ())
+            .await;
+        let elapsed = start.elapsed();
+
+        // Assert
+        assert_eq!(
+            rate_limited
+                .unwrap_err()
+                .downcast_ref::<BinanceError>()
+                .and_then(BinanceError::api_code),
+            Some(-1003)
+        );
+        assert_eq!(delayed.unwrap()["serverTime"], 1);
+        assert!(elapsed >= Duration::from_millis(900), "{:?}", elapsed);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    /**
+     * Creates a client whose primary endpoint refuses connections.
+     */
+    fn client_with_failover(failover_urls: Vec<String>) -> BinanceSpotRestClient {
+        let mut rest_config = RestConfig::builder()
+            .with_url("https://127.0.0.1:1")
+            .with_connection_timeout(Duration::from_secs(2))
+            .build();
+        rest_config.failover_urls = failover_urls;
+
+        let config = BinanceConfig::<RestConfig>::builder()
+            .with_rest_config(rest_config)
+            .build()
+            .expect("Config creation");
+        BinanceSpotRestClient::new(config).unwrap()
+    }
+
+    /**
+     * Tests that a connection error fails over to the next endpoint.
+     */
+    #[tokio::test]
+    async fn test_failover_on_connection_error() {
+        // Arrange
+        let (mock_url, requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, r#"{"serverTime":1}"#).await;
+        let client = client_with_failover(vec![mock_url]);
+
+        // Act
+        let result = client
+            .send_request(reqwest::Method::GET, "/api/v3/time", ())
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap()["serverTime"], 1);
+        assert_eq!(
+            requests.lock().unwrap().as_slice(),
+            ["GET /api/v3/time HTTP/1.1"]
+        );
+    }
+
+    /**
+     * Tests that a 5xx response fails over to the next endpoint.
+     */
+    #[tokio::test]
+    async fn test_failover_on_server_error() {
+        // Arrange
+        let (failing_url, failing_requests) =
+            crate::clients::tests::helpers::spawn_mock_server(503, "Service Unavailable").await;
+        let (healthy_url, healthy_requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, "{}").await;
+        let client = client_with_failover(vec![failing_url, healthy_url]);
+
+        // Act
+        let result = client
+            .send_request(reqwest::Method::GET, "/api/v3/ping", ())
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(failing_requests.lock().unwrap().len(), 1);
+        assert_eq!(healthy_requests.lock().unwrap().len(), 1);
+    }
+
+    /**
+     * Tests that a timed-out POST is sent exactly once and not failed over.
+     */
+    #[tokio::test]
+    async fn test_no_failover_on_post_timeout() {
+        // Arrange
+        let (slow_url, slow_requests) = crate::clients::tests::helpers::spawn_delayed_mock_server(
+            Duration::from_millis(500),
+            200,
+            "{}",
+        )
+        .await;
+        let (healthy_url, healthy_requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, "{}").await;
+        let mut config = BinanceConfig::<RestConfig>::builder()
+            .with_rest_config(
+                RestConfig::builder()
+                    .with_request_timeout(Duration::from_millis(200))
+                    .build(),
+            )
+            .build()
+            .expect("Config creation");
+        config.rest_config_mut().url = slow_url;
+        config.rest_config_mut().failover_urls = vec![healthy_url];
+        let client = BinanceSpotRestClient::new(config).unwrap();
+
+        // Act
+        let result = client
+            .send_request(
+                reqwest::Method::POST,
+                "/api/v3/order",
+                [("symbol", "BTCUSDT")],
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(slow_requests.lock().unwrap().len(), 1);
+        assert!(healthy_requests.lock().unwrap().is_empty());
+    }
+
+    /**
+     * Tests that a 5xx answer to a DELETE is returned instead of failing over.
+     */
+    #[tokio::test]
+    async fn test_no_failover_on_delete_server_error() {
+        // Arrange
+        let (failing_url, failing_requests) =
+            crate::clients::tests::helpers::spawn_mock_server(503, "Service Unavailable").await;
+        let (healthy_url, healthy_requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, "{}").await;
+        let client = client_with_failover(vec![failing_url, healthy_url]);
+
+        // Act
+        let result = client
+            .send_request(reqwest::Method::DELETE, "/api/v3/order", ())
+            .await;
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("HTTP 503"));
+        assert_eq!(failing_requests.lock().unwrap().len(), 1);
+        assert!(healthy_requests.lock().unwrap().is_empty());
+    }
+
+    /**
+     * Tests that the last endpoint's server error is returned when all endpoints fail.
+     */
+    #[tokio::test]
+    async fn test_failover_exhausted() {
+        // Arrange
+        let (failing_url, _) =
+            crate::clients::tests::helpers::spawn_mock_server(500, "Internal Server Error").await;
+        let client = client_with_failover(vec![failing_url]);
+
+        // Act
+        let result = client
+            .send_request(reqwest::Method::GET, "/api/v3/ping", ())
+            .await;
+
+        // Assert
+        assert!(result.unwrap_err().to_string().contains("HTTP 500"));
+    }
+
+    /**
+     * Tests that the failover builder sets the primary and fallback URLs.
+     */
+    #[test]
+    fn test_with_endpoint_failover() {
+        // Act
+        let config = BinanceConfig::<RestConfig>::builder()
+            .with_endpoint_failover(&[
+                crate::BinanceEndpoint::Api1,
+                crate::BinanceEndpoint::Api2,
+                crate::BinanceEndpoint::Gcp,
+            ])
+            .build()
+            .expect("Config creation");
+
+        // Assert
+        assert_eq!(config.url(), "https://api1.binance.com");
+        assert_eq!(
+            config.rest_config().failover_urls,
+            vec!["https://api2.binance.com", "https://api-gcp.binance.com"]
+        );
+    }
+
+    /**
+     * Tests that the keepalive warmer pings server time once per interval under a paused clock.
+     */
+    #[tokio::test(start_paused = true)]
+    async fn test_keepalive_warmup_pings_periodically() {
+        // Arrange
+        let (url, requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, r#"{"serverTime":1}"#).await;
+        let mut config = crate::clients::tests::helpers::create_mock_rest_config(&url);
+        config.rest_config_mut().keepalive_warmup = Some(Duration::from_secs(30));
+        let _client = BinanceSpotRestClient::new(config).unwrap();
+
+        for expected in 1..=3 {
+            // Act
+            tokio::time::advance(Duration::from_secs(30)).await;
+
+            // Assert
+            // Yielding keeps the runtime busy, so IO makes progress without the
+            // paused clock auto-advancing to the next tick.
+            let deadline = std::time::Instant::now() + Duration::from_secs(5);
+            while requests.lock().unwrap().len() < expected {
+                assert!(
+                    std::time::Instant::now() < deadline,
+                    "Keepalive request {} not sent",
+                    expected
+                );
+                tokio::task::yield_now().await;
+            }
+            assert_eq!(requests.lock().unwrap().len(), expected);
+        }
+        assert!(
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|line| line.starts_with("GET /api/v3/time"))
+        );
+    }
+
+    /**
+     * Tests that the keepalive warmer is disabled unless configured.
+     */
+    #[tokio::test]
+    async fn test_keepalive_warmup_disabled_by_default() {
+        // Arrange
+        let config = BinanceConfig::<RestConfig>::builder()
+            .with_testnet()
+            .build()
+            .expect("Config creation");
+
+        // Act
+        let client = BinanceSpotRestClient::new(config).unwrap();
+
+        // Assert
+        assert!(client.config.rest_config().keepalive_warmup.is_none());
+        assert!(client.keepalive_task.is_none());
+    }
+
+    /**
+     * Tests that the keepalive warmer stops issuing requests after shutdown.
+     */
+    #[tokio::test(start_paused = true)]
+    async fn test_shutdown_stops_keepalive_warmup() {
+        // Arrange
+        let (url, requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, r#"{"serverTime":1}"#).await;
+        let mut config = crate::clients::tests::helpers::create_mock_rest_config(&url);
+        config.rest_config_mut().keepalive_warmup = Some(Duration::from_secs(30));
+        let client = BinanceSpotRestClient::new(config).unwrap();
+        tokio::time::advance(Duration::from_secs(30)).await;
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while requests.lock().unwrap().is_empty() {
+            assert!(
+                std::time::Instant::now() < deadline,
+                "Keepalive request not sent"
+            );
+            tokio::task::yield_now().await;
+        }
+
+        let warmer = client.keepalive_task.as_ref().unwrap().abort_handle();
+
+        // Act
+        client.shutdown().await;
+        let finished_on_return = warmer.is_finished();
+        for _ in 0..3 {
+            tokio::time::advance(Duration::from_secs(30)).await;
+        }
+        let settle = std::time::Instant::now() + Duration::from_millis(200);
+        while std::time::Instant::now() < settle {
+            tokio::task::yield_now().await;
+        }
+
+        // Assert
+        assert!(finished_on_return);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    /**
+     * Span layer capturing the `correlation_id` field of every span.
+     */
+    struct CorrelationIdLayer(std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>);
+
+    struct CorrelationIdVisitor<'a>(&'a mut Option<String>);
+
+    impl tracing::field::Visit for CorrelationIdVisitor<'_> {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            if field.name() == "correlation_id" {
+                *self.0 = Some(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, _field: &tracing::field::Field, _value: &dyn std::fmt::Debug) {}
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for CorrelationIdLayer
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut correlation_id = None;
+            values.record(&mut CorrelationIdVisitor(&mut correlation_id));
+            if let (Some(correlation_id), Some(span)) = (correlation_id, ctx.span(id)) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push((span.name().to_string(), correlation_id));
+            }
+        }
+    }
+
+    /**
+     * Tests that the configured correlation id is recorded on the request span.
+     */
+    #[tokio::test]
+    async fn test_correlation_id_recorded_on_request_span() {
+        // Arrange
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let (url, _requests) =
+            crate::clients::tests::helpers::spawn_mock_server(200, r#"{"serverTime":1}"#).await;
+        let mut config = crate::clients::tests::helpers::create_mock_rest_config(&url);
+        config.set_correlation_id_provider(std::sync::Arc::new(|| "trace-42".to_string()));
+        let client = BinanceSpotRestClient::new(config).unwrap();
+        let spans = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber = tracing_subscriber::registry().with(CorrelationIdLayer(spans.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        // Act
+        let result = client
+            .send_request(reqwest::Method::GET, "/api/v3/time", ())
+            .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            spans.lock().unwrap().as_slice(),
+            [("send_request".to_string(), "trace-42".to_string())]
+        );
+    }
 }
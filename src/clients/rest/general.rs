@@ -3,9 +3,11 @@ use async_trait::async_trait;
 use crate::Result;
 use crate::{
     clients::{rest::BinanceSpotRestClient, r#trait::GeneralClient},
+    enums::{Permission, SymbolStatus},
+    errors::InvalidParameter,
     types::{
         requests::{ExchangeInfoSpec, Validated},
-        responses::{ExchangeInfo, ServerTime},
+        responses::{Bootstrap, ExchangeInfo, ServerTime, SymbolInfo},
     },
 };
 
@@ -30,3 +32,305 @@ impl GeneralClient for BinanceSpotRestClient {
             .await
     }
 }
+
+impl BinanceSpotRestClient {
+    /**
+     * Fetches the state a client typically needs at startup.
+     *
+     * Exchange information and server time are requested concurrently. The
+     * client clock is then shifted by the measured offset to server time, so
+     * signed requests carry server-aligned timestamps. The offset assumes the
+     * server time was taken halfway through the round trip. The rate limiter
+     * is seeded with the reported request weight limits, charged with the
+     * weight of the two startup requests.
+     *
+     * # Returns
+     * - `Bootstrap`: Exchange information, server time and the applied clock offset.
+     */
+    pub async fn bootstrap(&mut self) -> Result<Bootstrap> {
+        let this = &*self;
+        let timed_server_time = async {
+            let sent_at = this.config.clock().now_millis();
+            let server_time = this.server_time().await?;
+            let received_at = this.config.clock().now_millis();
+            Ok::<_, anyhow::Error>((
+                server_time,
+                sent_at + received_at.saturating_sub(sent_at) / 2,
+            ))
+        };
+        let exchange_info = async { this.exchange_info(ExchangeInfoSpec::new().build()?).await };
+
+        let ((server_time, local_time), exchange_info) =
+            tokio::try_join!(timed_server_time, exchange_info)?;
+
+        let clock_offset_ms = server_time.server_time.timestamp_millis() - local_time as i64;
+        self.config.shift_clock(clock_offset_ms);
+
+        let startup_weight = self.request_weight(&reqwest::Method::GET, "/api/v3/time", "")
+            + self.request_weight(&reqwest::Method::GET, "/api/v3/exchangeInfo", "");
+        self.rate_limiter()
+            .set_rate_limits(exchange_info.rate_limits());
+        self.rate_limiter().acquire(startup_weight).await;
+
+        Ok(Bootstrap {
+            exchange_info,
+            server_time,
+            clock_offset_ms,
+        })
+    }
+
+    /**
+     * Checks that a symbol can currently be traded on spot before ordering.
+     *
+     * Symbol information comes from the client's `SymbolInfoCache` and is
+     * reused for up to a minute, so repeated checks do not cost extra request
+     * weight.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol to check.
+     *
+     * # Returns
+     * - `SymbolInfo`: Symbol information if the symbol is trading with SPOT permission.
+     */
+    pub async fn ensure_tradable(&self, symbol: &str) -> Result<SymbolInfo> {
+        let symbol = symbol.to_uppercase();
+        let symbol_info = self.symbol_info_cache().symbol_info(self, &symbol).await?;
+
+        if symbol_info.status != SymbolStatus::Trading {
+            return Err(InvalidParameter::new(
+                "symbol",
+                format!(
+                    "{} is not trading (status: {:?})",
+                    symbol, symbol_info.status
+                ),
+            )
+            .into());
+        }
+
+        if !symbol_info.is_spot_trading_allowed || !symbol_info.has_permission(Permission::Spot) {
+            return Err(InvalidParameter::new(
+                "symbol",
+                format!("{} does not allow spot trading", symbol),
+            )
+            .into());
+        }
+
+        Ok(symbol_info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::clients::tests::helpers::{
+        create_mock_rest_config, expect_api_error, spawn_gzip_mock_server, spawn_mock_server,
+        spawn_routed_mock_server,
+    };
+    use crate::enums::RateLimitType;
+    use crate::types::responses::test_fixtures::BNBBTC_TRADING;
+    use crate::{BinanceConfig, FixedClock, RestConfig};
+
+    const HALTED: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/fixtures/exchange_info/bnbbtc_halt.json"
+    ));
+
+    /**
+     * Creates a REST client that sends every request to a mock server.
+     */
+    fn mock_client(base_url: &str) -> BinanceSpotRestClient {
+        mock_client_with_gzip(base_url, true)
+    }
+
+    /**
+     * Creates a REST client for a mock server with gzip support toggled.
+     */
+    fn mock_client_with_gzip(base_url: &str, gzip: bool) -> BinanceSpotRestClient {
+        let mut config = BinanceConfig::<RestConfig>::builder()
+            .with_testnet()
+            .with_gzip(gzip)
+            .build()
+            .expect("Config creation");
+        config.rest_config_mut().url = base_url.to_string();
+        BinanceSpotRestClient::new(config).unwrap()
+    }
+
+    /**
+     * Tests that bootstrapping fetches exchange info and server time, shifts the clock
+     * and seeds the rate limiter.
+     */
+    #[tokio::test]
+    async fn test_bootstrap_seeds_clock_offset_and_rate_limits() {
+        // Arrange
+        let (url, requests) = spawn_routed_mock_server(vec![
+            ("/api/v3/time", r#"{"serverTime":1700000000250}"#),
+            (
+                "/api/v3/exchangeInfo",
+                r#"{"timezone":"UTC","serverTime":1700000000250,"rateLimits":[{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":6000},{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":100}],"exchangeFilters":[],"symbols":[]}"#,
+            ),
+        ])
+        .await;
+        let mut config = create_mock_rest_config(&url);
+        config.set_clock(FixedClock(1_700_000_000_000));
+        let mut client = BinanceSpotRestClient::new(config).unwrap();
+
+        // Act
+        let bootstrap = client.bootstrap().await.unwrap();
+
+        // Assert
+        assert_eq!(bootstrap.clock_offset_ms, 250);
+        assert_eq!(client.config.clock().now_millis(), 1_700_000_000_250);
+        assert_eq!(
+            bootstrap.server_time.server_time.timestamp_millis(),
+            1_700_000_000_250
+        );
+        let rate_limits = bootstrap.rate_limits();
+        assert_eq!(rate_limits.len(), 2);
+        assert_eq!(rate_limits[0].rate_limit_type, RateLimitType::RequestWeight);
+        assert_eq!(rate_limits[0].limit, 6000);
+        let minute = Duration::from_secs(60);
+        assert_eq!(client.rate_limiter().used_weight(minute), Some(21));
+        assert_eq!(
+            client.rate_limiter().used_weight(Duration::from_secs(10)),
+            None
+        );
+        let mut requests = requests.lock().unwrap().clone();
+        requests.sort();
+        assert_eq!(
+            requests,
+            [
+                "GET /api/v3/exchangeInfo HTTP/1.1",
+                "GET /api/v3/time HTTP/1.1"
+            ]
+        );
+    }
+
+    /**
+     * Tests that a trading symbol passes and later checks use the cache.
+     */
+    #[tokio::test]
+    async fn test_ensure_tradable_trading_symbol() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(200, BNBBTC_TRADING).await;
+        let client = mock_client(&url);
+
+        // Act
+        let first = client.ensure_tradable("bnbbtc").await.unwrap();
+        let second = client.ensure_tradable("BNBBTC").await.unwrap();
+
+        // Assert
+        assert_eq!(first.symbol, "BNBBTC");
+        assert_eq!(first, second);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /api/v3/exchangeInfo?"));
+        assert!(requests[0].contains("symbol=BNBBTC"));
+    }
+
+    /**
+     * Tests that a halted symbol is rejected.
+     */
+    #[tokio::test]
+    async fn test_ensure_tradable_halted_symbol() {
+        // Arrange
+        let (url, _) = spawn_mock_server(200, HALTED).await;
+        let client = mock_client(&url);
+
+        // Act
+        let result = client.ensure_tradable("BNBBTC").await;
+
+        // Assert
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("not trading"), "{}", error);
+    }
+
+    /**
+     * Tests that an unknown symbol surfaces the invalid symbol error.
+     */
+    #[tokio::test]
+    async fn test_ensure_tradable_unknown_symbol() {
+        // Arrange
+        let (url, _) = spawn_mock_server(400, r#"{"code":-1121,"msg":"Invalid symbol."}"#).await;
+        let client = mock_client(&url);
+
+        // Act
+        let result = client.ensure_tradable("NOPENOPE").await;
+
+        // Assert
+        expect_api_error(&result, -1121);
+    }
+
+    /**
+     * Tests that a gzip-encoded exchange info response is decompressed and parsed.
+     */
+    #[tokio::test]
+    async fn test_exchange_info_gzip_response() {
+        // Arrange
+        let (url, _) = spawn_gzip_mock_server(200, BNBBTC_TRADING).await;
+        let client = mock_client(&url);
+        let specification = ExchangeInfoSpec::new().build().unwrap();
+
+        // Act
+        let exchange_info = client.exchange_info(specification).await.unwrap();
+
+        // Assert
+        assert_eq!(exchange_info.symbols.len(), 1);
+        assert_eq!(exchange_info.symbols[0].symbol, "BNBBTC");
+    }
+
+    /**
+     * Tests that gzip responses are not decoded when compression is disabled.
+     */
+    #[tokio::test]
+    async fn test_exchange_info_gzip_disabled() {
+        // Arrange
+        let (url, _) = spawn_gzip_mock_server(200, BNBBTC_TRADING).await;
+        let client = mock_client_with_gzip(&url, false);
+        let specification = ExchangeInfoSpec::new().build().unwrap();
+
+        // Act
+        let result = client.exchange_info(specification).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    /**
+     * Tests that a body larger than the configured limit is rejected, compressed or not.
+     */
+    #[tokio::test]
+    async fn test_max_response_bytes_exceeded() {
+        // Arrange
+        let (plain_url, _) = spawn_mock_server(200, BNBBTC_TRADING).await;
+        let (gzip_url, _) = spawn_gzip_mock_server(200, BNBBTC_TRADING).await;
+        let limited_client = |url: &str| {
+            let mut config = BinanceConfig::<RestConfig>::builder()
+                .with_testnet()
+                .with_max_response_bytes(256)
+                .build()
+                .expect("Config creation");
+            config.rest_config_mut().url = url.to_string();
+            BinanceSpotRestClient::new(config).unwrap()
+        };
+
+        // Act
+        let plain = limited_client(&plain_url)
+            .exchange_info(ExchangeInfoSpec::new().build().unwrap())
+            .await;
+        let gzip = limited_client(&gzip_url)
+            .exchange_info(ExchangeInfoSpec::new().build().unwrap())
+            .await;
+
+        // Assert
+        for result in [plain, gzip] {
+            let error = result.unwrap_err();
+            assert!(
+                error.to_string().contains("exceeds limit of 256 bytes"),
+                "{}",
+                error
+            );
+        }
+    }
+}
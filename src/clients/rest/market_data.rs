@@ -65,3 +65,182 @@ impl MarketDataClient for BinanceSpotRestClient {
             .await
     }
 }
+
+impl BinanceSpotRestClient {
+    /**
+     * Fetches an order book snapshot ready to be maintained with diff depth events.
+     *
+     * The returned book has no diffs applied yet; pass it to
+     * `OrderBookManager::resync` to replay the buffered diff depth events.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol.
+     * - `limit`: Optional number of levels to fetch (default: 100, max: 5000).
+     *
+     * # Returns
+     * - `OrderBook`: Order book snapshot.
+     */
+    pub async fn order_book_snapshot(&self, symbol: &str, limit: Option<u16>) -> Result<OrderBook> {
+        let mut specification = OrderBookSpec::new(symbol);
+        if let Some(limit) = limit {
+            specification = specification.with_limit(limit);
+        }
+        self.order_book(specification.build()?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use rust_decimal::dec;
+
+    use crate::clients::rest::BinanceSpotRestClient;
+    use crate::clients::tests::helpers::{
+        create_mock_rest_config, spawn_mock_server, spawn_sequenced_mock_server,
+    };
+    use crate::clients::r#trait::MarketDataClient;
+    use crate::streams::specs::TimezoneOffset;
+    use crate::types::requests::KlinesSpec;
+
+    const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+    const EIGHT_HOURS_MS: u64 = 8 * 60 * 60 * 1000;
+
+    /**
+     * Builds a JSON page of consecutive aggregate trades starting at an ID.
+     */
+    fn agg_trades_page(from_id: u64, count: u64) -> String {
+        let trades: Vec<_> = (from_id..from_id + count)
+            .map(|id| {
+                serde_json::json!({
+                    "a": id, "p": "0.01633102", "q": "4.70443515", "f": id, "l": id,
+                    "T": 1498793709153u64 + id, "m": true, "M": true
+                })
+            })
+            .collect();
+        serde_json::Value::from(trades).to_string()
+    }
+
+    /**
+     * Tests that a fetched snapshot deserializes into a ready-to-update order book.
+     */
+    #[tokio::test]
+    async fn test_order_book_snapshot() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(
+            200,
+            r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"],["3.99000000","12.00000000"]],"asks":[["4.00000200","12.00000000"],["4.01000000","5.00000000"],["4.02000000","7.00000000"]]}"#,
+        )
+        .await;
+        let client = BinanceSpotRestClient::new(create_mock_rest_config(&url)).unwrap();
+
+        // Act
+        let book = client.order_book_snapshot("BNBBTC", Some(5)).await.unwrap();
+
+        // Assert
+        assert_eq!(book.last_update_id, 1027024);
+        assert_eq!(book.best_bid().unwrap().price, dec!(4.00000000));
+        assert_eq!(book.best_ask().unwrap().price, dec!(4.00000200));
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.asks.len(), 3);
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("GET /api/v3/depth?"));
+        assert!(request.contains("symbol=BNBBTC"));
+        assert!(request.contains("limit=5"));
+    }
+
+    /**
+     * Tests that every aggregate trade across pages is visited once, in order.
+     */
+    #[tokio::test]
+    async fn test_agg_trades_for_each_visits_all_pages() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, agg_trades_page(5, 1000)),
+            (200, agg_trades_page(1005, 3)),
+        ])
+        .await;
+        let client = BinanceSpotRestClient::new(create_mock_rest_config(&url)).unwrap();
+        let mut visited = Vec::new();
+
+        // Act
+        client
+            .agg_trades_for_each("BNBBTC", 5, |trade| {
+                visited.push(trade.id);
+                ControlFlow::Continue(())
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(visited, (5..1008).collect::<Vec<_>>());
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("fromId=5"));
+        assert!(requests[1].contains("fromId=1005"));
+    }
+
+    /**
+     * Tests that iteration stops without fetching further pages once the callback breaks.
+     */
+    #[tokio::test]
+    async fn test_agg_trades_for_each_early_stop() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, agg_trades_page(1, 1000)),
+            (200, agg_trades_page(1001, 1000)),
+        ])
+        .await;
+        let client = BinanceSpotRestClient::new(create_mock_rest_config(&url)).unwrap();
+        let mut visited = 0;
+
+        // Act
+        client
+            .agg_trades_for_each("BNBBTC", 1, |trade| {
+                visited += 1;
+                if trade.id == 10 {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(visited, 10);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    /**
+     * Tests that a UTC+8 klines request sends timeZone and decodes candles opening at midnight UTC+8.
+     */
+    #[tokio::test]
+    async fn test_klines_utc_plus_8_daily_boundary() {
+        // Arrange
+        let fixture = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/klines/bnbbtc_1d_utc8.json"
+        ));
+        let (url, requests) = spawn_sequenced_mock_server(vec![(200, fixture)]).await;
+        let client = BinanceSpotRestClient::new(create_mock_rest_config(&url)).unwrap();
+        let specification = KlinesSpec::new("BNBBTC", "1d")
+            .with_timezone(TimezoneOffset::UtcPlus8)
+            .build()
+            .unwrap();
+
+        // Act
+        let klines = client.klines(specification).await.unwrap();
+
+        // Assert
+        let request = requests.lock().unwrap()[0].clone();
+        assert!(request.starts_with("GET /api/v3/klines?"));
+        assert!(request.contains("timeZone=%2B08%3A00"));
+        assert_eq!(klines.len(), 2);
+        for kline in &klines {
+            assert_eq!((kline.open_time + EIGHT_HOURS_MS) % DAY_MS, 0);
+            assert_ne!(kline.open_time % DAY_MS, 0);
+            assert_eq!(kline.close_time - kline.open_time, DAY_MS - 1);
+        }
+    }
+}
@@ -7,7 +7,8 @@ use crate::{
         requests::{
             AllOrderListsSpec, AmendOrderSpec, CancelAllOrdersSpec, CancelOrderListSpec,
             CancelOrderSpec, CancelReplaceSpec, OcoOrderSpec, OpenOrderListsSpec,
-            OrderListStatusSpec, OrderSpec, OtoOrderSpec, OtocoOrderSpec, SorOrderSpec, Validated,
+            OrderListStatusSpec, OrderResponseDefault, OrderSpec, OtoOrderSpec, OtocoOrderSpec,
+            SorOrderSpec, Validated,
         },
         responses::{
             AmendedOrder, CancelReplaceOrder, CancelledOrder, Order, OrderList, TestOrder,
@@ -18,11 +19,15 @@ use crate::{
 #[async_trait]
 impl TradingClient for BinanceSpotRestClient {
     async fn place_order(&self, specification: OrderSpec<Validated>) -> Result<Order> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(reqwest::Method::POST, "/api/v3/order", specification)
             .await
     }
 
     async fn test_order(&self, specification: OrderSpec<Validated>) -> Result<TestOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(reqwest::Method::POST, "/api/v3/order/test", specification)
             .await
     }
@@ -44,12 +49,15 @@ impl TradingClient for BinanceSpotRestClient {
         &self,
         specification: CancelReplaceSpec<Validated>,
     ) -> Result<CancelReplaceOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(
             reqwest::Method::POST,
             "/api/v3/order/cancelReplace",
             specification,
         )
         .await
+        .or_else(CancelReplaceOrder::from_partial_failure)
     }
 
     async fn amend_order(&self, specification: AmendOrderSpec<Validated>) -> Result<AmendedOrder> {
@@ -62,6 +70,8 @@ impl TradingClient for BinanceSpotRestClient {
     }
 
     async fn place_oco_order(&self, specification: OcoOrderSpec<Validated>) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(
             reqwest::Method::POST,
             "/api/v3/orderList/oco",
@@ -71,6 +81,8 @@ impl TradingClient for BinanceSpotRestClient {
     }
 
     async fn place_oto_order(&self, specification: OtoOrderSpec<Validated>) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(
             reqwest::Method::POST,
             "/api/v3/orderList/oto",
@@ -83,6 +95,8 @@ impl TradingClient for BinanceSpotRestClient {
         &self,
         specification: OtocoOrderSpec<Validated>,
     ) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(
             reqwest::Method::POST,
             "/api/v3/orderList/otoco",
@@ -124,11 +138,15 @@ impl TradingClient for BinanceSpotRestClient {
     }
 
     async fn place_sor_order(&self, specification: SorOrderSpec<Validated>) -> Result<Order> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(reqwest::Method::POST, "/api/v3/sor/order", specification)
             .await
     }
 
     async fn test_sor_order(&self, specification: SorOrderSpec<Validated>) -> Result<TestOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.rest_config().default_order_resp_type);
         self.signed_request(
             reqwest::Method::POST,
             "/api/v3/sor/order/test",
@@ -137,3 +155,460 @@ impl TradingClient for BinanceSpotRestClient {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    use rust_decimal::dec;
+
+    use crate::clients::rest;
+    use crate::clients::tests::helpers::{
+        create_mock_rest_client, create_mock_rest_config, spawn_mock_server,
+        spawn_scripted_mock_server, spawn_sequenced_mock_server,
+    };
+    use crate::clients::r#trait::TradingClient;
+    use crate::enums::{
+        CancelReplaceMode, CancelReplaceStatus, OrderListOrderStatus, OrderResponseType, OrderSide,
+        OrderStatus, OrderType, TimeInForce,
+    };
+    use crate::types::requests::{
+        CancelOrderListSpec, CancelOrderSpec, CancelReplaceSpec, OrderSpec, RequestSpec,
+    };
+    use crate::{BinanceError, Clock};
+
+    const EXECUTING_ORDER_LIST: &str = r#"{"orderListId":1929,"contingencyType":"OCO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"C3wyj4WVEktd7u9aVBRXcN","transactionTime":1574040868128,"symbol":"BNBBTC","orders":[{"symbol":"BNBBTC","orderId":2,"clientOrderId":"pO9ufTiFGg3nw2fOdgeOXa"}]}"#;
+    const ALL_DONE_ORDER_LIST: &str = r#"{"orderListId":1929,"contingencyType":"OCO","listStatusType":"ALL_DONE","listOrderStatus":"ALL_DONE","listClientOrderId":"C3wyj4WVEktd7u9aVBRXcN","transactionTime":1574040868130,"symbol":"BNBBTC","orders":[{"symbol":"BNBBTC","orderId":2,"clientOrderId":"pO9ufTiFGg3nw2fOdgeOXa"}]}"#;
+
+    const OPEN_ORDER_LISTS: &str = r#"[
+        {"orderListId":1,"contingencyType":"OCO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"list1","transactionTime":1574040868128,"symbol":"BNBBTC","orders":[{"symbol":"BNBBTC","orderId":1,"clientOrderId":"order1"}]},
+        {"orderListId":2,"contingencyType":"OCO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"list2","transactionTime":1574040868128,"symbol":"BNBBTC","orders":[{"symbol":"BNBBTC","orderId":2,"clientOrderId":"order2"}]},
+        {"orderListId":3,"contingencyType":"OCO","listStatusType":"EXEC_STARTED","listOrderStatus":"EXECUTING","listClientOrderId":"list3","transactionTime":1574040868128,"symbol":"BNBBTC","orders":[{"symbol":"BNBBTC","orderId":3,"clientOrderId":"order3"}]}
+    ]"#;
+    const UNKNOWN_ORDER_LIST: &str = r#"{"code":-2011,"msg":"Unknown order list sent."}"#;
+
+    const CANCELLED_ORDER: &str = r#"{"symbol":"BNBBTC","origClientOrderId":"myOrder1","orderId":4,"orderListId":-1,"clientOrderId":"cancelMyOrder1","transactTime":1684804350068,"price":"0.00100000","origQty":"1.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"CANCELED","timeInForce":"GTC","type":"LIMIT","side":"BUY","selfTradePreventionMode":"NONE"}"#;
+    const CANCEL_REPLACE_PARTIALLY_FAILED: &str = r#"{"code":-2021,"msg":"Order cancel-replace partially failed.","data":{"cancelResult":"SUCCESS","newOrderResult":"FAILURE","cancelResponse":{"symbol":"BNBBTC","origClientOrderId":"myOrder1","orderId":4,"orderListId":-1,"clientOrderId":"cancelMyOrder1","transactTime":1684804350068,"price":"0.00100000","origQty":"1.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"CANCELED","timeInForce":"GTC","type":"LIMIT","side":"BUY","selfTradePreventionMode":"NONE"},"newOrderResponse":{"code":-2010,"msg":"Order would immediately match and take."}}}"#;
+    const UNKNOWN_ORDER: &str = r#"{"code":-2011,"msg":"Unknown order sent."}"#;
+    const AUDITED_ORDER: &str = r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"audit-1","transactTime":1507725176595,"price":"30000.00000000","origQty":"0.10000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","workingTime":1507725176595,"selfTradePreventionMode":"NONE"}"#;
+    const NO_SUCH_ORDER: &str = r#"{"code":-2013,"msg":"Order does not exist."}"#;
+    const PLACED_ORDER: &str = r#"{"symbol":"BNBBTC","orderId":7,"orderListId":-1,"clientOrderId":"retrySafe1","price":"0.00100000","origQty":"1.00000000","executedQty":"0.00000000","origQuoteOrderQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","time":1684804350068,"updateTime":1684804350068,"isWorking":true,"workingTime":1684804350068,"selfTradePreventionMode":"NONE"}"#;
+
+    /**
+     * Tests that cancelling several orders returns a result per ID and tolerates -2011.
+     */
+    #[tokio::test]
+    async fn test_cancel_orders() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, CANCELLED_ORDER),
+            (400, UNKNOWN_ORDER),
+            (200, CANCELLED_ORDER),
+        ])
+        .await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let results = client.cancel_orders("BNBBTC", &[4, 5, 6]).await.unwrap();
+
+        // Assert
+        assert_eq!(
+            results.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            [4, 5, 6]
+        );
+        let failures: Vec<_> = results
+            .iter()
+            .filter_map(|(_, result)| result.as_ref().err())
+            .collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0]
+                .downcast_ref::<crate::BinanceError>()
+                .and_then(crate::BinanceError::api_code),
+            Some(-2011)
+        );
+        assert_eq!(
+            results.iter().filter(|(_, result)| result.is_ok()).count(),
+            2
+        );
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    /**
+     * Tests that a STOP_ON_FAILURE cancel-replace whose placement is rejected
+     * returns both legs instead of a top-level error.
+     */
+    #[tokio::test]
+    async fn test_cancel_replace_stop_on_failure_placement_rejected() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(409, CANCEL_REPLACE_PARTIALLY_FAILED).await;
+        let client = create_mock_rest_client(&url);
+        let specification = CancelReplaceSpec::new(
+            "BNBBTC",
+            CancelReplaceMode::StopOnFailure,
+            OrderSide::Buy,
+            OrderType::LimitMaker,
+        )
+        .with_cancel_order_id(4)
+        .with_quantity(dec!(1))
+        .with_price(dec!(0.002))
+        .build()
+        .unwrap();
+
+        // Act
+        let cancel_replace = client.cancel_replace_order(specification).await.unwrap();
+
+        // Assert
+        assert!(requests.lock().unwrap()[0].contains("cancelReplaceMode=STOP_ON_FAILURE"));
+        assert_eq!(cancel_replace.cancel_status, CancelReplaceStatus::Success);
+        assert_eq!(
+            cancel_replace.new_order_status,
+            CancelReplaceStatus::Failure
+        );
+        let cancel_order = cancel_replace.cancel_order.unwrap();
+        assert_eq!(cancel_order.order_id, 4);
+        assert_eq!(cancel_order.status, Some(OrderStatus::Canceled));
+        assert!(cancel_replace.new_order.is_none());
+        assert_eq!(cancel_replace.new_order_error.unwrap().code, -2010);
+    }
+
+    /**
+     * Tests that cancelling all order lists cancels each open list and tolerates -2011.
+     */
+    #[tokio::test]
+    async fn test_cancel_all_order_lists() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, OPEN_ORDER_LISTS),
+            (200, ALL_DONE_ORDER_LIST),
+            (400, UNKNOWN_ORDER_LIST),
+            (200, ALL_DONE_ORDER_LIST),
+        ])
+        .await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let cancelled = client.cancel_all_order_lists(2).await.unwrap();
+
+        // Assert
+        assert_eq!(cancelled.len(), 2);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].starts_with("GET /api/v3/openOrderList?"));
+        for order_list_id in 1..=3 {
+            let prefix = format!("DELETE /api/v3/orderList?orderListId={}&", order_list_id);
+            assert!(requests[1..].iter().any(|line| line.starts_with(&prefix)));
+        }
+    }
+
+    /**
+     * Tests that errors other than -2011 abort cancelling all order lists.
+     */
+    #[tokio::test]
+    async fn test_cancel_all_order_lists_propagates_other_errors() {
+        // Arrange
+        let (url, _) = spawn_sequenced_mock_server(vec![
+            (200, OPEN_ORDER_LISTS),
+            (
+                400,
+                r#"{"code":-1102,"msg":"Mandatory parameter was not sent."}"#,
+            ),
+        ])
+        .await;
+        let client = create_mock_rest_client(&url);
+
+        // Act
+        let result = client.cancel_all_order_lists(3).await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    /**
+     * Tests that cancel-and-confirm polls the order list status until it is done.
+     */
+    #[tokio::test]
+    async fn test_cancel_order_list_and_confirm() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![
+            (200, EXECUTING_ORDER_LIST),
+            (200, EXECUTING_ORDER_LIST),
+            (200, ALL_DONE_ORDER_LIST),
+        ])
+        .await;
+        let client = create_mock_rest_client(&url);
+        let spec = CancelOrderListSpec::new("BNBBTC")
+            .with_order_list_id(1929)
+            .build()
+            .unwrap();
+
+        // Act
+        let order_list = client
+            .cancel_order_list_and_confirm(spec, Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(order_list.list_order_status, OrderListOrderStatus::AllDone);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("DELETE /api/v3/orderList?"));
+        assert!(requests[1].starts_with("GET /api/v3/orderList?orderListId=1929&"));
+        assert!(requests[2].starts_with("GET /api/v3/orderList?orderListId=1929&"));
+    }
+
+    /**
+     * Tests that a timed out placement the exchange accepted is found by client order ID, not re-sent.
+     */
+    #[tokio::test]
+    async fn test_place_order_idempotent_recovers_accepted_order() {
+        // Arrange
+        let (url, requests) = spawn_scripted_mock_server(vec![
+            (Duration::from_millis(500), 200, PLACED_ORDER),
+            (Duration::ZERO, 200, PLACED_ORDER),
+        ])
+        .await;
+        let mut config = create_mock_rest_config(&url);
+        config
+            .rest_config_mut()
+            .endpoint_timeouts
+            .insert("/api/v3/order".to_string(), Duration::from_millis(100));
+        let client = rest::client(config).unwrap();
+        let specification = OrderSpec::new("BNBBTC", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(1))
+            .with_price(dec!(0.001))
+            .with_client_order_id("retrySafe1")
+            .build()
+            .unwrap();
+
+        // Act
+        let order = client.place_order_idempotent(specification).await.unwrap();
+
+        // Assert
+        assert_eq!(order.order_id, 7);
+        assert_eq!(order.status, Some(OrderStatus::New));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /api/v3/order"));
+        assert!(requests[1].starts_with("GET /api/v3/order"));
+        assert!(requests[1].contains("origClientOrderId=retrySafe1"));
+    }
+
+    /**
+     * Tests that an order missing on the first lookup is found by a later lookup, not re-sent.
+     */
+    #[tokio::test]
+    async fn test_place_order_idempotent_retries_lookup_until_order_appears() {
+        // Arrange
+        let (url, requests) = spawn_scripted_mock_server(vec![
+            (Duration::from_millis(500), 200, PLACED_ORDER),
+            (Duration::ZERO, 400, NO_SUCH_ORDER),
+            (Duration::ZERO, 200, PLACED_ORDER),
+        ])
+        .await;
+        let mut config = create_mock_rest_config(&url);
+        config.set_recv_window(3000);
+        config
+            .rest_config_mut()
+            .endpoint_timeouts
+            .insert("/api/v3/order".to_string(), Duration::from_millis(100));
+        let client = rest::client(config).unwrap();
+        let specification = OrderSpec::new("BNBBTC", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(1))
+            .with_price(dec!(0.001))
+            .with_client_order_id("retrySafe1")
+            .build()
+            .unwrap();
+
+        // Act
+        let order = client.place_order_idempotent(specification).await.unwrap();
+
+        // Assert
+        assert_eq!(order.order_id, 7);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /api/v3/order"));
+        assert!(requests[0].contains("recvWindow=3000"));
+        assert!(requests[1].starts_with("GET /api/v3/order"));
+        assert!(requests[2].starts_with("GET /api/v3/order"));
+    }
+
+    /**
+     * Clock advancing by a fixed step on every reading.
+     *
+     * # Fields
+     * - `now_ms`: Milliseconds since the Unix epoch returned by the next reading.
+     * - `step_ms`: Milliseconds added after every reading.
+     */
+    #[derive(Debug)]
+    struct SteppingClock {
+        now_ms: AtomicU64,
+        step_ms: u64,
+    }
+
+    impl Clock for SteppingClock {
+        fn now_millis(&self) -> u64 {
+            self.now_ms.fetch_add(self.step_ms, Ordering::SeqCst)
+        }
+    }
+
+    /**
+     * Tests that an order still missing after the receive window, counted from
+     * the signing timestamp, fails with an unknown outcome and is never re-sent.
+     */
+    #[tokio::test]
+    async fn test_place_order_idempotent_unknown_outcome_is_not_resent() {
+        // Arrange
+        let (url, requests) = spawn_scripted_mock_server(vec![
+            (Duration::from_millis(500), 200, PLACED_ORDER),
+            (Duration::ZERO, 400, NO_SUCH_ORDER),
+        ])
+        .await;
+        let mut config = create_mock_rest_config(&url);
+        config.set_recv_window(400);
+        config.set_clock(SteppingClock {
+            now_ms: AtomicU64::new(1_700_000_000_000),
+            step_ms: 100,
+        });
+        config
+            .rest_config_mut()
+            .endpoint_timeouts
+            .insert("/api/v3/order".to_string(), Duration::from_millis(100));
+        let client = rest::client(config).unwrap();
+        let specification = OrderSpec::new("BNBBTC", OrderSide::Buy, OrderType::Market)
+            .with_quantity(dec!(1))
+            .with_client_order_id("retrySafe2")
+            .build()
+            .unwrap();
+
+        // Act
+        let error = client
+            .place_order_idempotent(specification)
+            .await
+            .unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            error.downcast_ref::<BinanceError>(),
+            Some(BinanceError::OrderOutcomeUnknown { client_order_id }) if client_order_id == "retrySafe2"
+        ));
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /api/v3/order"));
+        assert!(requests[0].contains("newClientOrderId=retrySafe2"));
+        assert!(requests[0].contains("recvWindow=400"));
+        assert!(requests[0].contains("timestamp=1700000000000"));
+        for lookup in &requests[1..] {
+            assert!(lookup.starts_with("GET /api/v3/order"));
+            assert!(lookup.contains("origClientOrderId=retrySafe2"));
+        }
+    }
+
+    /**
+     * Tests that the audit parameters of a LIMIT order match the query string actually sent.
+     */
+    #[tokio::test]
+    async fn test_order_spec_to_params_matches_query() {
+        // Arrange
+        let (url, requests) = spawn_mock_server(200, AUDITED_ORDER).await;
+        let client = create_mock_rest_client(&url);
+        let specification = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(0.1))
+            .with_price(dec!(30000))
+            .with_client_order_id("audit-1")
+            .build()
+            .unwrap();
+
+        // Act
+        let params = specification.to_params().unwrap();
+        client.place_order(specification).await.unwrap();
+
+        // Assert
+        let request_line = requests.lock().unwrap()[0].clone();
+        let query = request_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|target| target.split_once('?'))
+            .map(|(_, query)| query)
+            .unwrap();
+        let mut sent: std::collections::BTreeMap<String, String> =
+            serde_urlencoded::from_str(query).unwrap();
+        for auth_param in ["timestamp", "recvWindow", "signature"] {
+            sent.remove(auth_param);
+        }
+        assert_eq!(params, sent);
+        assert_eq!(params["type"], "LIMIT");
+        assert_eq!(params["price"], "30000");
+        assert_eq!(params["newClientOrderId"], "audit-1");
+    }
+
+    /**
+     * Tests that a per-spec receive window replaces the client default in the signed query.
+     */
+    #[tokio::test]
+    async fn test_spec_recv_window_overrides_client_default() {
+        // Arrange
+        let (url, requests) =
+            spawn_sequenced_mock_server(vec![(200, CANCELLED_ORDER), (200, CANCELLED_ORDER)]).await;
+        let mut config = create_mock_rest_config(&url);
+        config.set_recv_window(5000);
+        let client = rest::client(config).unwrap();
+        let overridden = CancelOrderSpec::new("BNBBTC")
+            .with_order_id(4)
+            .with_recv_window(Duration::from_millis(1500))
+            .build()
+            .unwrap();
+        let defaulted = CancelOrderSpec::new("BNBBTC")
+            .with_order_id(4)
+            .build()
+            .unwrap();
+
+        // Act
+        client.cancel_order(overridden).await.unwrap();
+        client.cancel_order(defaulted).await.unwrap();
+
+        // Assert
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].matches("recvWindow=").count(), 1);
+        assert!(requests[0].contains("recvWindow=1500&"));
+        assert!(requests[1].contains("recvWindow=5000&"));
+    }
+
+    /**
+     * Tests that the client's default response type fills specs that omit it but not ones that set it.
+     */
+    #[tokio::test]
+    async fn test_default_order_resp_type() {
+        // Arrange
+        let (url, requests) =
+            spawn_sequenced_mock_server(vec![(200, AUDITED_ORDER), (200, AUDITED_ORDER)]).await;
+        let mut config = create_mock_rest_config(&url);
+        config.rest_config_mut().default_order_resp_type = Some(OrderResponseType::Full);
+        let client = rest::client(config).unwrap();
+        let order = || {
+            OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                .with_time_in_force(TimeInForce::GTC)
+                .with_quantity(dec!(0.1))
+                .with_price(dec!(30000))
+        };
+        let defaulted = order().build().unwrap();
+        let overridden = order()
+            .with_response_type(OrderResponseType::ACK)
+            .build()
+            .unwrap();
+
+        // Act
+        client.place_order(defaulted).await.unwrap();
+        client.place_order(overridden).await.unwrap();
+
+        // Assert
+        let requests = requests.lock().unwrap();
+        assert!(requests[0].contains("newOrderRespType=FULL&"));
+        assert!(requests[1].contains("newOrderRespType=ACK&"));
+        assert_eq!(requests[1].matches("newOrderRespType=").count(), 1);
+    }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use rust_decimal::Decimal;
+use tokio::sync::Mutex;
+
+use crate::Result;
+use crate::clients::r#trait::{GeneralClient, MarketDataClient};
+use crate::enums::OrderSide;
+use crate::errors::InvalidParameter;
+use crate::types::requests::{AveragePriceSpec, ExchangeInfoSpec};
+use crate::types::responses::SymbolInfo;
+
+/**
+ * Caches symbol information to keep client-side validation up to date.
+ *
+ * Symbol information is fetched lazily from exchange info the first time a
+ * symbol is looked up and refreshed once it is older than the configured TTL,
+ * so callers no longer need to fetch and pass `SymbolInfo` themselves. The
+ * REST client keeps one for its pre-flight checks, available through
+ * `BinanceSpotRestClient::symbol_info_cache`.
+ *
+ * # Fields
+ * - `ttl`: Maximum age of cached symbol information before it is refreshed.
+ * - `symbols`: Cached symbol information and the time it was fetched, keyed by symbol.
+ */
+#[derive(Debug)]
+pub struct SymbolInfoCache {
+    ttl: Duration,
+    symbols: Mutex<HashMap<String, (Instant, SymbolInfo)>>,
+}
+
+impl SymbolInfoCache {
+    /**
+     * Creates an empty symbol information cache.
+     *
+     * # Arguments
+     * - `ttl`: Maximum age of cached symbol information before it is refreshed.
+     *
+     * # Returns
+     * - New SymbolInfoCache instance.
+     */
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            symbols: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /**
+     * Gets symbol information, fetching it when not cached or stale.
+     *
+     * # Arguments
+     * - `client`: Client used to fetch exchange information.
+     * - `symbol`: Trading symbol to look up.
+     *
+     * # Returns
+     * - `SymbolInfo`: Symbol information from exchange info.
+     */
+    pub async fn symbol_info<C: GeneralClient>(
+        &self,
+        client: &C,
+        symbol: &str,
+    ) -> Result<SymbolInfo> {
+        let symbol = symbol.to_uppercase();
+        let mut symbols = self.symbols.lock().await;
+
+        if let Some((fetched_at, symbol_info)) = symbols.get(&symbol)
+            && fetched_at.elapsed() < self.ttl
+        {
+            return Ok(symbol_info.clone());
+        }
+
+        let specification = ExchangeInfoSpec::new()
+            .with_symbol(symbol.as_str())
+            .with_show_permission_sets(true)
+            .build()?;
+        let symbol_info = client
+            .exchange_info(specification)
+            .await?
+            .symbols
+            .into_iter()
+            .find(|symbol_info| symbol_info.symbol == symbol)
+            .ok_or_else(|| InvalidParameter::new("symbol", format!("{} not found", symbol)))?;
+
+        symbols.insert(symbol, (Instant::now(), symbol_info.clone()));
+        Ok(symbol_info)
+    }
+
+    /**
+     * Validates an order price against the cached filters of its symbol.
+     *
+     * Fetches the current average price and applies the percent price filters
+     * through `SymbolInfo::validate_order`.
+     *
+     * # Arguments
+     * - `client`: Client used to fetch exchange and market data.
+     * - `symbol`: Trading symbol of the order.
+     * - `side`: Side of the order.
+     * - `price`: Limit price of the order.
+     *
+     * # Returns
+     * - `()`: Empty result if the price satisfies every percent price filter.
+     */
+    pub async fn validate_order<C: GeneralClient + MarketDataClient>(
+        &self,
+        client: &C,
+        symbol: &str,
+        side: OrderSide,
+        price: Decimal,
+    ) -> Result<()> {
+        let symbol_info = self.symbol_info(client, symbol).await?;
+        let average_price = client
+            .average_price(AveragePriceSpec::new(symbol).build()?)
+            .await?;
+        symbol_info.validate_order(side, price, average_price.price)
+    }
+
+    /**
+     * Discards all cached symbol information so the next lookups refresh it.
+     */
+    pub async fn invalidate(&self) {
+        self.symbols.lock().await.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::tests::helpers::{create_mock_rest_client, spawn_sequenced_mock_server};
+    use crate::types::responses::test_fixtures::BNBBTC_TRADING;
+
+    /**
+     * Tests that lookups within the TTL are served from a single fetch.
+     */
+    #[tokio::test]
+    async fn test_symbol_info_cache_hit() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![(200, BNBBTC_TRADING)]).await;
+        let client = create_mock_rest_client(&url);
+        let cache = SymbolInfoCache::new(Duration::from_secs(60));
+
+        // Act
+        let first = cache.symbol_info(&client, "bnbbtc").await.unwrap();
+        let second = cache.symbol_info(&client, "BNBBTC").await.unwrap();
+
+        // Assert
+        assert_eq!(first, second);
+        assert_eq!(first.symbol, "BNBBTC");
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert!(requests.lock().unwrap()[0].starts_with("GET /api/v3/exchangeInfo"));
+    }
+
+    /**
+     * Tests that symbol information is fetched again once the TTL has expired.
+     */
+    #[tokio::test]
+    async fn test_symbol_info_cache_refreshes_when_stale() {
+        // Arrange
+        let (url, requests) = spawn_sequenced_mock_server(vec![(200, BNBBTC_TRADING)]).await;
+        let client = create_mock_rest_client(&url);
+        let cache = SymbolInfoCache::new(Duration::from_millis(50));
+
+        // Act
+        cache.symbol_info(&client, "BNBBTC").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        cache.symbol_info(&client, "BNBBTC").await.unwrap();
+
+        // Assert
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
@@ -288,42 +288,12 @@ mod tests {
             Err(_) => return,
         };
 
-        let open_spec = match OpenOrderListsSpec::new().build() {
-            Ok(spec) => spec,
-            Err(_) => return,
-        };
-
-        let open_lists = match with_timeout(rest_client.open_order_lists(open_spec)).await {
-            Ok(lists) => lists,
-            Err(_) => return,
-        };
-
-        if open_lists.is_empty() {
-            return;
-        }
-
-        println!("Cleaning up {} open order lists...", open_lists.len());
-
-        let mut cancelled_count = 0;
-        for order_list in &open_lists {
-            let cancel_spec = match CancelOrderListSpec::new(&order_list.symbol)
-                .with_order_list_id(order_list.order_list_id)
-                .build()
-            {
-                Ok(spec) => spec,
-                Err(_) => continue,
-            };
-
-            if (with_timeout(rest_client.cancel_order_list(cancel_spec)).await).is_ok() {
-                cancelled_count += 1;
-            }
-        }
-
-        if cancelled_count > 0 {
+        if let Ok(cancelled) = with_timeout(rest_client.cancel_all_order_lists(4)).await
+            && !cancelled.is_empty()
+        {
             println!(
-                "🧹 Cleanup complete: {}/{} order lists cancelled.",
-                cancelled_count,
-                open_lists.len()
+                "🧹 Cleanup complete: {} order lists cancelled.",
+                cancelled.len()
             );
         }
     }
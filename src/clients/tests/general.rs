@@ -5,7 +5,7 @@ mod tests {
         errors::{BinanceError, ErrorCategory, RequestError},
         types::{
             requests::ExchangeInfoSpec,
-            responses::{ExchangeInfo, ServerTime},
+            responses::{ExchangeInfo, ServerTime, test_fixtures::BNBBTC_TRADING},
         },
     };
 
@@ -261,4 +261,36 @@ mod tests {
             }
         }
     }
+
+    /**
+     * Tests that REST and WebSocket clients parse an identical exchange info payload identically.
+     */
+    #[tokio::test]
+    async fn test_exchange_info_transport_parity() {
+        // Arrange
+        let (rest_url, _) = spawn_mock_server(200, BNBBTC_TRADING).await;
+        let (ws_url, ws_requests) = spawn_websocket_mock_server(BNBBTC_TRADING).await;
+        let rest_client = create_mock_rest_client(&rest_url);
+        let ws_client = crate::clients::websocket::client(create_mock_websocket_config(&ws_url))
+            .expect("WebSocket client creation");
+        let spec = || {
+            ExchangeInfoSpec::new()
+                .with_symbol("BNBBTC")
+                .build()
+                .expect("Spec validation")
+        };
+
+        // Act
+        let rest_info = with_timeout(rest_client.exchange_info(spec()))
+            .await
+            .expect("REST exchange info");
+        let ws_info = with_timeout(ws_client.exchange_info(spec()))
+            .await
+            .expect("WebSocket exchange info");
+
+        // Assert
+        assert_eq!(rest_info, ws_info);
+        assert_eq!(rest_info.symbols[0].symbol, "BNBBTC");
+        assert_eq!(ws_requests.lock().unwrap()[0]["method"], "exchangeInfo");
+    }
 }
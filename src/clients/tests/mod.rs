@@ -1,7 +1,8 @@
 mod account;
 mod advanced_trading;
 mod general;
-mod helpers;
+#[cfg(test)]
+pub(crate) mod helpers;
 mod market_data;
 mod ticker;
 mod trading;
@@ -2,6 +2,7 @@
 mod tests {
     use crate::{
         clients::{tests::helpers::*, r#trait::TickerClient},
+        enums::TickerType,
         errors::{BinanceError, ErrorCategory, RequestError},
         types::{
             requests::{
@@ -987,7 +988,7 @@ mod tests {
         // Act
         let rest_spec = Ticker24HrSpec::new()
             .with_symbol(test_symbol)
-            .with_ticker_type("MINI")
+            .with_type(TickerType::Mini)
             .build()
             .expect("Spec validation");
         let rest_stats = with_timeout(rest_client.ticker_24hr(rest_spec))
@@ -996,7 +997,7 @@ mod tests {
 
         let ws_spec = Ticker24HrSpec::new()
             .with_symbol(test_symbol)
-            .with_ticker_type("MINI")
+            .with_type(TickerType::Mini)
             .build()
             .expect("Spec validation");
         let ws_stats = with_timeout(ws_client.ticker_24hr(ws_spec))
@@ -1295,4 +1296,41 @@ mod tests {
             }
         }
     }
+
+    /**
+     * Tests that REST and WebSocket clients parse an identical ticker price payload identically.
+     */
+    #[tokio::test]
+    async fn test_ticker_price_transport_parity() {
+        // Arrange
+        const PRICE: &str = r#"{"symbol":"BNBBTC","price":"0.00249800"}"#;
+        let (rest_url, rest_requests) = spawn_mock_server(200, PRICE).await;
+        let (ws_url, ws_requests) = spawn_websocket_mock_server(PRICE).await;
+        let rest_client = create_mock_rest_client(&rest_url);
+        let ws_client = crate::clients::websocket::client(create_mock_websocket_config(&ws_url))
+            .expect("WebSocket client creation");
+        let spec = || {
+            TickerPriceSpec::new()
+                .with_symbol("BNBBTC")
+                .build()
+                .expect("Spec validation")
+        };
+
+        // Act
+        let rest_prices = with_timeout(rest_client.ticker_price(spec()))
+            .await
+            .expect("REST ticker price");
+        let ws_prices = with_timeout(ws_client.ticker_price(spec()))
+            .await
+            .expect("WebSocket ticker price");
+
+        // Assert
+        assert_eq!(rest_prices, ws_prices);
+        assert_eq!(rest_prices.len(), 1);
+        assert_valid_ticker_price(&rest_prices[0]);
+        assert!(rest_requests.lock().unwrap()[0].starts_with("GET /api/v3/ticker/price?"));
+        let ws_requests = ws_requests.lock().unwrap();
+        assert_eq!(ws_requests[0]["method"], "ticker.price");
+        assert_eq!(ws_requests[0]["params"]["symbol"], "BNBBTC");
+    }
 }
@@ -1,24 +1,40 @@
+use std::ops::ControlFlow;
+use std::time::Duration;
+
 use async_trait::async_trait;
+use futures_util::{StreamExt, future, stream};
 
 use crate::Result;
 use crate::{
+    BinanceError, Clock,
+    enums::OrderListOrderStatus,
+    errors::InvalidParameter,
     types::requests::{
-        AggregateTradesSpec, AllOrderListsSpec, AllOrdersSpec, AllocationSpec, AmendOrderSpec,
-        AveragePriceSpec, CancelAllOrdersSpec, CancelOrderListSpec, CancelOrderSpec,
-        CancelReplaceSpec, CommissionRatesSpec, ExchangeInfoSpec, HistoricalTradesSpec, KlinesSpec,
-        MyTradesSpec, OcoOrderSpec, OpenOrderListsSpec, OpenOrdersSpec, OrderBookSpec,
-        OrderListStatusSpec, OrderSpec, OtoOrderSpec, OtocoOrderSpec, PreventedMatchesSpec,
-        QueryOrderSpec, RecentTradesSpec, SorOrderSpec, Ticker24HrSpec, TickerBookSpec,
-        TickerPriceSpec, TickerRollingWindowSpec, TickerTradingDaySpec, Validated,
+        AccountInfoSpec, AggregateTradesSpec, AllOrderListsSpec, AllOrdersSpec, AllocationSpec,
+        AmendOrderSpec, AveragePriceSpec, CancelAllOrdersSpec, CancelOrderListSpec,
+        CancelOrderSpec, CancelReplaceSpec, CommissionRatesSpec, ExchangeInfoSpec,
+        HistoricalTradesSpec, KlinesSpec, MyTradesSpec, OcoOrderSpec, OpenOrderListsSpec,
+        OpenOrdersSpec, OrderBookSpec, OrderListStatusSpec, OrderSpec, OtoOrderSpec,
+        OtocoOrderSpec, PreventedMatchesSpec, QueryOrderSpec, RecentTradesSpec, SorOrderSpec,
+        Ticker24HrSpec, TickerBookSpec, TickerPriceSpec, TickerRollingWindowSpec,
+        TickerTradingDaySpec, Validated,
     },
     types::responses::{
         AccountInfo, AccountTrade, AggregateTrade, Allocation, AmendedOrder, AveragePrice,
-        CancelReplaceOrder, CancelledOrder, ExchangeInfo, Kline, Order, OrderBook, OrderList,
-        PreventedMatch, RateLimit, ServerTime, SymbolCommissionRates, TestOrder, TickerBook,
-        TickerPrice, TickerStatistics, Trade,
+        CancelReplaceOrder, CancelledOrder, ExchangeInfo, Kline, Order, OrderBook, OrderCountUsage,
+        OrderList, PreventedMatch, RateLimit, ServerTime, SymbolCommissionRates, SymbolInfo,
+        TestOrder, TickerBook, TickerPrice, TickerStatistics, Trade,
     },
 };
 
+const AGG_TRADES_PAGE_LIMIT: u16 = 1000;
+const ALLOCATIONS_PAGE_LIMIT: u32 = 1000;
+const ORDER_LIST_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const UNKNOWN_ORDER_LIST_CODE: i32 = -2011;
+const NO_SUCH_ORDER_CODE: i32 = -2013;
+const IDEMPOTENT_LOOKUP_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const IDEMPOTENT_LOOKUP_MAX_BACKOFF: Duration = Duration::from_secs(1);
+
 /**
  * General client operations for connectivity and exchange metadata.
  */
@@ -146,6 +162,47 @@ pub trait MarketDataClient {
         &self,
         specification: AveragePriceSpec<Validated>,
     ) -> Result<AveragePrice>;
+
+    /**
+     * Visits aggregate trades forward from an ID, one page at a time.
+     *
+     * Pages of up to 1000 trades are fetched and handed to the callback one
+     * trade at a time, so long histories never need to be held in memory.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol.
+     * - `from_id`: Aggregate trade ID to start from (inclusive).
+     * - `f`: Callback invoked per trade in ID order; returning `Break` stops iteration.
+     *
+     * # Returns
+     * - `()` once the trades are exhausted or the callback stops.
+     */
+    async fn agg_trades_for_each<F>(&self, symbol: &str, from_id: u64, mut f: F) -> Result<()>
+    where
+        F: FnMut(&AggregateTrade) -> ControlFlow<()> + Send,
+    {
+        let mut from_id = from_id;
+        loop {
+            let specification = AggregateTradesSpec::new(symbol)
+                .with_from_id(from_id)
+                .with_limit(AGG_TRADES_PAGE_LIMIT)
+                .build()?;
+            let trades = self.aggregate_trades(specification).await?;
+
+            for trade in &trades {
+                if f(trade).is_break() {
+                    return Ok(());
+                }
+            }
+
+            match trades.last() {
+                Some(last) if trades.len() == usize::from(AGG_TRADES_PAGE_LIMIT) => {
+                    from_id = last.id + 1;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
 }
 
 /**
@@ -235,7 +292,24 @@ pub trait AccountClient {
      * # Returns
      * - `AccountInfo`: Account information.
      */
-    async fn account_info(&self) -> Result<AccountInfo>;
+    async fn account_info(&self) -> Result<AccountInfo> {
+        self.account_info_with(AccountInfoSpec::new().build()?)
+            .await
+    }
+
+    /**
+     * Gets current account information with query parameters such as omitting zero balances.
+     *
+     * # Arguments
+     * - `specification`: Account information query specification.
+     *
+     * # Returns
+     * - `AccountInfo`: Account information.
+     */
+    async fn account_info_with(
+        &self,
+        specification: AccountInfoSpec<Validated>,
+    ) -> Result<AccountInfo>;
 
     /**
      * Gets commission rates for a specific trading symbol.
@@ -281,6 +355,24 @@ pub trait AccountClient {
      */
     async fn open_orders(&self, specification: OpenOrdersSpec<Validated>) -> Result<Vec<Order>>;
 
+    /**
+     * Reports how many open orders a symbol has against its order count filters.
+     *
+     * # Arguments
+     * - `symbol_info`: Symbol information from exchange info, providing the
+     *   MAX_NUM_ORDERS and MAX_NUM_ALGO_ORDERS filters.
+     *
+     * # Returns
+     * - `OrderCountUsage`: Open order counts, limits and remaining capacity.
+     */
+    async fn order_count_usage(&self, symbol_info: &SymbolInfo) -> Result<OrderCountUsage> {
+        let specification = OpenOrdersSpec::new()
+            .with_symbol(symbol_info.symbol.as_str())
+            .build()?;
+        let open_orders = self.open_orders(specification).await?;
+        Ok(OrderCountUsage::new(symbol_info, &open_orders))
+    }
+
     /**
      * Gets all account orders (active, canceled, or filled) for a symbol.
      *
@@ -330,6 +422,89 @@ pub trait AccountClient {
         &self,
         specification: AllocationSpec<Validated>,
     ) -> Result<Vec<Allocation>>;
+
+    /**
+     * Gets every allocation of a symbol within a time range, one page at a time.
+     *
+     * The first page is queried from `start_time`; later pages advance by
+     * `fromAllocationId`, since the endpoint does not combine it with a time
+     * range. Paging stops at the first allocation after `end_time`, so the
+     * range is not limited to 24 hours.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol.
+     * - `start_time`: Start of the range in milliseconds (inclusive).
+     * - `end_time`: End of the range in milliseconds (inclusive).
+     *
+     * # Returns
+     * - `Vec<Allocation>`: Allocations in the range, in allocation ID order.
+     */
+    async fn allocations_range(
+        &self,
+        symbol: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Vec<Allocation>> {
+        let mut allocations = Vec::new();
+        let mut specification = AllocationSpec::new(symbol).with_start_time(start_time);
+        loop {
+            let page = self
+                .allocations(specification.with_limit(ALLOCATIONS_PAGE_LIMIT).build()?)
+                .await?;
+            let is_last_page = page.len() < ALLOCATIONS_PAGE_LIMIT as usize;
+            let next_id = page.last().map(|allocation| allocation.allocation_id + 1);
+
+            for allocation in page {
+                if allocation.time > end_time {
+                    return Ok(allocations);
+                }
+                allocations.push(allocation);
+            }
+
+            match next_id {
+                Some(next_id) if !is_last_page => {
+                    let next_id = u32::try_from(next_id).map_err(|_| {
+                        InvalidParameter::new(
+                            "from_allocation_id",
+                            format!("{} exceeds the supported range", next_id),
+                        )
+                    })?;
+                    specification = AllocationSpec::new(symbol).with_from_allocation_id(next_id);
+                }
+                _ => return Ok(allocations),
+            }
+        }
+    }
+
+    /**
+     * Starts a new user data stream and returns its listen key.
+     *
+     * # Returns
+     * - `String`: Listen key for the user data stream.
+     */
+    async fn create_listen_key(&self) -> Result<String>;
+
+    /**
+     * Extends the validity of a user data stream listen key by 60 minutes.
+     *
+     * # Arguments
+     * - `listen_key`: Listen key to keep alive.
+     *
+     * # Returns
+     * - `()`: Ok if the listen key was extended.
+     */
+    async fn keepalive_listen_key(&self, listen_key: &str) -> Result<()>;
+
+    /**
+     * Closes a user data stream listen key.
+     *
+     * # Arguments
+     * - `listen_key`: Listen key to close.
+     *
+     * # Returns
+     * - `()`: Ok if the listen key was closed.
+     */
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()>;
 }
 
 /**
@@ -348,6 +523,79 @@ pub trait TradingClient {
      */
     async fn place_order(&self, specification: OrderSpec<Validated>) -> Result<Order>;
 
+    /**
+     * Places an order at most once, resolving attempts with an unknown outcome.
+     *
+     * The order is tagged with a client order ID (generated when the
+     * specification has none) and signed with a timestamp taken up front.
+     * When the attempt times out or otherwise fails without a definitive
+     * answer, the order is looked up by that ID with backoff until a lookup
+     * made after the receive window has passed, counted from that timestamp,
+     * since the matching engine may still accept the order until then. The
+     * order is never sent again: Binance only enforces client order ID
+     * uniqueness among open orders, so a resend could duplicate an order that
+     * already filled.
+     *
+     * # Arguments
+     * - `specification`: Order specification.
+     *
+     * # Returns
+     * - `Order`: Order placement result, or the queried order if the attempt was accepted.
+     * - `BinanceError::OrderOutcomeUnknown` if the order was still not found after the receive window.
+     */
+    async fn place_order_idempotent(&self, mut specification: OrderSpec<Validated>) -> Result<Order>
+    where
+        Self: AccountClient + SigningClient,
+    {
+        let client_order_id = specification
+            .client_order_id
+            .get_or_insert_with(|| uuid::Uuid::new_v4().to_string())
+            .clone();
+        let recv_window = specification
+            .recv_window
+            .unwrap_or_else(|| self.recv_window());
+        let timestamp = *specification.timestamp.insert(self.clock().now_millis());
+        let deadline = timestamp.saturating_add(recv_window);
+        let symbol = specification.symbol.clone();
+
+        let error = match self.place_order(specification).await {
+            Ok(order) => return Ok(order),
+            Err(error) => error,
+        };
+        if !is_outcome_unknown(&error) {
+            return Err(error);
+        }
+
+        let query = QueryOrderSpec::new(symbol.as_str())
+            .with_original_client_order_id(client_order_id.as_str())
+            .build()?;
+        let mut backoff = IDEMPOTENT_LOOKUP_INITIAL_BACKOFF;
+        loop {
+            let window_passed = self.clock().now_millis() >= deadline;
+            match self.order_status(query.clone()).await {
+                Ok(order) => return Ok(order),
+                Err(lookup_error)
+                    if lookup_error
+                        .downcast_ref::<BinanceError>()
+                        .and_then(BinanceError::api_code)
+                        == Some(NO_SUCH_ORDER_CODE) => {}
+                Err(lookup_error) => {
+                    return Err(
+                        lookup_error.context(BinanceError::OrderOutcomeUnknown { client_order_id })
+                    );
+                }
+            }
+
+            if window_passed {
+                return Err(error.context(BinanceError::OrderOutcomeUnknown { client_order_id }));
+            }
+            let remaining =
+                Duration::from_millis(deadline.saturating_sub(self.clock().now_millis()));
+            tokio::time::sleep(remaining.min(backoff)).await;
+            backoff = (backoff * 2).min(IDEMPOTENT_LOOKUP_MAX_BACKOFF);
+        }
+    }
+
     /**
      * Tests order placement without actually executing the order.
      *
@@ -370,6 +618,45 @@ pub trait TradingClient {
      */
     async fn cancel_order(&self, specification: CancelOrderSpec<Validated>) -> Result<Order>;
 
+    /**
+     * Cancels a set of orders on a symbol concurrently.
+     *
+     * Each order is cancelled independently, so an order that was already
+     * filled or cancelled (error -2011) does not prevent the others from
+     * being cancelled.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol the orders belong to.
+     * - `order_ids`: Identifiers of the orders to cancel.
+     *
+     * # Returns
+     * - `Vec<(u64, Result<Order>)>`: Cancellation result for each order ID, in input order.
+     */
+    async fn cancel_orders(
+        &self,
+        symbol: &str,
+        order_ids: &[u64],
+    ) -> Result<Vec<(u64, Result<Order>)>> {
+        let specifications = order_ids
+            .iter()
+            .map(|&order_id| {
+                CancelOrderSpec::new(symbol)
+                    .with_order_id(order_id)
+                    .build()
+                    .map(|specification| (order_id, specification))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let results = future::join_all(specifications.into_iter().map(
+            |(order_id, specification)| async move {
+                (order_id, self.cancel_order(specification).await)
+            },
+        ))
+        .await;
+
+        Ok(results)
+    }
+
     /**
      * Cancels all active orders on a symbol.
      *
@@ -387,6 +674,9 @@ pub trait TradingClient {
     /**
      * Cancels an existing order and immediately places a new order.
      *
+     * A partial failure (-2021) is returned as a successful response whose
+     * failed leg carries its error, so both outcomes can be inspected.
+     *
      * # Arguments
      * - `specification`: Cancel-replace order specification.
      *
@@ -459,6 +749,50 @@ pub trait TradingClient {
         specification: CancelOrderListSpec<Validated>,
     ) -> Result<OrderList>;
 
+    /**
+     * Cancels every open order list on the account.
+     *
+     * Cancellations run concurrently with at most `concurrency` requests in
+     * flight. Lists that are already gone by the time they are cancelled
+     * (error -2011) are skipped rather than treated as failures.
+     *
+     * # Arguments
+     * - `concurrency`: Maximum number of cancellations in flight at once.
+     *
+     * # Returns
+     * - `Vec<OrderList>`: Order lists that were cancelled.
+     */
+    async fn cancel_all_order_lists(&self, concurrency: usize) -> Result<Vec<OrderList>> {
+        let open_lists = self
+            .open_order_lists(OpenOrderListsSpec::new().build()?)
+            .await?;
+
+        let results: Vec<Result<OrderList>> = stream::iter(open_lists)
+            .map(|order_list| async move {
+                let specification = CancelOrderListSpec::new(order_list.symbol)
+                    .with_order_list_id(order_list.order_list_id)
+                    .build()?;
+                self.cancel_order_list(specification).await
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut cancelled = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(order_list) => cancelled.push(order_list),
+                Err(e)
+                    if e.downcast_ref::<BinanceError>()
+                        .and_then(BinanceError::api_code)
+                        == Some(UNKNOWN_ORDER_LIST_CODE) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(cancelled)
+    }
+
     /**
      * Gets the status of a specific order list.
      *
@@ -473,6 +807,40 @@ pub trait TradingClient {
         specification: OrderListStatusSpec<Validated>,
     ) -> Result<OrderList>;
 
+    /**
+     * Cancels an order list and polls its status until it is reported as done.
+     *
+     * Open order list queries may still include a cancelled list for a short
+     * while, so this waits for the exchange to confirm the removal.
+     *
+     * # Arguments
+     * - `specification`: Cancel order list specification.
+     * - `timeout`: Maximum time to wait for the list to reach `AllDone`.
+     *
+     * # Returns
+     * - `OrderList`: Last observed order list state.
+     */
+    async fn cancel_order_list_and_confirm(
+        &self,
+        specification: CancelOrderListSpec<Validated>,
+        timeout: Duration,
+    ) -> Result<OrderList> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut order_list = self.cancel_order_list(specification).await?;
+
+        while order_list.list_order_status != OrderListOrderStatus::AllDone
+            && tokio::time::Instant::now() < deadline
+        {
+            tokio::time::sleep(ORDER_LIST_POLL_INTERVAL).await;
+            let status_spec = OrderListStatusSpec::new()
+                .with_order_list_id(order_list.order_list_id)
+                .build()?;
+            order_list = self.order_list_status(status_spec).await?;
+        }
+
+        Ok(order_list)
+    }
+
     /**
      * Gets all order lists for the account.
      *
@@ -521,11 +889,32 @@ pub trait TradingClient {
     async fn test_sor_order(&self, specification: SorOrderSpec<Validated>) -> Result<TestOrder>;
 }
 
+/**
+ * Signing settings of a client that sends authenticated requests.
+ */
+pub trait SigningClient {
+    /**
+     * Gets the clock that timestamps signed requests.
+     *
+     * # Returns
+     * - `&dyn Clock`: Source of request timestamps.
+     */
+    fn clock(&self) -> &dyn Clock;
+
+    /**
+     * Gets the receive window of signed requests that set none.
+     *
+     * # Returns
+     * - `u64`: Receive window in milliseconds.
+     */
+    fn recv_window(&self) -> u64;
+}
+
 /**
  * Main client trait that combines all Binance API functionality.
  */
 pub trait BinanceSpotClient:
-    GeneralClient + MarketDataClient + TickerClient + AccountClient + TradingClient
+    GeneralClient + MarketDataClient + TickerClient + AccountClient + TradingClient + SigningClient
 {
 }
 
@@ -533,6 +922,33 @@ pub trait BinanceSpotClient:
  * Blanket implementation of BinanceClient for any type that implements all component traits.
  */
 impl<T> BinanceSpotClient for T where
-    T: GeneralClient + MarketDataClient + TickerClient + AccountClient + TradingClient
+    T: GeneralClient
+        + MarketDataClient
+        + TickerClient
+        + AccountClient
+        + TradingClient
+        + SigningClient
 {
 }
+
+/**
+ * Checks whether a failed order placement may still have reached the exchange.
+ *
+ * Transport failures (timeouts, dropped connections) and the API's own
+ * "send status unknown" errors (-1006, -1007) leave the order state
+ * undetermined; every other API error is a definitive rejection.
+ *
+ * # Arguments
+ * - `error`: Error returned by the placement attempt.
+ *
+ * # Returns
+ * - `bool`: True if the order may have been accepted.
+ */
+fn is_outcome_unknown(error: &anyhow::Error) -> bool {
+    match error.downcast_ref::<BinanceError>() {
+        Some(BinanceError::Api(api_error)) => matches!(api_error.code, -1006 | -1007),
+        Some(BinanceError::Timeout { .. }) => true,
+        Some(_) => false,
+        None => true,
+    }
+}
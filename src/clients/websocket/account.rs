@@ -1,15 +1,16 @@
 use async_trait::async_trait;
+use serde_json::json;
 
 use crate::Result;
 use crate::{
     clients::{r#trait::AccountClient, websocket::BinanceSpotWebSocketClient},
     types::{
         requests::{
-            AllOrdersSpec, AllocationSpec, CommissionRatesSpec, MyTradesSpec, OpenOrdersSpec,
-            PreventedMatchesSpec, QueryOrderSpec, Validated,
+            AccountInfoSpec, AllOrdersSpec, AllocationSpec, CommissionRatesSpec, MyTradesSpec,
+            OpenOrdersSpec, PreventedMatchesSpec, QueryOrderSpec, Validated,
         },
         responses::{
-            AccountInfo, AccountTrade, Allocation, Order, PreventedMatch, RateLimit,
+            AccountInfo, AccountTrade, Allocation, ListenKey, Order, PreventedMatch, RateLimit,
             SymbolCommissionRates,
         },
     },
@@ -17,8 +18,11 @@ use crate::{
 
 #[async_trait]
 impl AccountClient for BinanceSpotWebSocketClient {
-    async fn account_info(&self) -> Result<AccountInfo> {
-        self.signed_request("account.status", ()).await
+    async fn account_info_with(
+        &self,
+        specification: AccountInfoSpec<Validated>,
+    ) -> Result<AccountInfo> {
+        self.signed_request("account.status", specification).await
     }
 
     async fn commission_rates(
@@ -64,4 +68,43 @@ impl AccountClient for BinanceSpotWebSocketClient {
     ) -> Result<Vec<Allocation>> {
         self.signed_request("myAllocations", specification).await
     }
+
+    async fn create_listen_key(&self) -> Result<String> {
+        let api_key = self
+            .config
+            .api_key()
+            .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
+        let response: ListenKey = self
+            .request("userDataStream.start", json!({ "apiKey": api_key }))
+            .await?;
+        Ok(response.listen_key)
+    }
+
+    async fn keepalive_listen_key(&self, listen_key: &str) -> Result<()> {
+        let api_key = self
+            .config
+            .api_key()
+            .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
+        let _: serde_json::Value = self
+            .request(
+                "userDataStream.ping",
+                json!({ "apiKey": api_key, "listenKey": listen_key }),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn close_listen_key(&self, listen_key: &str) -> Result<()> {
+        let api_key = self
+            .config
+            .api_key()
+            .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
+        let _: serde_json::Value = self
+            .request(
+                "userDataStream.stop",
+                json!({ "apiKey": api_key, "listenKey": listen_key }),
+            )
+            .await?;
+        Ok(())
+    }
 }
@@ -1,18 +1,23 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use anyhow::Context;
 use futures_util::{SinkExt, StreamExt};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::{Value, json};
-use tokio::sync::{mpsc, oneshot, watch};
+use tokio::sync::{Semaphore, mpsc, oneshot, watch};
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::Result;
 use crate::{
-    BinanceConfig, WebSocketConfig, clients::common::generate_signature, errors::BinanceError,
+    BinanceConfig, Clock, ShutdownToken, WebSocketConfig,
+    clients::common::{generate_signature, record_correlation_id},
+    clients::r#trait::SigningClient,
+    config::wait_for_shutdown,
+    errors::BinanceError,
 };
 
 /**
@@ -42,6 +47,7 @@ pub enum ConnectionStatus {
  * - `request_sender`: Channel to send requests to the background task.
  * - `connection_handle`: Handle to the background connection task.
  * - `status_receiver`: Channel to receive connection status updates.
+ * - `request_permits`: Permits bounding the number of in-flight requests, if limited.
  */
 #[allow(dead_code)]
 pub struct BinanceSpotWebSocketClient {
@@ -49,6 +55,7 @@ pub struct BinanceSpotWebSocketClient {
     pub(crate) request_sender: Option<mpsc::UnboundedSender<TaskMessage>>,
     pub(crate) connection_handle: Option<tokio::task::JoinHandle<()>>,
     pub(crate) status_receiver: watch::Receiver<ConnectionStatus>,
+    pub(crate) request_permits: Option<Arc<Semaphore>>,
 }
 
 /**
@@ -94,14 +101,18 @@ impl BinanceSpotWebSocketClient {
     pub fn new(config: BinanceConfig<WebSocketConfig>) -> Result<Self> {
         let (request_sender, request_receiver) = mpsc::unbounded_channel::<TaskMessage>();
         let (status_sender, status_receiver) = watch::channel(ConnectionStatus::Connecting);
-        let ws_url = format!("{}/ws-api/v3", config.url());
+        let ws_url = config.websocket_config().api_url();
         let ws_config = config.websocket_config().clone();
+        let request_permits = ws_config
+            .max_concurrent_requests
+            .map(|max| Arc::new(Semaphore::new(max)));
 
         let connection_handle = tokio::spawn(Self::connection_task(
             ws_url,
             request_receiver,
             ws_config,
             status_sender,
+            config.shutdown_token().cloned(),
         ));
 
         Ok(Self {
@@ -109,6 +120,7 @@ impl BinanceSpotWebSocketClient {
             request_sender: Some(request_sender),
             connection_handle: Some(connection_handle),
             status_receiver,
+            request_permits,
         })
     }
 
@@ -567,24 +579,31 @@ impl BinanceSpotWebSocketClient {
      * - `request_receiver`: Channel to receive requests from the client.
      * - `ws_config`: WebSocket configuration for connection management.
      * - `status_sender`: Channel to send connection status updates.
+     * - `shutdown_token`: Optional shared signal that closes the connection when triggered.
      */
     async fn connection_task(
         url: String,
         mut request_receiver: mpsc::UnboundedReceiver<TaskMessage>,
         ws_config: WebSocketConfig,
         status_sender: watch::Sender<ConnectionStatus>,
+        shutdown_token: Option<ShutdownToken>,
     ) {
         let mut reconnect_attempts = 0;
 
         loop {
-            let ws_stream = match Self::establish_connection(
-                &url,
-                &ws_config,
-                &mut reconnect_attempts,
-                &status_sender,
-            )
-            .await
-            {
+            let connection = tokio::select! {
+                connection = Self::establish_connection(
+                    &url,
+                    &ws_config,
+                    &mut reconnect_attempts,
+                    &status_sender,
+                ) => connection,
+                _ = wait_for_shutdown(shutdown_token.as_ref()) => {
+                    let _ = status_sender.send(ConnectionStatus::Disconnected);
+                    return;
+                }
+            };
+            let ws_stream = match connection {
                 Some(stream) => stream,
                 None => break,
             };
@@ -630,6 +649,11 @@ impl BinanceSpotWebSocketClient {
                             }
                         }
                     }
+                    _ = wait_for_shutdown(shutdown_token.as_ref()) => {
+                        let (response_sender, _) = oneshot::channel();
+                        Self::handle_shutdown(response_sender, &mut write, &mut read, &mut pending_requests, &status_sender).await;
+                        return;
+                    }
                     message = read.next() => {
                         message_count += 1;
 
@@ -657,7 +681,13 @@ impl BinanceSpotWebSocketClient {
                 let _ = sender.send(Err(anyhow::anyhow!("WebSocket connection lost")));
             }
 
-            tokio::time::sleep(ws_config.initial_retry_delay).await;
+            tokio::select! {
+                _ = tokio::time::sleep(ws_config.initial_retry_delay) => {}
+                _ = wait_for_shutdown(shutdown_token.as_ref()) => {
+                    let _ = status_sender.send(ConnectionStatus::Disconnected);
+                    return;
+                }
+            }
         }
     }
 
@@ -672,14 +702,8 @@ impl BinanceSpotWebSocketClient {
      */
     fn parse_websocket_response(response: Value) -> Result<Value> {
         if let Some(error) = response.get("error") {
-            if let (Some(code), Some(msg)) = (error.get("code"), error.get("msg"))
-                && let (Some(code_num), Some(msg_str)) = (code.as_i64(), msg.as_str())
-            {
-                return Err(BinanceError::Api(crate::errors::ApiError::new(
-                    code_num as i32,
-                    msg_str.to_string(),
-                ))
-                .into());
+            if let Some(api_error) = crate::errors::ApiError::from_json(error) {
+                return Err(BinanceError::Api(api_error).into());
             }
             return Err(anyhow::anyhow!("WebSocket error: {}", error));
         }
@@ -707,12 +731,14 @@ impl BinanceSpotWebSocketClient {
      * # Returns
      * - `Value`: The response result.
      */
-    #[instrument(skip(self, params), fields(method = method))]
+    #[instrument(skip(self, params), fields(method = method, correlation_id = tracing::field::Empty))]
     pub(crate) async fn send_request<T: Serialize>(
         &self,
         method: &str,
         params: T,
     ) -> Result<Value> {
+        record_correlation_id(&self.config);
+
         let start = std::time::Instant::now();
         let prep_start = std::time::Instant::now();
 
@@ -721,6 +747,23 @@ impl BinanceSpotWebSocketClient {
             .as_ref()
             .ok_or_else(|| anyhow::anyhow!("WebSocket client not initialized"))?;
 
+        let request_timeout = self.config.websocket_config().request_timeout;
+        let deadline = tokio::time::Instant::now() + request_timeout;
+        let timeout_error = || BinanceError::Timeout {
+            method: method.to_string(),
+            timeout: request_timeout,
+        };
+
+        let _permit = match &self.request_permits {
+            Some(permits) => Some(
+                tokio::time::timeout_at(deadline, permits.acquire())
+                    .await
+                    .map_err(|_| timeout_error())?
+                    .context("Request concurrency limiter closed")?,
+            ),
+            None => None,
+        };
+
         let request_id = Uuid::new_v4().to_string();
         let (response_sender, response_receiver) = oneshot::channel();
 
@@ -757,9 +800,9 @@ impl BinanceSpotWebSocketClient {
         let channel_send_duration = channel_start.elapsed();
 
         let wait_start = std::time::Instant::now();
-        let result = tokio::time::timeout(std::time::Duration::from_secs(30), response_receiver)
+        let result = tokio::time::timeout_at(deadline, response_receiver)
             .await
-            .context("WebSocket request timeout")?
+            .map_err(|_| timeout_error())?
             .context("Failed to receive WebSocket response")?;
         let wait_duration = wait_start.elapsed();
 
@@ -787,12 +830,14 @@ impl BinanceSpotWebSocketClient {
      * # Returns
      * - `Value`: JSON response.
      */
-    #[instrument(skip(self, params), fields(method = method))]
+    #[instrument(skip(self, params), fields(method = method, correlation_id = tracing::field::Empty))]
     pub(crate) async fn send_signed_request<T: Serialize>(
         &self,
         method: &str,
         params: T,
     ) -> Result<Value> {
+        record_correlation_id(&self.config);
+
         let start = std::time::Instant::now();
         let prep_start = std::time::Instant::now();
 
@@ -801,8 +846,14 @@ impl BinanceSpotWebSocketClient {
             .signer()
             .ok_or_else(|| anyhow::anyhow!("No authentication configured"))?;
 
-        let (signature, query_string) =
-            generate_signature(&params, signer.as_ref(), self.config.recv_window(), true).await?;
+        let (signature, query_string) = generate_signature(
+            &params,
+            signer.as_ref(),
+            self.config.clock(),
+            self.config.recv_window(),
+            true,
+        )
+        .await?;
 
         let mut final_params = serde_json::Map::new();
         for pair in query_string.split('&') {
@@ -878,6 +929,16 @@ impl BinanceSpotWebSocketClient {
     }
 }
 
+impl SigningClient for BinanceSpotWebSocketClient {
+    fn clock(&self) -> &dyn Clock {
+        self.config.clock()
+    }
+
+    fn recv_window(&self) -> u64 {
+        self.config.recv_window()
+    }
+}
+
 impl Drop for BinanceSpotWebSocketClient {
     /**
      * Cleanup when the client is dropped.
@@ -898,7 +959,10 @@ impl Drop for BinanceSpotWebSocketClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{BinanceConfig, WebSocketConfig, errors::BinanceError};
+    use crate::{
+        BinanceConfig, WebSocketConfig,
+        errors::{ApiError, BinanceError, ErrorCategory, RequestError},
+    };
     use serde_json::json;
     use std::time::Duration;
 
@@ -944,6 +1008,187 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    /**
+     * Tests that the selected API version is used in the connection URL and validated.
+     */
+    #[tokio::test]
+    async fn test_api_version() {
+        // Arrange
+        let builder = || BinanceConfig::<WebSocketConfig>::builder().with_testnet();
+
+        // Act
+        let default_config = builder().build().unwrap();
+        let versioned_config = builder().with_api_version("v4").build().unwrap();
+        let invalid_results =
+            ["4", "v", "v3beta", "/v4"].map(|version| builder().with_api_version(version).build());
+
+        // Assert
+        assert_eq!(
+            default_config.websocket_config().api_url(),
+            "wss://ws-api.testnet.binance.vision/ws-api/v3"
+        );
+        assert_eq!(
+            versioned_config.websocket_config().api_url(),
+            "wss://ws-api.testnet.binance.vision/ws-api/v4"
+        );
+        assert!(invalid_results.iter().all(Result::is_err));
+    }
+
+    /**
+     * Tests that a concurrency limit of zero is rejected when the configuration is built.
+     */
+    #[test]
+    fn test_zero_max_concurrent_requests_rejected() {
+        // Arrange
+        let builder = BinanceConfig::<WebSocketConfig>::builder()
+            .with_testnet()
+            .with_max_concurrent_requests(0);
+
+        // Act
+        let result = builder.build();
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    /**
+     * Tests that a request without a response fails with a timeout error after the configured duration.
+     */
+    #[tokio::test]
+    async fn test_request_timeout() {
+        // Arrange
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while websocket.next().await.is_some() {}
+        });
+        let mut config = BinanceConfig::<WebSocketConfig>::builder()
+            .with_testnet()
+            .with_request_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        config.websocket_config_mut().url = url;
+        let mut client = BinanceSpotWebSocketClient::new(config).unwrap();
+        client.wait_for_connection().await.unwrap();
+
+        // Act
+        let start = std::time::Instant::now();
+        let error = client.send_request("ping", ()).await.unwrap_err();
+
+        // Assert
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            error.downcast_ref::<BinanceError>(),
+            Some(BinanceError::Timeout { method, timeout })
+                if method == "ping" && *timeout == Duration::from_millis(200)
+        ));
+    }
+
+    /**
+     * Tests that time spent waiting for a concurrency permit counts towards the request timeout.
+     */
+    #[tokio::test]
+    async fn test_request_timeout_includes_permit_wait() {
+        // Arrange
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while websocket.next().await.is_some() {}
+        });
+        let mut config = BinanceConfig::<WebSocketConfig>::builder()
+            .with_testnet()
+            .with_request_timeout(Duration::from_millis(300))
+            .with_max_concurrent_requests(1)
+            .build()
+            .unwrap();
+        config.websocket_config_mut().url = url;
+        let mut client = BinanceSpotWebSocketClient::new(config).unwrap();
+        client.wait_for_connection().await.unwrap();
+
+        // Act
+        let start = std::time::Instant::now();
+        let (first, second) = tokio::join!(
+            client.send_request("ping", ()),
+            client.send_request("ping", ())
+        );
+
+        // Assert
+        assert!(start.elapsed() < Duration::from_millis(550));
+        for result in [first, second] {
+            assert!(matches!(
+                result.unwrap_err().downcast_ref::<BinanceError>(),
+                Some(BinanceError::Timeout { .. })
+            ));
+        }
+    }
+
+    /**
+     * Tests that requests beyond the concurrency limit are queued and each response reaches its caller.
+     */
+    #[tokio::test]
+    async fn test_max_concurrent_requests() {
+        // Arrange
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let max_in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let (observed, peak) = (in_flight.clone(), max_in_flight.clone());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let (mut sink, mut stream) = websocket.split();
+            let (response_sender, mut response_receiver) = mpsc::unbounded_channel::<Value>();
+            loop {
+                tokio::select! {
+                    Some(Ok(Message::Text(text))) = stream.next() => {
+                        let request: Value = serde_json::from_str(&text).unwrap();
+                        let current = observed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                        peak.fetch_max(current, std::sync::atomic::Ordering::SeqCst);
+                        let (observed, response_sender) = (observed.clone(), response_sender.clone());
+                        tokio::spawn(async move {
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                            observed.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+                            let _ = response_sender.send(json!({
+                                "id": request["id"],
+                                "status": 200,
+                                "result": request["params"],
+                            }));
+                        });
+                    }
+                    Some(response) = response_receiver.recv() => {
+                        sink.send(Message::Text(response.to_string())).await.unwrap();
+                    }
+                    else => break,
+                }
+            }
+        });
+        let mut config = BinanceConfig::<WebSocketConfig>::builder()
+            .with_testnet()
+            .with_max_concurrent_requests(2)
+            .build()
+            .unwrap();
+        config.websocket_config_mut().url = url;
+        let mut client = BinanceSpotWebSocketClient::new(config).unwrap();
+        client.wait_for_connection().await.unwrap();
+
+        // Act
+        let results = futures_util::future::join_all(
+            (0..6).map(|index| client.send_request("echo", json!({ "index": index }))),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(max_in_flight.load(std::sync::atomic::Ordering::SeqCst), 2);
+        for (index, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap(), json!({ "index": index }));
+        }
+    }
+
     /**
      * Tests parse_websocket_response with successful response.
      */
@@ -988,6 +1233,78 @@ mod tests {
         assert!(matches!(binance_error, Some(BinanceError::Api(_))));
     }
 
+    /**
+     * Tests that a signed request carries the timestamp of the configured clock.
+     */
+    #[tokio::test]
+    async fn test_signed_request_uses_configured_clock() {
+        // Arrange
+        use futures_util::{SinkExt, StreamExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let Some(Ok(Message::Text(text))) = ws.next().await else {
+                panic!("No request received");
+            };
+            let request: Value = serde_json::from_str(&text).unwrap();
+            let response = json!({ "id": request["id"], "status": 200, "result": {} });
+            ws.send(Message::Text(response.to_string())).await.unwrap();
+            request
+        });
+        let mut config = crate::clients::tests::helpers::create_mock_websocket_config(&url);
+        config.set_clock(crate::FixedClock(1_700_000_000_000));
+        let client = BinanceSpotWebSocketClient::new(config).unwrap();
+
+        // Act
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.send_signed_request("account.status", json!({})),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        assert!(result.is_ok(), "{:?}", result);
+        let request = server.await.unwrap();
+        assert_eq!(request["method"], "account.status");
+        assert_eq!(request["params"]["timestamp"], "1700000000000");
+        assert_eq!(request["params"]["apiKey"], "mock_api_key");
+        assert!(request["params"]["signature"].is_string());
+    }
+
+    /**
+     * Tests that a WebSocket error frame maps to the same error as the REST client.
+     */
+    #[tokio::test]
+    async fn test_parse_websocket_response_error_matches_rest() {
+        // Arrange
+        let frame = r#"{"id":"test-123","status":400,"error":{"code":-1102,"msg":"Mandatory parameter 'symbol' was not sent, was empty/null, or malformed."},"rateLimits":[]}"#;
+        let response: Value = serde_json::from_str(frame).unwrap();
+        let rest_body = json!({
+            "code": -1102,
+            "msg": "Mandatory parameter 'symbol' was not sent, was empty/null, or malformed."
+        });
+
+        // Act
+        let ws_error = BinanceSpotWebSocketClient::parse_websocket_response(response).unwrap_err();
+
+        // Assert
+        let ws_api_error = ws_error
+            .downcast_ref::<BinanceError>()
+            .and_then(BinanceError::api_error)
+            .expect("WebSocket API error");
+        let rest_api_error = ApiError::from_json(&rest_body).expect("REST API error");
+        assert_eq!(ws_api_error, &rest_api_error);
+        assert_eq!(ws_api_error.category, ErrorCategory::RequestIssues);
+        assert_eq!(
+            ws_api_error.request_error,
+            Some(RequestError::MandatoryParamEmptyOrMalformed)
+        );
+    }
+
     /**
      * Tests parse_websocket_response with WebSocket status error.
      */
@@ -1140,7 +1457,7 @@ mod tests {
             Err(e) => {
                 let error_string = e.to_string();
                 assert!(
-                    error_string.contains("WebSocket request timeout")
+                    error_string.contains("timed out after")
                         || error_string.contains("Failed to send request")
                         || error_string.contains("WebSocket client not initialized"),
                     "Should be timeout or connection error, not serialization error: {}",
@@ -1171,7 +1488,7 @@ mod tests {
             Err(e) => {
                 let error_string = e.to_string();
                 assert!(
-                    error_string.contains("WebSocket request timeout")
+                    error_string.contains("timed out after")
                         || error_string.contains("Failed to receive WebSocket response")
                         || error_string.contains("Failed to parse response"),
                     "Should be timeout or parse error: {}",
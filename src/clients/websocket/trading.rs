@@ -7,7 +7,8 @@ use crate::{
         requests::{
             AllOrderListsSpec, AmendOrderSpec, CancelAllOrdersSpec, CancelOrderListSpec,
             CancelOrderSpec, CancelReplaceSpec, OcoOrderSpec, OpenOrderListsSpec,
-            OrderListStatusSpec, OrderSpec, OtoOrderSpec, OtocoOrderSpec, SorOrderSpec, Validated,
+            OrderListStatusSpec, OrderResponseDefault, OrderSpec, OtoOrderSpec, OtocoOrderSpec,
+            SorOrderSpec, Validated,
         },
         responses::{
             AmendedOrder, CancelReplaceOrder, CancelledOrder, Order, OrderList, TestOrder,
@@ -18,10 +19,14 @@ use crate::{
 #[async_trait]
 impl TradingClient for BinanceSpotWebSocketClient {
     async fn place_order(&self, specification: OrderSpec<Validated>) -> Result<Order> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("order.place", specification).await
     }
 
     async fn test_order(&self, specification: OrderSpec<Validated>) -> Result<TestOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("order.test", specification).await
     }
 
@@ -41,8 +46,11 @@ impl TradingClient for BinanceSpotWebSocketClient {
         &self,
         specification: CancelReplaceSpec<Validated>,
     ) -> Result<CancelReplaceOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("order.cancelReplace", specification)
             .await
+            .or_else(CancelReplaceOrder::from_partial_failure)
     }
 
     async fn amend_order(&self, specification: AmendOrderSpec<Validated>) -> Result<AmendedOrder> {
@@ -51,11 +59,15 @@ impl TradingClient for BinanceSpotWebSocketClient {
     }
 
     async fn place_oco_order(&self, specification: OcoOrderSpec<Validated>) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("orderList.place.oco", specification)
             .await
     }
 
     async fn place_oto_order(&self, specification: OtoOrderSpec<Validated>) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("orderList.place.oto", specification)
             .await
     }
@@ -64,6 +76,8 @@ impl TradingClient for BinanceSpotWebSocketClient {
         &self,
         specification: OtocoOrderSpec<Validated>,
     ) -> Result<OrderList> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("orderList.place.otoco", specification)
             .await
     }
@@ -98,10 +112,14 @@ impl TradingClient for BinanceSpotWebSocketClient {
     }
 
     async fn place_sor_order(&self, specification: SorOrderSpec<Validated>) -> Result<Order> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("sor.order.place", specification).await
     }
 
     async fn test_sor_order(&self, specification: SorOrderSpec<Validated>) -> Result<TestOrder> {
+        let specification = specification
+            .with_default_response_type(self.config.websocket_config().default_order_resp_type);
         self.signed_request("sor.order.test", specification).await
     }
 }
@@ -2,18 +2,18 @@
 use std::sync::Arc;
 
 use crate::Result;
+use crate::enums::OrderResponseType;
 use crate::{
-    auth::{Ed25519Signer, SignatureProvider},
+    auth::{Clock, Ed25519Signer, OffsetClock, SignatureProvider, SystemClock},
     config::{
-        RestConfig, StreamConfig, WebSocketConfig,
+        BinanceEndpoint, CorrelationIdProvider, RestConfig, ShutdownToken, StreamConfig,
+        WebSocketConfig,
         stream_config::{StreamInfo, StreamMode, StreamType},
     },
-    errors::InvalidUrl,
+    errors::{InvalidConfig, InvalidUrl},
     streams::specs::StreamSpec,
 };
 
-const BINANCE_API_MAINNET: &str = "https://api.binance.com";
-const BINANCE_API_TESTNET: &str = "https://testnet.binance.vision";
 const BINANCE_WS_MAINNET: &str = "wss://ws-api.binance.com:443";
 const BINANCE_WS_TESTNET: &str = "wss://ws-api.testnet.binance.vision";
 const BINANCE_STREAM_MAINNET: &str = "wss://stream.binance.com:9443";
@@ -23,6 +23,9 @@ const BINANCE_STREAM_USER_TESTNET: &str = "wss://ws-api.testnet.binance.vision/w
 #[derive(Debug)]
 pub struct BinanceConfig<T> {
     signer: Option<Arc<dyn SignatureProvider>>,
+    clock: Arc<dyn Clock>,
+    correlation_id_provider: Option<CorrelationIdProvider>,
+    shutdown_token: Option<ShutdownToken>,
     recv_window: u64,
     specific_config: T,
 }
@@ -32,6 +35,9 @@ pub struct BinanceConfigBuilder {
     testnet: bool,
     recv_window: u64,
     credentials: Option<(String, String)>,
+    clock: Arc<dyn Clock>,
+    correlation_id_provider: Option<CorrelationIdProvider>,
+    shutdown_token: Option<ShutdownToken>,
 }
 
 #[derive(Debug)]
@@ -73,6 +79,20 @@ impl<T> BinanceConfig<T> {
         self.recv_window
     }
 
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn correlation_id(&self) -> Option<String> {
+        self.correlation_id_provider
+            .as_ref()
+            .map(CorrelationIdProvider::next_id)
+    }
+
+    pub fn shutdown_token(&self) -> Option<&ShutdownToken> {
+        self.shutdown_token.as_ref()
+    }
+
     pub fn api_key(&self) -> Option<&str> {
         self.signer.as_ref().map(|s| s.get_api_key())
     }
@@ -84,6 +104,18 @@ impl<T> BinanceConfig<T> {
     pub fn set_recv_window(&mut self, recv_window_ms: u64) {
         self.recv_window = recv_window_ms;
     }
+
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        self.clock = Arc::new(clock);
+    }
+
+    pub(crate) fn shift_clock(&mut self, offset_ms: i64) {
+        self.clock = Arc::new(OffsetClock::new(self.clock.clone(), offset_ms));
+    }
+
+    pub fn set_correlation_id_provider(&mut self, provider: Arc<dyn Fn() -> String + Send + Sync>) {
+        self.correlation_id_provider = Some(CorrelationIdProvider::new(provider));
+    }
 }
 
 impl BinanceConfig<WebSocketConfig> {
@@ -95,6 +127,11 @@ impl BinanceConfig<WebSocketConfig> {
         &self.specific_config
     }
 
+    #[cfg(test)]
+    pub(crate) fn websocket_config_mut(&mut self) -> &mut WebSocketConfig {
+        &mut self.specific_config
+    }
+
     pub fn url(&self) -> &str {
         &self.specific_config.url
     }
@@ -109,6 +146,11 @@ impl BinanceConfig<RestConfig> {
         &self.specific_config
     }
 
+    #[cfg(test)]
+    pub(crate) fn rest_config_mut(&mut self) -> &mut RestConfig {
+        &mut self.specific_config
+    }
+
     pub fn url(&self) -> &str {
         &self.specific_config.url
     }
@@ -123,6 +165,11 @@ impl BinanceConfig<StreamConfig> {
         &self.specific_config
     }
 
+    #[cfg(test)]
+    pub(crate) fn stream_config_mut(&mut self) -> &mut StreamConfig {
+        &mut self.specific_config
+    }
+
     pub fn market_data_url(&self) -> &str {
         &self.specific_config.market_data_url
     }
@@ -138,6 +185,9 @@ impl BinanceConfigBuilder {
             testnet: false,
             recv_window: 5000,
             credentials: None,
+            clock: Arc::new(SystemClock),
+            correlation_id_provider: None,
+            shutdown_token: None,
         }
     }
 
@@ -156,6 +206,24 @@ impl BinanceConfigBuilder {
         self
     }
 
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    pub fn with_correlation_id_provider(
+        mut self,
+        provider: Arc<dyn Fn() -> String + Send + Sync>,
+    ) -> Self {
+        self.correlation_id_provider = Some(CorrelationIdProvider::new(provider));
+        self
+    }
+
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.shutdown_token = Some(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -166,15 +234,15 @@ impl BinanceConfigBuilder {
     }
 
     pub fn for_rest(self) -> RestBinanceConfigBuilder {
-        let url = if self.testnet {
-            BINANCE_API_TESTNET
+        let endpoint = if self.testnet {
+            BinanceEndpoint::Testnet
         } else {
-            BINANCE_API_MAINNET
+            BinanceEndpoint::Mainnet
         };
 
         RestBinanceConfigBuilder {
             base: self,
-            rest_config: RestConfig::builder().with_url(url).build(),
+            rest_config: RestConfig::builder().with_endpoint(endpoint).build(),
         }
     }
 
@@ -212,13 +280,13 @@ impl BinanceConfigBuilder {
 impl RestBinanceConfigBuilder {
     pub fn with_testnet(mut self) -> Self {
         self.base = self.base.with_testnet();
-        self.rest_config.url = BINANCE_API_TESTNET.to_string();
+        self.rest_config.url = BinanceEndpoint::Testnet.url().to_string();
         self
     }
 
     pub fn with_mainnet(mut self) -> Self {
         self.base = self.base.with_mainnet();
-        self.rest_config.url = BINANCE_API_MAINNET.to_string();
+        self.rest_config.url = BinanceEndpoint::Mainnet.url().to_string();
         self
     }
 
@@ -227,6 +295,24 @@ impl RestBinanceConfigBuilder {
         self
     }
 
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.base = self.base.with_clock(clock);
+        self
+    }
+
+    pub fn with_correlation_id_provider(
+        mut self,
+        provider: Arc<dyn Fn() -> String + Send + Sync>,
+    ) -> Self {
+        self.base = self.base.with_correlation_id_provider(provider);
+        self
+    }
+
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.base = self.base.with_shutdown_token(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -256,15 +342,67 @@ impl RestBinanceConfigBuilder {
         self
     }
 
+    pub fn with_endpoint(mut self, endpoint: BinanceEndpoint) -> Self {
+        self.rest_config.url = endpoint.url().to_string();
+        self
+    }
+
+    pub fn with_endpoint_failover(mut self, endpoints: &[BinanceEndpoint]) -> Self {
+        if let Some((primary, fallbacks)) = endpoints.split_first() {
+            self.rest_config.url = primary.url().to_string();
+            self.rest_config.failover_urls =
+                fallbacks.iter().map(|e| e.url().to_string()).collect();
+        }
+        self
+    }
+
     pub fn with_connection_timeout(mut self, timeout: std::time::Duration) -> Self {
-        self.rest_config = RestConfig::builder()
-            .with_url(self.rest_config.url.clone())
-            .with_connection_timeout(timeout)
-            .with_request_timeout(self.rest_config.request_timeout)
-            .with_pool_max_idle_per_host(self.rest_config.pool_max_idle_per_host)
-            .with_pool_idle_timeout(self.rest_config.pool_idle_timeout)
-            .with_user_agent(self.rest_config.user_agent.clone())
-            .build();
+        self.rest_config.connection_timeout = timeout;
+        self
+    }
+
+    pub fn with_endpoint_timeout_override(
+        mut self,
+        endpoint: impl Into<String>,
+        timeout: std::time::Duration,
+    ) -> Self {
+        self.rest_config
+            .endpoint_timeouts
+            .insert(endpoint.into(), timeout);
+        self
+    }
+
+    pub fn with_endpoint_weights(mut self, weights: std::collections::HashMap<&str, u32>) -> Self {
+        self.rest_config.endpoint_weights.extend(
+            weights
+                .into_iter()
+                .map(|(endpoint, weight)| (endpoint.to_string(), weight)),
+        );
+        self
+    }
+
+    pub fn with_gzip(mut self, enabled: bool) -> Self {
+        self.rest_config.gzip = enabled;
+        self
+    }
+
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.rest_config.max_response_bytes = max_bytes;
+        self
+    }
+
+    pub fn with_keepalive_warmup(mut self, interval: std::time::Duration) -> Self {
+        self.rest_config.keepalive_warmup = Some(interval);
+        self
+    }
+
+    pub fn with_default_order_resp_type(mut self, response_type: OrderResponseType) -> Self {
+        self.rest_config.default_order_resp_type = Some(response_type);
+        self
+    }
+
+    pub fn with_auto_cooldown(mut self, enabled: bool) -> Self {
+        self.rest_config.auto_cooldown = enabled;
         self
     }
 
@@ -281,6 +419,9 @@ impl RestBinanceConfigBuilder {
 
         Ok(BinanceConfig {
             signer,
+            clock: self.base.clock,
+            correlation_id_provider: self.base.correlation_id_provider,
+            shutdown_token: self.base.shutdown_token,
             recv_window: self.base.recv_window,
             specific_config: self.rest_config,
         })
@@ -305,6 +446,24 @@ impl WebSocketBinanceConfigBuilder {
         self
     }
 
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.base = self.base.with_clock(clock);
+        self
+    }
+
+    pub fn with_correlation_id_provider(
+        mut self,
+        provider: Arc<dyn Fn() -> String + Send + Sync>,
+    ) -> Self {
+        self.base = self.base.with_correlation_id_provider(provider);
+        self
+    }
+
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.base = self.base.with_shutdown_token(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -335,13 +494,27 @@ impl WebSocketBinanceConfigBuilder {
     }
 
     pub fn with_max_reconnects(mut self, max: u32) -> Self {
-        self.websocket_config = WebSocketConfig::builder()
-            .with_url(self.websocket_config.url.clone())
-            .with_max_reconnects(max)
-            .with_initial_retry_delay(self.websocket_config.initial_retry_delay)
-            .with_max_retry_delay(self.websocket_config.max_retry_delay)
-            .with_connection_timeout(self.websocket_config.connection_timeout)
-            .build();
+        self.websocket_config.max_reconnect_attempts = max;
+        self
+    }
+
+    pub fn with_default_order_resp_type(mut self, response_type: OrderResponseType) -> Self {
+        self.websocket_config.default_order_resp_type = Some(response_type);
+        self
+    }
+
+    pub fn with_api_version(mut self, version: impl Into<String>) -> Self {
+        self.websocket_config.api_version = version.into();
+        self
+    }
+
+    pub fn with_request_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.websocket_config.request_timeout = timeout;
+        self
+    }
+
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.websocket_config.max_concurrent_requests = Some(max);
         self
     }
 
@@ -350,6 +523,22 @@ impl WebSocketBinanceConfigBuilder {
             return Err(InvalidUrl::invalid_scheme(&self.websocket_config.url, "wss://").into());
         }
 
+        let api_version = &self.websocket_config.api_version;
+        let is_valid_version = api_version
+            .strip_prefix('v')
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()));
+        if !is_valid_version {
+            return Err(InvalidConfig::new(
+                "api_version",
+                format!("'{}' must be of the form v<number> (e.g., v3)", api_version),
+            )
+            .into());
+        }
+
+        if self.websocket_config.max_concurrent_requests == Some(0) {
+            return Err(InvalidConfig::new("max_concurrent_requests", "must be at least 1").into());
+        }
+
         let signer = if let Some((api_key, private_key)) = self.base.credentials {
             Some(Arc::new(Ed25519Signer::new(&api_key, &private_key)?) as Arc<dyn SignatureProvider>)
         } else {
@@ -358,6 +547,9 @@ impl WebSocketBinanceConfigBuilder {
 
         Ok(BinanceConfig {
             signer,
+            clock: self.base.clock,
+            correlation_id_provider: self.base.correlation_id_provider,
+            shutdown_token: self.base.shutdown_token,
             recv_window: self.base.recv_window,
             specific_config: self.websocket_config,
         })
@@ -382,6 +574,11 @@ impl StreamBinanceConfigBuilder {
         self
     }
 
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.base = self.base.with_shutdown_token(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -406,6 +603,16 @@ impl StreamBinanceConfigBuilder {
         self
     }
 
+    pub fn with_error_on_duplicate_subscribe(mut self, enabled: bool) -> Self {
+        self.stream_config.error_on_duplicate_subscribe = enabled;
+        self
+    }
+
+    pub fn with_normalize_decimals(mut self, enabled: bool) -> Self {
+        self.stream_config.normalize_decimals = enabled;
+        self
+    }
+
     pub fn with_stream_config(mut self, config: StreamConfig) -> Self {
         self.stream_config = config;
         self
@@ -511,12 +718,20 @@ impl StreamBinanceConfigBuilder {
         self.stream_config.max_reconnect_attempts = max;
         self
     }
+
+    pub fn with_reconnect_on(mut self, codes: &[u16]) -> Self {
+        self.stream_config.reconnect_on = Some(codes.to_vec());
+        self
+    }
 }
 
 impl<T: Clone> Clone for BinanceConfig<T> {
     fn clone(&self) -> Self {
         Self {
             signer: self.signer.clone(),
+            clock: self.clock.clone(),
+            correlation_id_provider: self.correlation_id_provider.clone(),
+            shutdown_token: self.shutdown_token.clone(),
             recv_window: self.recv_window,
             specific_config: self.specific_config.clone(),
         }
@@ -547,6 +762,11 @@ impl MarketDataStreamBuilder {
         self
     }
 
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.base = self.base.with_shutdown_token(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -601,6 +821,16 @@ impl MarketDataStreamBuilder {
         self
     }
 
+    pub fn with_error_on_duplicate_subscribe(mut self, enabled: bool) -> Self {
+        self.stream_config.error_on_duplicate_subscribe = enabled;
+        self
+    }
+
+    pub fn with_normalize_decimals(mut self, enabled: bool) -> Self {
+        self.stream_config.normalize_decimals = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<BinanceConfig<StreamConfig>> {
         if !self.stream_config.market_data_url.starts_with("wss://") {
             return Err(
@@ -622,6 +852,9 @@ impl MarketDataStreamBuilder {
 
         Ok(BinanceConfig {
             signer,
+            clock: self.base.clock,
+            correlation_id_provider: self.base.correlation_id_provider,
+            shutdown_token: self.base.shutdown_token,
             recv_window: self.base.recv_window,
             specific_config: self.stream_config,
         })
@@ -646,6 +879,11 @@ impl UserDataStreamBuilder {
         self
     }
 
+    pub fn with_shutdown_token(mut self, token: ShutdownToken) -> Self {
+        self.base = self.base.with_shutdown_token(token);
+        self
+    }
+
     pub fn with_credentials(
         mut self,
         api_key: impl Into<String>,
@@ -665,6 +903,11 @@ impl UserDataStreamBuilder {
         Ok(self)
     }
 
+    pub fn with_normalize_decimals(mut self, enabled: bool) -> Self {
+        self.stream_config.normalize_decimals = enabled;
+        self
+    }
+
     pub fn build(self) -> Result<BinanceConfig<StreamConfig>> {
         if !self.stream_config.market_data_url.starts_with("wss://") {
             return Err(
@@ -686,6 +929,9 @@ impl UserDataStreamBuilder {
 
         Ok(BinanceConfig {
             signer,
+            clock: self.base.clock,
+            correlation_id_provider: self.base.correlation_id_provider,
+            shutdown_token: self.base.shutdown_token,
             recv_window: self.base.recv_window,
             specific_config: self.stream_config,
         })
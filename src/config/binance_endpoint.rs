@@ -0,0 +1,143 @@
+/**
+ * Known Binance Spot REST API base endpoints.
+ *
+ * Binance serves the Spot REST API from several data centers. The primary
+ * endpoint load balances across them, while the numbered endpoints and the
+ * GCP mirror target specific clusters and may offer better latency or
+ * stability depending on the client location.
+ *
+ * # Variants
+ * - `Mainnet`: Primary mainnet endpoint (`api.binance.com`).
+ * - `Gcp`: Google Cloud mirror (`api-gcp.binance.com`).
+ * - `Api1`: Data-center endpoint 1 (`api1.binance.com`).
+ * - `Api2`: Data-center endpoint 2 (`api2.binance.com`).
+ * - `Api3`: Data-center endpoint 3 (`api3.binance.com`).
+ * - `Api4`: Data-center endpoint 4 (`api4.binance.com`).
+ * - `Testnet`: Spot testnet endpoint (`testnet.binance.vision`).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BinanceEndpoint {
+    #[default]
+    Mainnet,
+    Gcp,
+    Api1,
+    Api2,
+    Api3,
+    Api4,
+    Testnet,
+}
+
+impl BinanceEndpoint {
+    /**
+     * Returns the base URL for this endpoint.
+     *
+     * # Returns
+     * - `&'static str`: HTTPS base URL without a trailing slash.
+     */
+    pub fn url(&self) -> &'static str {
+        match self {
+            BinanceEndpoint::Mainnet => "https://api.binance.com",
+            BinanceEndpoint::Gcp => "https://api-gcp.binance.com",
+            BinanceEndpoint::Api1 => "https://api1.binance.com",
+            BinanceEndpoint::Api2 => "https://api2.binance.com",
+            BinanceEndpoint::Api3 => "https://api3.binance.com",
+            BinanceEndpoint::Api4 => "https://api4.binance.com",
+            BinanceEndpoint::Testnet => "https://testnet.binance.vision",
+        }
+    }
+
+    /**
+     * Checks whether this endpoint points at the testnet.
+     *
+     * # Returns
+     * - `bool`: True for the testnet endpoint.
+     */
+    pub fn is_testnet(&self) -> bool {
+        matches!(self, BinanceEndpoint::Testnet)
+    }
+}
+
+impl std::fmt::Display for BinanceEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinanceConfig, RestConfig};
+
+    /**
+     * Tests that every endpoint resolves to its documented host.
+     */
+    #[test]
+    fn test_endpoint_urls() {
+        // Arrange
+        let expected = [
+            (BinanceEndpoint::Mainnet, "https://api.binance.com"),
+            (BinanceEndpoint::Gcp, "https://api-gcp.binance.com"),
+            (BinanceEndpoint::Api1, "https://api1.binance.com"),
+            (BinanceEndpoint::Api2, "https://api2.binance.com"),
+            (BinanceEndpoint::Api3, "https://api3.binance.com"),
+            (BinanceEndpoint::Api4, "https://api4.binance.com"),
+            (BinanceEndpoint::Testnet, "https://testnet.binance.vision"),
+        ];
+
+        // Act & Assert
+        for (endpoint, url) in expected {
+            assert_eq!(endpoint.url(), url);
+            assert_eq!(endpoint.to_string(), url);
+        }
+    }
+
+    /**
+     * Tests selecting an endpoint through the REST configuration builder.
+     */
+    #[test]
+    fn test_rest_config_with_endpoint() {
+        // Act
+        let rest_config = RestConfig::builder()
+            .with_endpoint(BinanceEndpoint::Api3)
+            .build();
+
+        // Assert
+        assert_eq!(rest_config.url, "https://api3.binance.com");
+    }
+
+    /**
+     * Tests that testnet and mainnet select their default endpoints.
+     */
+    #[test]
+    fn test_network_selects_default_endpoint() {
+        // Act
+        let testnet = BinanceConfig::<RestConfig>::builder()
+            .with_testnet()
+            .build()
+            .expect("Config creation");
+        let mainnet = BinanceConfig::<RestConfig>::builder()
+            .with_mainnet()
+            .build()
+            .expect("Config creation");
+
+        // Assert
+        assert_eq!(testnet.url(), BinanceEndpoint::Testnet.url());
+        assert_eq!(mainnet.url(), BinanceEndpoint::Mainnet.url());
+    }
+
+    /**
+     * Tests that an explicit endpoint overrides the network default.
+     */
+    #[test]
+    fn test_with_endpoint_overrides_network_default() {
+        // Act
+        let config = BinanceConfig::<RestConfig>::builder()
+            .with_mainnet()
+            .with_endpoint(BinanceEndpoint::Gcp)
+            .build()
+            .expect("Config creation");
+
+        // Assert
+        assert_eq!(config.url(), "https://api-gcp.binance.com");
+    }
+}
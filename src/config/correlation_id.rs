@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+/**
+ * Source of correlation ids attached to outgoing requests.
+ *
+ * Called once per request; the returned id is recorded on the request's
+ * tracing span so logs and timings can be joined with traces from other
+ * services.
+ *
+ * # Fields
+ * - `0`: Function generating a new correlation id.
+ */
+#[derive(Clone)]
+pub struct CorrelationIdProvider(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl CorrelationIdProvider {
+    /**
+     * Creates a provider from an id generating function.
+     *
+     * # Arguments
+     * - `provider`: Function returning the correlation id for the next request.
+     *
+     * # Returns
+     * - New CorrelationIdProvider instance.
+     */
+    pub fn new(provider: Arc<dyn Fn() -> String + Send + Sync>) -> Self {
+        Self(provider)
+    }
+
+    /**
+     * Generates the correlation id for the next request.
+     *
+     * # Returns
+     * - `String`: Correlation id returned by the provider.
+     */
+    pub fn next_id(&self) -> String {
+        (self.0)()
+    }
+}
+
+impl std::fmt::Debug for CorrelationIdProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CorrelationIdProvider")
+    }
+}
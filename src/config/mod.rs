@@ -1,9 +1,16 @@
 mod binance_config;
+mod binance_endpoint;
+mod correlation_id;
 mod rest_config;
+mod shutdown_token;
 mod stream_config;
 mod websocket_config;
 
 pub use binance_config::BinanceConfig;
+pub use binance_endpoint::BinanceEndpoint;
+pub use correlation_id::CorrelationIdProvider;
 pub use rest_config::RestConfig;
+pub use shutdown_token::ShutdownToken;
+pub(crate) use shutdown_token::wait_for_shutdown;
 pub use stream_config::{StreamConfig, StreamMode, StreamType};
 pub use websocket_config::WebSocketConfig;
@@ -1,27 +1,47 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
-const DEFAULT_BINANCE_API_URL: &str = "https://api.binance.com";
+use crate::config::BinanceEndpoint;
+use crate::enums::OrderResponseType;
+
 const DEFAULT_USER_AGENT: &str = "binance-rust-client/1.0";
+const DEFAULT_MAX_RESPONSE_BYTES: usize = 64 * 1024 * 1024;
 
 /**
  * Configuration for REST API client behavior.
  *
  * # Fields
  * - `url`: Base URL for REST API requests.
+ * - `failover_urls`: Fallback base URLs tried in order when a request to `url` fails.
  * - `connection_timeout`: Timeout for establishing HTTP connections.
  * - `request_timeout`: Timeout for complete request-response cycle.
+ * - `endpoint_timeouts`: Request timeouts overriding `request_timeout` for specific endpoint paths.
+ * - `endpoint_weights`: Request weights overriding the documented weight of specific endpoint paths.
  * - `pool_max_idle_per_host`: Maximum idle connections to keep per host.
  * - `pool_idle_timeout`: How long to keep idle connections before closing.
  * - `user_agent`: User agent string for requests.
+ * - `gzip`: Whether to request gzip-compressed responses and decompress them transparently.
+ * - `max_response_bytes`: Maximum (decompressed) response body size before reading is aborted.
+ * - `keepalive_warmup`: Interval at which idle pooled connections are kept warm, if enabled.
+ * - `default_order_resp_type`: Response type applied to order requests that do not set one.
+ * - `auto_cooldown`: Whether to hold back requests after a rate limit or IP ban response.
  */
 #[derive(Debug, Clone)]
 pub struct RestConfig {
     pub url: String,
+    pub failover_urls: Vec<String>,
     pub connection_timeout: Duration,
     pub request_timeout: Duration,
+    pub endpoint_timeouts: HashMap<String, Duration>,
+    pub endpoint_weights: HashMap<String, u32>,
     pub pool_max_idle_per_host: usize,
     pub pool_idle_timeout: Duration,
     pub user_agent: String,
+    pub gzip: bool,
+    pub max_response_bytes: usize,
+    pub keepalive_warmup: Option<Duration>,
+    pub default_order_resp_type: Option<OrderResponseType>,
+    pub auto_cooldown: bool,
 }
 
 /**
@@ -29,20 +49,36 @@ pub struct RestConfig {
  *
  * # Fields
  * - `url`: Base URL for REST API requests.
+ * - `failover_urls`: Fallback base URLs tried in order when a request to `url` fails.
  * - `connection_timeout`: Timeout for establishing HTTP connections.
  * - `request_timeout`: Timeout for complete request-response cycle.
+ * - `endpoint_timeouts`: Request timeouts overriding `request_timeout` for specific endpoint paths.
+ * - `endpoint_weights`: Request weights overriding the documented weight of specific endpoint paths.
  * - `pool_max_idle_per_host`: Maximum idle connections to keep per host.
  * - `pool_idle_timeout`: How long to keep idle connections before closing.
  * - `user_agent`: User agent string for requests.
+ * - `gzip`: Whether to request gzip-compressed responses and decompress them transparently.
+ * - `max_response_bytes`: Maximum (decompressed) response body size before reading is aborted.
+ * - `keepalive_warmup`: Interval at which idle pooled connections are kept warm, if enabled.
+ * - `default_order_resp_type`: Response type applied to order requests that do not set one.
+ * - `auto_cooldown`: Whether to hold back requests after a rate limit or IP ban response.
  */
 #[derive(Debug)]
 pub struct RestConfigBuilder {
     url: String,
+    failover_urls: Vec<String>,
     connection_timeout: Duration,
     request_timeout: Duration,
+    endpoint_timeouts: HashMap<String, Duration>,
+    endpoint_weights: HashMap<String, u32>,
     pool_max_idle_per_host: usize,
     pool_idle_timeout: Duration,
     user_agent: String,
+    gzip: bool,
+    max_response_bytes: usize,
+    keepalive_warmup: Option<Duration>,
+    default_order_resp_type: Option<OrderResponseType>,
+    auto_cooldown: bool,
 }
 
 impl RestConfig {
@@ -55,6 +91,19 @@ impl RestConfig {
     pub fn builder() -> RestConfigBuilder {
         RestConfigBuilder::new()
     }
+
+    /**
+     * Gets the overridden request weight of an endpoint.
+     *
+     * # Arguments
+     * - `endpoint`: Endpoint path (e.g., "/api/v3/allOrders").
+     *
+     * # Returns
+     * - `Option<u32>`: Overridden weight, or None to charge the documented weight.
+     */
+    pub fn endpoint_weight(&self, endpoint: &str) -> Option<u32> {
+        self.endpoint_weights.get(endpoint).copied()
+    }
 }
 
 impl RestConfigBuilder {
@@ -66,12 +115,20 @@ impl RestConfigBuilder {
      */
     fn new() -> Self {
         Self {
-            url: DEFAULT_BINANCE_API_URL.to_string(),
+            url: BinanceEndpoint::default().url().to_string(),
+            failover_urls: Vec::new(),
             connection_timeout: Duration::from_secs(10),
             request_timeout: Duration::from_secs(30),
+            endpoint_timeouts: HashMap::new(),
+            endpoint_weights: HashMap::new(),
             pool_max_idle_per_host: 10,
             pool_idle_timeout: Duration::from_secs(30),
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            gzip: true,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            keepalive_warmup: None,
+            default_order_resp_type: None,
+            auto_cooldown: false,
         }
     }
 
@@ -89,6 +146,42 @@ impl RestConfigBuilder {
         self
     }
 
+    /**
+     * Sets the base URL from one of the known Binance endpoints.
+     *
+     * # Arguments
+     * - `endpoint`: Binance endpoint to send requests to.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_endpoint(mut self, endpoint: BinanceEndpoint) -> Self {
+        self.url = endpoint.url().to_string();
+        self
+    }
+
+    /**
+     * Sets an ordered list of endpoints to fail over across.
+     *
+     * The first endpoint becomes the primary base URL. GET requests are retried
+     * against the next endpoint on a connection error, timeout or 5xx response.
+     * Other methods are retried only on a connection error, since they may
+     * already have executed.
+     *
+     * # Arguments
+     * - `endpoints`: Endpoints in order of preference.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_endpoint_failover(mut self, endpoints: &[BinanceEndpoint]) -> Self {
+        if let Some((primary, fallbacks)) = endpoints.split_first() {
+            self.url = primary.url().to_string();
+            self.failover_urls = fallbacks.iter().map(|e| e.url().to_string()).collect();
+        }
+        self
+    }
+
     /**
      * Sets the connection timeout.
      *
@@ -117,6 +210,48 @@ impl RestConfigBuilder {
         self
     }
 
+    /**
+     * Overrides the request timeout for a specific endpoint.
+     *
+     * # Arguments
+     * - `endpoint`: Endpoint path (e.g., "/api/v3/allOrders").
+     * - `timeout`: Complete request-response timeout for that endpoint.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_endpoint_timeout_override(
+        mut self,
+        endpoint: impl Into<String>,
+        timeout: Duration,
+    ) -> Self {
+        self.endpoint_timeouts.insert(endpoint.into(), timeout);
+        self
+    }
+
+    /**
+     * Overrides the request weight of specific endpoints.
+     *
+     * Binance adjusts endpoint weights over time; overrides let the weight
+     * table follow those changes without a new release. An override replaces
+     * the documented weight of the endpoint for every method and parameter
+     * set. Later calls add to or replace earlier overrides.
+     *
+     * # Arguments
+     * - `weights`: Request weight per endpoint path (e.g., "/api/v3/depth").
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_endpoint_weights(mut self, weights: HashMap<&str, u32>) -> Self {
+        self.endpoint_weights.extend(
+            weights
+                .into_iter()
+                .map(|(endpoint, weight)| (endpoint.to_string(), weight)),
+        );
+        self
+    }
+
     /**
      * Sets the maximum idle connections per host.
      *
@@ -159,6 +294,98 @@ impl RestConfigBuilder {
         self
     }
 
+    /**
+     * Enables or disables gzip compression of responses.
+     *
+     * When enabled, requests advertise `Accept-Encoding: gzip` and compressed
+     * responses are decompressed before parsing, which cuts bandwidth on large
+     * payloads such as exchange information and order history.
+     *
+     * # Arguments
+     * - `enabled`: Whether to request gzip-compressed responses.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_gzip(mut self, enabled: bool) -> Self {
+        self.gzip = enabled;
+        self
+    }
+
+    /**
+     * Sets the maximum response body size.
+     *
+     * Reading a response stops with an error once its body grows past this
+     * limit, guarding against pathological or malicious payloads. The default
+     * (64 MiB) comfortably fits a full exchange information response.
+     *
+     * # Arguments
+     * - `max_bytes`: Maximum body size in bytes, after decompression.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_max_response_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_response_bytes = max_bytes;
+        self
+    }
+
+    /**
+     * Enables periodic keepalive requests to keep pooled connections warm.
+     *
+     * The first request after an idle period otherwise pays for a fresh TCP
+     * and TLS handshake. With a warmup interval set, the client pings the
+     * server time endpoint on that interval so latency-sensitive bursts reuse
+     * an open connection. Disabled by default; the interval should be shorter
+     * than the pool idle timeout.
+     *
+     * # Arguments
+     * - `interval`: Time between keepalive requests.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_keepalive_warmup(mut self, interval: Duration) -> Self {
+        self.keepalive_warmup = Some(interval);
+        self
+    }
+
+    /**
+     * Sets the response type used for order requests that do not set one.
+     *
+     * Applies to new orders, order lists, SOR orders and the new order of
+     * cancel-replace requests; a response type set on the spec takes precedence.
+     *
+     * # Arguments
+     * - `response_type`: Default `newOrderRespType` for order requests.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_default_order_resp_type(mut self, response_type: OrderResponseType) -> Self {
+        self.default_order_resp_type = Some(response_type);
+        self
+    }
+
+    /**
+     * Enables or disables the automatic cooldown after rate limit responses.
+     *
+     * When enabled, a 429 (too many requests, -1003) or 418 (IP ban) response
+     * puts the client into a cooldown for the `Retry-After` period, and every
+     * request sent in the meantime waits for it to elapse instead of hitting
+     * the server and extending the ban.
+     *
+     * # Arguments
+     * - `enabled`: Whether to enter a cooldown on rate limit responses.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_auto_cooldown(mut self, enabled: bool) -> Self {
+        self.auto_cooldown = enabled;
+        self
+    }
+
     /**
      * Builds the REST configuration.
      *
@@ -168,11 +395,19 @@ impl RestConfigBuilder {
     pub fn build(self) -> RestConfig {
         RestConfig {
             url: self.url,
+            failover_urls: self.failover_urls,
             connection_timeout: self.connection_timeout,
             request_timeout: self.request_timeout,
+            endpoint_timeouts: self.endpoint_timeouts,
+            endpoint_weights: self.endpoint_weights,
             pool_max_idle_per_host: self.pool_max_idle_per_host,
             pool_idle_timeout: self.pool_idle_timeout,
             user_agent: self.user_agent,
+            gzip: self.gzip,
+            max_response_bytes: self.max_response_bytes,
+            keepalive_warmup: self.keepalive_warmup,
+            default_order_resp_type: self.default_order_resp_type,
+            auto_cooldown: self.auto_cooldown,
         }
     }
 }
@@ -182,3 +417,30 @@ impl Default for RestConfigBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests that only overridden endpoints report a weight.
+     */
+    #[test]
+    fn test_endpoint_weight_override() {
+        // Arrange
+        let rest_config = RestConfig::builder()
+            .with_endpoint_weights(HashMap::from([("/api/v3/depth", 250)]))
+            .with_endpoint_weights(HashMap::from([("/api/v3/allOrders", 20)]))
+            .build();
+
+        // Act
+        let depth = rest_config.endpoint_weight("/api/v3/depth");
+        let all_orders = rest_config.endpoint_weight("/api/v3/allOrders");
+        let time = rest_config.endpoint_weight("/api/v3/time");
+
+        // Assert
+        assert_eq!(depth, Some(250));
+        assert_eq!(all_orders, Some(20));
+        assert_eq!(time, None);
+    }
+}
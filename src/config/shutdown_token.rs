@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+/**
+ * Shared signal for shutting down every client built from one configuration.
+ *
+ * Clones share the same signal. Passing a token to several config builders
+ * lets one `trigger` close all stream connections and stop the background
+ * tasks of every client built from those configs.
+ *
+ * # Fields
+ * - `0`: Watch sender holding whether shutdown has been triggered.
+ */
+#[derive(Debug, Clone)]
+pub struct ShutdownToken(Arc<watch::Sender<bool>>);
+
+impl ShutdownToken {
+    /**
+     * Creates a new, untriggered shutdown token.
+     *
+     * # Returns
+     * - New ShutdownToken instance.
+     */
+    pub fn new() -> Self {
+        Self(Arc::new(watch::Sender::new(false)))
+    }
+
+    /**
+     * Triggers shutdown for every client holding a clone of this token.
+     *
+     * Triggering more than once has no further effect.
+     */
+    pub fn trigger(&self) {
+        self.0.send_replace(true);
+    }
+
+    /**
+     * Checks whether shutdown has been triggered.
+     *
+     * # Returns
+     * - `true` once `trigger` has been called on any clone.
+     */
+    pub fn is_triggered(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /**
+     * Waits until shutdown is triggered.
+     *
+     * Resolves immediately if the token was already triggered.
+     */
+    pub async fn triggered(&self) {
+        let mut receiver = self.0.subscribe();
+        let _ = receiver.wait_for(|triggered| *triggered).await;
+    }
+}
+
+impl Default for ShutdownToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/**
+ * Waits until an optional shutdown token is triggered.
+ *
+ * # Arguments
+ * - `token`: Shutdown token to wait on; without one this never resolves.
+ */
+pub(crate) async fn wait_for_shutdown(token: Option<&ShutdownToken>) {
+    match token {
+        Some(token) => token.triggered().await,
+        None => std::future::pending().await,
+    }
+}
@@ -48,6 +48,9 @@ pub struct StreamConfig {
     pub initial_retry_delay: Duration,
     pub max_retry_delay: Duration,
     pub connection_timeout: Duration,
+    pub error_on_duplicate_subscribe: bool,
+    pub reconnect_on: Option<Vec<u16>>,
+    pub normalize_decimals: bool,
     pub stream_mode: StreamMode,
 }
 
@@ -75,6 +78,9 @@ pub struct StreamConfigBuilder {
     initial_retry_delay: Duration,
     max_retry_delay: Duration,
     connection_timeout: Duration,
+    error_on_duplicate_subscribe: bool,
+    reconnect_on: Option<Vec<u16>>,
+    normalize_decimals: bool,
     stream_mode: StreamMode,
 }
 
@@ -86,6 +92,23 @@ impl StreamConfig {
     pub fn stream_mode(&self) -> &StreamMode {
         &self.stream_mode
     }
+
+    /**
+     * Checks whether a connection closed with the given code is re-established.
+     *
+     * Without a `reconnect_on` list every close code reconnects.
+     *
+     * # Arguments
+     * - `code`: WebSocket close code the connection ended with.
+     *
+     * # Returns
+     * - `true` if the code is listed in `reconnect_on` or no list is configured.
+     */
+    pub fn reconnects_on(&self, code: u16) -> bool {
+        self.reconnect_on
+            .as_ref()
+            .is_none_or(|codes| codes.contains(&code))
+    }
 }
 
 impl StreamConfigBuilder {
@@ -113,6 +136,9 @@ impl StreamConfigBuilder {
             initial_retry_delay: Duration::from_secs(1),
             max_retry_delay: Duration::from_secs(60),
             connection_timeout: Duration::from_secs(10),
+            error_on_duplicate_subscribe: false,
+            reconnect_on: None,
+            normalize_decimals: false,
             stream_mode: StreamMode::default(),
         }
     }
@@ -232,6 +258,21 @@ impl StreamConfigBuilder {
         self
     }
 
+    pub fn with_error_on_duplicate_subscribe(mut self, enabled: bool) -> Self {
+        self.error_on_duplicate_subscribe = enabled;
+        self
+    }
+
+    pub fn with_reconnect_on(mut self, codes: &[u16]) -> Self {
+        self.reconnect_on = Some(codes.to_vec());
+        self
+    }
+
+    pub fn with_normalize_decimals(mut self, enabled: bool) -> Self {
+        self.normalize_decimals = enabled;
+        self
+    }
+
     pub fn build(self) -> StreamConfig {
         StreamConfig {
             stream_type: self.stream_type,
@@ -256,6 +297,9 @@ impl StreamConfigBuilder {
             initial_retry_delay: self.initial_retry_delay,
             max_retry_delay: self.max_retry_delay,
             connection_timeout: self.connection_timeout,
+            error_on_duplicate_subscribe: self.error_on_duplicate_subscribe,
+            reconnect_on: self.reconnect_on,
+            normalize_decimals: self.normalize_decimals,
             stream_mode: self.stream_mode,
         }
     }
@@ -1,6 +1,9 @@
 use std::time::Duration;
 
+use crate::enums::OrderResponseType;
+
 const DEFAULT_WEBSOCKET_URL: &str = "wss://ws-api.binance.com:443";
+const DEFAULT_API_VERSION: &str = "v3";
 
 /**
  * Configuration for WebSocket connection management and behavior.
@@ -11,6 +14,10 @@ const DEFAULT_WEBSOCKET_URL: &str = "wss://ws-api.binance.com:443";
  * - `initial_retry_delay`: Initial delay between reconnection attempts.
  * - `max_retry_delay`: Maximum delay between reconnection attempts (for exponential backoff).
  * - `connection_timeout`: Timeout for establishing WebSocket connections.
+ * - `request_timeout`: Maximum time to wait for the response to a WebSocket API request.
+ * - `max_concurrent_requests`: Maximum number of in-flight requests, if bounded.
+ * - `default_order_resp_type`: Response type applied to order requests that do not set one.
+ * - `api_version`: WebSocket API path version (e.g., "v3" for `/ws-api/v3`).
  */
 #[derive(Debug, Clone)]
 pub struct WebSocketConfig {
@@ -19,6 +26,10 @@ pub struct WebSocketConfig {
     pub initial_retry_delay: Duration,
     pub max_retry_delay: Duration,
     pub connection_timeout: Duration,
+    pub request_timeout: Duration,
+    pub max_concurrent_requests: Option<usize>,
+    pub default_order_resp_type: Option<OrderResponseType>,
+    pub api_version: String,
 }
 
 /**
@@ -30,6 +41,10 @@ pub struct WebSocketConfig {
  * - `initial_retry_delay`: Initial delay between reconnection attempts.
  * - `max_retry_delay`: Maximum delay between reconnection attempts (for exponential backoff).
  * - `connection_timeout`: Timeout for establishing WebSocket connections.
+ * - `request_timeout`: Maximum time to wait for the response to a WebSocket API request.
+ * - `max_concurrent_requests`: Maximum number of in-flight requests, if bounded.
+ * - `default_order_resp_type`: Response type applied to order requests that do not set one.
+ * - `api_version`: WebSocket API path version (e.g., "v3" for `/ws-api/v3`).
  */
 #[derive(Debug)]
 pub struct WebSocketConfigBuilder {
@@ -38,6 +53,10 @@ pub struct WebSocketConfigBuilder {
     initial_retry_delay: Duration,
     max_retry_delay: Duration,
     connection_timeout: Duration,
+    request_timeout: Duration,
+    max_concurrent_requests: Option<usize>,
+    default_order_resp_type: Option<OrderResponseType>,
+    api_version: String,
 }
 
 impl WebSocketConfig {
@@ -50,6 +69,16 @@ impl WebSocketConfig {
     pub fn builder() -> WebSocketConfigBuilder {
         WebSocketConfigBuilder::new()
     }
+
+    /**
+     * Gets the WebSocket API connection URL for the configured version.
+     *
+     * # Returns
+     * - `String`: Base URL followed by `/ws-api/<api_version>`.
+     */
+    pub fn api_url(&self) -> String {
+        format!("{}/ws-api/{}", self.url, self.api_version)
+    }
 }
 
 impl WebSocketConfigBuilder {
@@ -66,6 +95,10 @@ impl WebSocketConfigBuilder {
             initial_retry_delay: Duration::from_secs(1),
             max_retry_delay: Duration::from_secs(60),
             connection_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            max_concurrent_requests: None,
+            default_order_resp_type: None,
+            api_version: DEFAULT_API_VERSION.to_string(),
         }
     }
 
@@ -139,6 +172,71 @@ impl WebSocketConfigBuilder {
         self
     }
 
+    /**
+     * Sets the request timeout.
+     *
+     * Requests without a response within this duration fail with `BinanceError::Timeout`.
+     *
+     * # Arguments
+     * - `timeout`: Maximum time to wait for a response.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /**
+     * Sets the maximum number of concurrent requests.
+     *
+     * Requests beyond the limit wait until an in-flight request completes
+     * before they are sent, and the wait counts towards the request timeout.
+     * Unbounded by default. A limit of zero is rejected when the
+     * `BinanceConfig` is built.
+     *
+     * # Arguments
+     * - `max`: Maximum number of requests awaiting a response at once.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max);
+        self
+    }
+
+    /**
+     * Sets the response type used for order requests that do not set one.
+     *
+     * # Arguments
+     * - `response_type`: Default `newOrderRespType` for order requests.
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_default_order_resp_type(mut self, response_type: OrderResponseType) -> Self {
+        self.default_order_resp_type = Some(response_type);
+        self
+    }
+
+    /**
+     * Sets the WebSocket API path version.
+     *
+     * The version is validated when the `BinanceConfig` is built.
+     *
+     * # Arguments
+     * - `version`: API version of the form `v<number>` (e.g., "v3").
+     *
+     * # Returns
+     * - `Self`: Updated builder.
+     */
+    pub fn with_api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = version.into();
+        self
+    }
+
     /**
      * Builds the WebSocket configuration.
      *
@@ -152,6 +250,10 @@ impl WebSocketConfigBuilder {
             initial_retry_delay: self.initial_retry_delay,
             max_retry_delay: self.max_retry_delay,
             connection_timeout: self.connection_timeout,
+            request_timeout: self.request_timeout,
+            max_concurrent_requests: self.max_concurrent_requests,
+            default_order_resp_type: self.default_order_resp_type,
+            api_version: self.api_version,
         }
     }
 }
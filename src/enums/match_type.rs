@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * Match types reported on order fills.
+ *
+ * # Variants
+ * - `OnePartyTradeReport`: Fill executed through an allocation, such as SOR (sent/recv as `"ONE_PARTY_TRADE_REPORT"`).
+ * - `Unknown`: Any type not recognized.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(deny_unknown_fields)]
+pub enum MatchType {
+    OnePartyTradeReport,
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
@@ -14,6 +14,8 @@ mod cancel_replace_mode;
 mod cancel_replace_status;
 mod cancel_restrictions;
 mod contingency_type;
+mod match_type;
+mod order_field;
 mod order_list_order_status;
 mod order_list_status;
 mod order_rate_limit_exceeded_mode;
@@ -26,6 +28,7 @@ mod rate_limit_interval;
 mod rate_limit_type;
 mod self_trade_prevention_mode;
 mod symbol_status;
+mod ticker_type;
 mod time_in_force;
 mod trade_group;
 mod working_floor;
@@ -35,6 +38,8 @@ pub use cancel_replace_mode::CancelReplaceMode;
 pub use cancel_replace_status::CancelReplaceStatus;
 pub use cancel_restrictions::CancelRestrictions;
 pub use contingency_type::ContingencyType;
+pub use match_type::MatchType;
+pub use order_field::OrderField;
 pub use order_list_order_status::OrderListOrderStatus;
 pub use order_list_status::OrderListStatus;
 pub use order_rate_limit_exceeded_mode::OrderRateLimitExceededMode;
@@ -47,6 +52,7 @@ pub use rate_limit_interval::RateLimitInterval;
 pub use rate_limit_type::RateLimitType;
 pub use self_trade_prevention_mode::SelfTradePreventionMode;
 pub use symbol_status::SymbolStatus;
+pub use ticker_type::TickerType;
 pub use time_in_force::TimeInForce;
 pub use trade_group::TradeGroup;
 pub use working_floor::WorkingFloor;
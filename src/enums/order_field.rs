@@ -0,0 +1,39 @@
+/**
+ * Order parameter an order type may require.
+ *
+ * # Variants
+ * - `TimeInForce`: Time in force of the order.
+ * - `Quantity`: Base asset quantity.
+ * - `QuoteOrderQuantity`: Quote asset quantity of a MARKET order.
+ * - `Price`: Limit price.
+ * - `StopPrice`: Trigger price of a stop loss or take profit order.
+ * - `TrailingDelta`: Trailing delta of a stop loss or take profit order.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderField {
+    TimeInForce,
+    Quantity,
+    QuoteOrderQuantity,
+    Price,
+    StopPrice,
+    TrailingDelta,
+}
+
+impl OrderField {
+    /**
+     * Returns the parameter name used by the order specifications.
+     *
+     * # Returns
+     * - `&'static str`: Snake case field name (e.g., "time_in_force").
+     */
+    pub fn name(&self) -> &'static str {
+        match self {
+            OrderField::TimeInForce => "time_in_force",
+            OrderField::Quantity => "quantity",
+            OrderField::QuoteOrderQuantity => "quote_order_quantity",
+            OrderField::Price => "price",
+            OrderField::StopPrice => "stop_price",
+            OrderField::TrailingDelta => "trailing_delta",
+        }
+    }
+}
@@ -31,3 +31,22 @@ pub enum OrderStatus {
     #[serde(other, skip_serializing)]
     Unknown,
 }
+
+impl OrderStatus {
+    /**
+     * Checks whether the order can no longer change state.
+     *
+     * # Returns
+     * - `bool`: True for filled, canceled, rejected and expired orders.
+     */
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Filled
+                | OrderStatus::Canceled
+                | OrderStatus::Rejected
+                | OrderStatus::Expired
+                | OrderStatus::ExpiredInMatch
+        )
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::enums::{OrderField, TimeInForce};
+
 /**
  * Order types available for trading.
  *
@@ -27,3 +29,118 @@ pub enum OrderType {
     #[serde(other, skip_serializing)]
     Unknown,
 }
+
+impl OrderType {
+    /**
+     * Returns the order parameters this order type cannot be placed without.
+     *
+     * Each entry lists alternatives, any one of which satisfies it.
+     *
+     * # Returns
+     * - `&'static [&'static [OrderField]]`: Required parameters, empty for `Unknown`.
+     */
+    pub fn required_fields(&self) -> &'static [&'static [OrderField]] {
+        const TIME_IN_FORCE: &[OrderField] = &[OrderField::TimeInForce];
+        const QUANTITY: &[OrderField] = &[OrderField::Quantity];
+        const PRICE: &[OrderField] = &[OrderField::Price];
+        const TRIGGER: &[OrderField] = &[OrderField::StopPrice, OrderField::TrailingDelta];
+
+        match self {
+            OrderType::Limit => &[TIME_IN_FORCE, QUANTITY, PRICE],
+            OrderType::Market => &[&[OrderField::Quantity, OrderField::QuoteOrderQuantity]],
+            OrderType::StopLoss | OrderType::TakeProfit => &[QUANTITY, TRIGGER],
+            OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+                &[TIME_IN_FORCE, QUANTITY, PRICE, TRIGGER]
+            }
+            OrderType::LimitMaker => &[QUANTITY, PRICE],
+            OrderType::Unknown => &[],
+        }
+    }
+
+    /**
+     * Checks whether this order type can be placed with the given time in force.
+     *
+     * LIMIT_MAKER orders must rest on the book, so the immediate execution
+     * semantics of IOC and FOK are rejected by the exchange.
+     *
+     * # Arguments
+     * - `time_in_force`: Time in force set on the order.
+     *
+     * # Returns
+     * - `bool`: False if the exchange rejects the combination.
+     */
+    pub fn allows_time_in_force(&self, time_in_force: TimeInForce) -> bool {
+        !matches!(
+            (self, time_in_force),
+            (OrderType::LimitMaker, TimeInForce::IOC | TimeInForce::FOK)
+        )
+    }
+
+    /**
+     * Checks whether this order type counts towards the MAX_NUM_ALGO_ORDERS filter.
+     *
+     * # Returns
+     * - `bool`: True for STOP_LOSS, STOP_LOSS_LIMIT, TAKE_PROFIT and TAKE_PROFIT_LIMIT.
+     */
+    pub fn is_algo(&self) -> bool {
+        matches!(
+            self,
+            OrderType::StopLoss
+                | OrderType::StopLossLimit
+                | OrderType::TakeProfit
+                | OrderType::TakeProfitLimit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests the required parameters reported for each order type.
+     */
+    #[test]
+    fn test_required_fields() {
+        // Arrange
+        use OrderField::{Price, Quantity, QuoteOrderQuantity, StopPrice, TrailingDelta};
+        let trigger: &[OrderField] = &[StopPrice, TrailingDelta];
+        let cases: [(OrderType, &[&[OrderField]]); 8] = [
+            (
+                OrderType::Limit,
+                &[&[OrderField::TimeInForce], &[Quantity], &[Price]],
+            ),
+            (OrderType::Market, &[&[Quantity, QuoteOrderQuantity]]),
+            (OrderType::StopLoss, &[&[Quantity], trigger]),
+            (
+                OrderType::StopLossLimit,
+                &[&[OrderField::TimeInForce], &[Quantity], &[Price], trigger],
+            ),
+            (OrderType::TakeProfit, &[&[Quantity], trigger]),
+            (
+                OrderType::TakeProfitLimit,
+                &[&[OrderField::TimeInForce], &[Quantity], &[Price], trigger],
+            ),
+            (OrderType::LimitMaker, &[&[Quantity], &[Price]]),
+            (OrderType::Unknown, &[]),
+        ];
+
+        // Act & Assert
+        for (order_type, expected) in cases {
+            assert_eq!(order_type.required_fields(), expected, "{:?}", order_type);
+        }
+    }
+
+    /**
+     * Tests that LIMIT_MAKER rejects immediate time in force values while LIMIT accepts them.
+     */
+    #[test]
+    fn test_allows_time_in_force() {
+        // Act & Assert
+        assert!(!OrderType::LimitMaker.allows_time_in_force(TimeInForce::IOC));
+        assert!(!OrderType::LimitMaker.allows_time_in_force(TimeInForce::FOK));
+        assert!(OrderType::LimitMaker.allows_time_in_force(TimeInForce::GTC));
+        assert!(OrderType::Limit.allows_time_in_force(TimeInForce::FOK));
+        assert!(OrderType::StopLossLimit.allows_time_in_force(TimeInForce::IOC));
+    }
+}
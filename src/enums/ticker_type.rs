@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * Response shape requested from ticker statistics endpoints.
+ *
+ * # Variants
+ * - `Full`: Complete statistics including price change and best bid/ask.
+ * - `Mini`: Essential OHLCV statistics only.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(deny_unknown_fields)]
+pub enum TickerType {
+    Full,
+    Mini,
+}
@@ -22,6 +22,7 @@ use crate::filters::{
  * - `trading_error`: Specific trading error type (if applicable)
  * - `filter_failure`: Parsed filter failure type (if applicable)
  * - `trading_rejection`: Parsed trading rejection message (if applicable)
+ * - `data`: Additional error payload returned alongside the error (if applicable)
  */
 #[derive(Debug, Error, Clone, Serialize, Deserialize, PartialEq)]
 #[error("Binance API error {code}: {msg}")]
@@ -54,6 +55,10 @@ pub struct ApiError {
     /// Parsed trading rejection message (not serialized)
     #[serde(skip)]
     pub trading_rejection: Option<TradingRejectionMessage>,
+
+    /// Additional payload such as per-leg cancel-replace results (not serialized)
+    #[serde(skip)]
+    pub data: Option<serde_json::Value>,
 }
 
 impl ApiError {
@@ -115,9 +120,31 @@ impl ApiError {
             filter_failure: parse_filter_failure(&msg),
             trading_rejection: parse_trading_rejection(&msg),
             msg,
+            data: None,
         }
     }
 
+    /**
+     * Parses an API error from a Binance error payload.
+     *
+     * REST responses carry the payload as the response body, while WebSocket
+     * API responses nest it under an `error` field. Both share the same
+     * `{"code": ..., "msg": ...}` shape, optionally with a `data` field.
+     *
+     * # Arguments
+     * - `payload`: JSON object containing `code` and `msg`.
+     *
+     * # Returns
+     * - `Option<Self>`: Categorized API error, or None if the payload is not an API error.
+     */
+    pub fn from_json(payload: &serde_json::Value) -> Option<Self> {
+        let code = payload.get("code")?.as_i64()?;
+        let msg = payload.get("msg")?.as_str()?;
+        let mut api_error = Self::new(i32::try_from(code).ok()?, msg);
+        api_error.data = payload.get("data").cloned();
+        Some(api_error)
+    }
+
     /**
      * Checks if the error is related to rate limiting (code -1003).
      */
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use thiserror::Error;
 use tracing::debug;
 
@@ -61,6 +63,25 @@ pub enum BinanceError {
      */
     #[error(transparent)]
     InvalidConfig(#[from] InvalidConfig),
+
+    /**
+     * Request timeout errors.
+     *
+     * These occur when a WebSocket API request receives no response within the
+     * configured request timeout. Whether the request was executed is unknown.
+     */
+    #[error("Request '{method}' timed out after {timeout:?}")]
+    Timeout { method: String, timeout: Duration },
+
+    /**
+     * Order placement outcome errors.
+     *
+     * These occur when an order placement failed without a definitive answer
+     * and the order could not be found afterwards. The order may still have
+     * been executed, so it must not be placed again blindly.
+     */
+    #[error("Order '{client_order_id}' has unknown outcome")]
+    OrderOutcomeUnknown { client_order_id: String },
 }
 
 impl BinanceError {
@@ -105,6 +126,13 @@ impl BinanceError {
         }
     }
 
+    /**
+     * Checks if this error represents a request timeout.
+     */
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, BinanceError::Timeout { .. })
+    }
+
     /**
      * Checks if this error might be retryable.
      */
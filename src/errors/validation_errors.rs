@@ -54,6 +54,26 @@ impl InvalidParameter {
         )
     }
 
+    /**
+     * Helper for a parameter value not permitted together with another parameter's value.
+     */
+    pub fn incompatible(
+        param: impl Into<String>,
+        value: impl std::fmt::Debug,
+        other_param: impl Into<String>,
+        other_value: impl std::fmt::Debug,
+    ) -> Self {
+        Self::new(
+            param,
+            format!(
+                "{:?} is not allowed when {} is {:?}",
+                value,
+                other_param.into(),
+                other_value
+            ),
+        )
+    }
+
     /**
      * Helper for required parameter validation.
      */
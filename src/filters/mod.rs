@@ -15,6 +15,8 @@ pub mod exchange;
 mod exchange_filter;
 mod parse_filter_failure;
 mod parse_trading_rejection;
+mod pre_trade_validator;
+mod step;
 pub mod symbol;
 mod symbol_filter;
 
@@ -22,5 +24,7 @@ pub use exchange::*;
 pub use exchange_filter::ExchangeFilter;
 pub use parse_filter_failure::{FilterFailure, parse_filter_failure};
 pub use parse_trading_rejection::{TradingRejectionMessage, parse_trading_rejection};
+pub use pre_trade_validator::PreTradeValidator;
+pub(crate) use step::is_step_compliant;
 pub use symbol::*;
 pub use symbol_filter::SymbolFilter;
@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+use crate::Result;
+use crate::enums::OrderType;
+use crate::errors::InvalidParameter;
+use crate::filters::{ExchangeFilter, FilterFailure, SymbolFilter, is_step_compliant};
+use crate::types::requests::OrderSpec;
+use crate::types::responses::{ExchangeInfo, Order, SymbolInfo};
+
+/**
+ * Validates orders against every applicable symbol and exchange filter at once.
+ *
+ * Unlike placing the order, which stops at the first rejected filter, the
+ * validator reports every violation, which suits form validation. Filters
+ * that depend on market data or balances (PERCENT_PRICE, PERCENT_PRICE_BY_SIDE,
+ * TRAILING_DELTA, MAX_POSITION) and notional checks of MARKET orders are not
+ * evaluated.
+ *
+ * # Fields
+ * - `symbols`: Symbol information with symbol-level filters, keyed by symbol.
+ * - `exchange_filters`: Exchange-level filters.
+ */
+#[derive(Debug, Clone)]
+pub struct PreTradeValidator {
+    symbols: HashMap<String, SymbolInfo>,
+    exchange_filters: Vec<ExchangeFilter>,
+}
+
+impl PreTradeValidator {
+    /**
+     * Creates a validator from exchange information.
+     *
+     * # Arguments
+     * - `exchange_info`: Exchange information providing symbol and exchange filters.
+     *
+     * # Returns
+     * - New PreTradeValidator instance.
+     */
+    pub fn new(exchange_info: &ExchangeInfo) -> Self {
+        Self {
+            symbols: exchange_info
+                .symbols
+                .iter()
+                .map(|symbol_info| (symbol_info.symbol.clone(), symbol_info.clone()))
+                .collect(),
+            exchange_filters: exchange_info.exchange_filters.clone(),
+        }
+    }
+
+    /**
+     * Validates an order against the filters of its symbol.
+     *
+     * Checks PRICE_FILTER, LOT_SIZE, MARKET_LOT_SIZE, MIN_NOTIONAL, NOTIONAL
+     * and ICEBERG_PARTS.
+     *
+     * # Arguments
+     * - `specification`: Order specification to validate.
+     *
+     * # Returns
+     * - `Vec<FilterFailure>`: Every violated filter, empty if the order passes.
+     */
+    pub fn validate<S>(&self, specification: &OrderSpec<S>) -> Result<Vec<FilterFailure>> {
+        let symbol_info = self.symbol_info(&specification.symbol)?;
+        let mut failures = Vec::new();
+
+        for filter in &symbol_info.filters {
+            let failure = match filter {
+                SymbolFilter::PriceFilter(filter) => {
+                    [specification.price, specification.stop_price]
+                        .into_iter()
+                        .flatten()
+                        .any(|price| {
+                            !within_range(
+                                price,
+                                &filter.min_price,
+                                &filter.max_price,
+                                &filter.tick_size,
+                            )
+                        })
+                        .then_some(FilterFailure::PriceFilter)
+                }
+                SymbolFilter::LotSize(filter) => {
+                    let quantity = specification
+                        .quantity
+                        .filter(|_| specification.order_type != OrderType::Market);
+                    [quantity, specification.iceberg_quantity]
+                        .into_iter()
+                        .flatten()
+                        .any(|quantity| {
+                            !within_range(
+                                quantity,
+                                &filter.min_qty,
+                                &filter.max_qty,
+                                &filter.step_size,
+                            )
+                        })
+                        .then_some(FilterFailure::LotSize)
+                }
+                SymbolFilter::MarketLotSize(filter) => specification
+                    .quantity
+                    .filter(|_| specification.order_type == OrderType::Market)
+                    .filter(|quantity| {
+                        !within_range(
+                            *quantity,
+                            &filter.min_qty,
+                            &filter.max_qty,
+                            &filter.step_size,
+                        )
+                    })
+                    .map(|_| FilterFailure::MarketLotSize),
+                SymbolFilter::MinNotional(filter) => notional(specification)
+                    .filter(|notional| {
+                        parse(&filter.min_notional).is_some_and(|min| *notional < min)
+                    })
+                    .map(|_| FilterFailure::MinNotional),
+                SymbolFilter::Notional(filter) => notional(specification)
+                    .filter(|notional| {
+                        parse(&filter.min_notional).is_some_and(|min| *notional < min)
+                            || parse(&filter.max_notional)
+                                .is_some_and(|max| max > Decimal::ZERO && *notional > max)
+                    })
+                    .map(|_| FilterFailure::Notional),
+                SymbolFilter::IcebergParts(filter) => specification
+                    .quantity
+                    .zip(specification.iceberg_quantity)
+                    .filter(|(quantity, iceberg_quantity)| {
+                        *iceberg_quantity > Decimal::ZERO
+                            && (quantity / iceberg_quantity).ceil() > Decimal::from(filter.limit)
+                    })
+                    .map(|_| FilterFailure::IcebergParts),
+                _ => None,
+            };
+            failures.extend(failure);
+        }
+
+        Ok(failures)
+    }
+
+    /**
+     * Validates an order against its symbol filters and the open order limits.
+     *
+     * In addition to `validate`, checks MAX_NUM_ORDERS, MAX_NUM_ALGO_ORDERS and
+     * MAX_NUM_ICEBERG_ORDERS for the symbol and their exchange-level
+     * counterparts across all symbols, assuming the order would be opened.
+     *
+     * # Arguments
+     * - `specification`: Order specification to validate.
+     * - `open_orders`: All open orders on the account.
+     *
+     * # Returns
+     * - `Vec<FilterFailure>`: Every violated filter, empty if the order passes.
+     */
+    pub fn validate_with_open_orders<S>(
+        &self,
+        specification: &OrderSpec<S>,
+        open_orders: &[Order],
+    ) -> Result<Vec<FilterFailure>> {
+        let mut failures = self.validate(specification)?;
+        let symbol_info = self.symbol_info(&specification.symbol)?;
+
+        let is_algo = specification.order_type.is_algo();
+        let is_iceberg = specification
+            .iceberg_quantity
+            .is_some_and(|quantity| quantity > Decimal::ZERO);
+        let symbol_orders: Vec<&Order> = open_orders
+            .iter()
+            .filter(|order| order.symbol == specification.symbol)
+            .collect();
+        let exceeds = |orders: &[&Order], predicate: fn(&Order) -> bool, max: u32| {
+            orders.iter().filter(|order| predicate(order)).count() as u64 + 1 > u64::from(max)
+        };
+        let all_orders: Vec<&Order> = open_orders.iter().collect();
+
+        for filter in &symbol_info.filters {
+            let failure = match filter {
+                SymbolFilter::MaxNumOrders(filter) => {
+                    exceeds(&symbol_orders, any_order, filter.max_num_orders)
+                        .then_some(FilterFailure::MaxNumOrders)
+                }
+                SymbolFilter::MaxNumAlgoOrders(filter) => (is_algo
+                    && exceeds(&symbol_orders, Order::is_algo, filter.max_num_algo_orders))
+                .then_some(FilterFailure::MaxNumAlgoOrders),
+                SymbolFilter::MaxNumIcebergOrders(filter) => (is_iceberg
+                    && exceeds(
+                        &symbol_orders,
+                        is_iceberg_order,
+                        filter.max_num_iceberg_orders,
+                    ))
+                .then_some(FilterFailure::MaxNumIcebergOrders),
+                _ => None,
+            };
+            failures.extend(failure);
+        }
+
+        for filter in &self.exchange_filters {
+            let failure = match filter {
+                ExchangeFilter::ExchangeMaxNumOrders(filter) => {
+                    exceeds(&all_orders, any_order, filter.max_num_orders)
+                        .then_some(FilterFailure::ExchangeMaxNumOrders)
+                }
+                ExchangeFilter::ExchangeMaxNumAlgoOrders(filter) => (is_algo
+                    && exceeds(&all_orders, Order::is_algo, filter.max_num_algo_orders))
+                .then_some(FilterFailure::ExchangeMaxNumAlgoOrders),
+                ExchangeFilter::ExchangeMaxNumIcebergOrders(filter) => (is_iceberg
+                    && exceeds(&all_orders, is_iceberg_order, filter.max_num_iceberg_orders))
+                .then_some(FilterFailure::ExchangeMaxNumIcebergOrders),
+            };
+            failures.extend(failure);
+        }
+
+        Ok(failures)
+    }
+
+    /**
+     * Gets the symbol information an order is validated against.
+     *
+     * # Arguments
+     * - `symbol`: Trading symbol of the order.
+     *
+     * # Returns
+     * - `&SymbolInfo`: Symbol information from the exchange information.
+     */
+    fn symbol_info(&self, symbol: &str) -> Result<&SymbolInfo> {
+        self.symbols
+            .get(symbol)
+            .ok_or_else(|| InvalidParameter::new("symbol", format!("{} not found", symbol)).into())
+    }
+}
+
+/**
+ * Parses a filter value, treating malformed values as absent.
+ */
+fn parse(value: &str) -> Option<Decimal> {
+    Decimal::from_str(value).ok()
+}
+
+/**
+ * Checks a value against a minimum, maximum and step; zero disables a rule.
+ */
+fn within_range(value: Decimal, min_value: &str, max_value: &str, step: &str) -> bool {
+    let min = parse(min_value).unwrap_or_default();
+    let max = parse(max_value).unwrap_or_default();
+
+    (min.is_zero() || value >= min)
+        && (max.is_zero() || value <= max)
+        && is_step_compliant(value, min_value, step)
+}
+
+/**
+ * Computes the notional of a priced order; MARKET orders have none.
+ */
+fn notional<S>(specification: &OrderSpec<S>) -> Option<Decimal> {
+    if specification.order_type == OrderType::Market {
+        return None;
+    }
+    specification
+        .price
+        .zip(specification.quantity)
+        .map(|(price, quantity)| price * quantity)
+}
+
+fn any_order(_: &Order) -> bool {
+    true
+}
+
+fn is_iceberg_order(order: &Order) -> bool {
+    order
+        .iceberg_quantity
+        .is_some_and(|quantity| quantity > Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::enums::{OrderSide, TimeInForce};
+    use crate::types::responses::test_fixtures;
+
+    /**
+     * Loads the BNBBTC exchange info fixture with price, lot size and notional filters.
+     */
+    fn exchange_info() -> ExchangeInfo {
+        test_fixtures::exchange_info(
+            serde_json::json!([
+                { "filterType": "PRICE_FILTER", "minPrice": "0.00000100", "maxPrice": "100000.00000000", "tickSize": "0.00000100" },
+                { "filterType": "LOT_SIZE", "minQty": "0.00100000", "maxQty": "100000.00000000", "stepSize": "0.00100000" },
+                { "filterType": "NOTIONAL", "minNotional": "0.00010000", "applyMinToMarket": true, "maxNotional": "9000000.00000000", "applyMaxToMarket": false, "avgPriceMins": 5 },
+                { "filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200 }
+            ]),
+            serde_json::json!([{ "filterType": "EXCHANGE_MAX_NUM_ORDERS", "maxNumOrders": 1 }]),
+        )
+    }
+
+    /**
+     * Builds a LIMIT BUY order for BNBBTC.
+     */
+    fn limit_order(price: Decimal, quantity: Decimal) -> OrderSpec {
+        OrderSpec::new("BNBBTC", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_price(price)
+            .with_quantity(quantity)
+    }
+
+    /**
+     * Tests that an order violating tick size and minimum notional reports both failures.
+     */
+    #[test]
+    fn test_reports_all_failures() {
+        // Arrange
+        let validator = PreTradeValidator::new(&exchange_info());
+        let order = limit_order(dec!(0.0010005), dec!(0.01));
+
+        // Act
+        let failures = validator.validate(&order).unwrap();
+
+        // Assert
+        assert_eq!(
+            failures,
+            vec![FilterFailure::PriceFilter, FilterFailure::Notional]
+        );
+    }
+
+    /**
+     * Tests that an order satisfying every filter passes.
+     */
+    #[test]
+    fn test_valid_order_passes() {
+        // Arrange
+        let validator = PreTradeValidator::new(&exchange_info());
+        let order = limit_order(dec!(0.001), dec!(1));
+
+        // Act
+        let failures = validator.validate(&order).unwrap();
+
+        // Assert
+        assert!(failures.is_empty());
+    }
+
+    /**
+     * Tests that exchange-level order limits count open orders across symbols.
+     */
+    #[test]
+    fn test_exchange_max_num_orders() {
+        // Arrange
+        let validator = PreTradeValidator::new(&exchange_info());
+        let order = limit_order(dec!(0.001), dec!(1));
+        let open_order: Order = serde_json::from_value(serde_json::json!({
+            "symbol": "ETHBTC", "orderId": 1, "orderListId": -1, "clientOrderId": "open-1",
+            "price": "0.05000000", "origQty": "1.00000000", "executedQty": "0.00000000",
+            "cummulativeQuoteQty": "0.00000000", "status": "NEW", "timeInForce": "GTC",
+            "type": "LIMIT", "side": "BUY"
+        }))
+        .unwrap();
+
+        // Act
+        let failures = validator
+            .validate_with_open_orders(&order, &[open_order])
+            .unwrap();
+
+        // Assert
+        assert_eq!(failures, vec![FilterFailure::ExchangeMaxNumOrders]);
+    }
+
+    /**
+     * Tests that orders for unknown symbols are rejected.
+     */
+    #[test]
+    fn test_unknown_symbol() {
+        // Arrange
+        let validator = PreTradeValidator::new(&exchange_info());
+        let order = OrderSpec::new("ETHBTC", OrderSide::Buy, OrderType::Market);
+
+        // Act
+        let result = validator.validate(&order);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+
+/**
+ * Checks that a value is a whole number of steps above the minimum.
+ *
+ * Filter values are parsed as sent by the exchange; a malformed value counts
+ * as zero, and a zero step disables the check.
+ *
+ * # Arguments
+ * - `value`: Price or quantity to check.
+ * - `min`: Minimum of the filter the step grid starts from.
+ * - `step`: Tick or step size of the filter.
+ *
+ * # Returns
+ * - `bool`: True if the value lies on the step grid.
+ */
+pub(crate) fn is_step_compliant(value: Decimal, min: &str, step: &str) -> bool {
+    let min = Decimal::from_str(min).unwrap_or_default();
+    let step = Decimal::from_str(step).unwrap_or_default();
+    step.is_zero() || ((value - min) % step).is_zero()
+}
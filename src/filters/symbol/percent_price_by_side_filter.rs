@@ -1,5 +1,12 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::Result;
+use crate::enums::OrderSide;
+
 /**
  * This filter defines the valid range for the price based on the average of the previous trades.
  * There is a different range depending on whether the order is placed on the BUY side or the SELL side.
@@ -32,3 +39,31 @@ pub struct PercentPriceBySideFilter {
     #[serde(rename = "avgPriceMins")]
     pub avg_price_mins: u16,
 }
+
+impl PercentPriceBySideFilter {
+    /**
+     * Computes the allowed price range for an order side.
+     *
+     * # Arguments
+     * - `side`: Side of the order; BUY uses the bid multipliers, SELL the ask multipliers.
+     * - `weighted_average_price`: Average price over `avg_price_mins`.
+     *
+     * # Returns
+     * - `(Decimal, Decimal)`: Inclusive lower and upper price bounds.
+     */
+    pub fn price_bounds(
+        &self,
+        side: OrderSide,
+        weighted_average_price: Decimal,
+    ) -> Result<(Decimal, Decimal)> {
+        let (down, up) = match side {
+            OrderSide::Buy => (&self.bid_multiplier_down, &self.bid_multiplier_up),
+            OrderSide::Sell => (&self.ask_multiplier_down, &self.ask_multiplier_up),
+            OrderSide::Unknown => anyhow::bail!("Cannot derive price bounds for an unknown side"),
+        };
+        let down = Decimal::from_str(down).context("Invalid PERCENT_PRICE_BY_SIDE multiplier")?;
+        let up = Decimal::from_str(up).context("Invalid PERCENT_PRICE_BY_SIDE multiplier")?;
+
+        Ok((weighted_average_price * down, weighted_average_price * up))
+    }
+}
@@ -1,5 +1,11 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::Result;
+
 /**
  * This filter defines the valid range for the price based on the average of the previous trades.
  *
@@ -22,3 +28,23 @@ pub struct PercentPriceFilter {
     #[serde(rename = "avgPriceMins")]
     pub avg_price_mins: u16,
 }
+
+impl PercentPriceFilter {
+    /**
+     * Computes the allowed price range around the weighted average price.
+     *
+     * # Arguments
+     * - `weighted_average_price`: Average price over `avg_price_mins`.
+     *
+     * # Returns
+     * - `(Decimal, Decimal)`: Inclusive lower and upper price bounds.
+     */
+    pub fn price_bounds(&self, weighted_average_price: Decimal) -> Result<(Decimal, Decimal)> {
+        let down =
+            Decimal::from_str(&self.multiplier_down).context("Invalid PERCENT_PRICE multiplier")?;
+        let up =
+            Decimal::from_str(&self.multiplier_up).context("Invalid PERCENT_PRICE multiplier")?;
+
+        Ok((weighted_average_price * down, weighted_average_price * up))
+    }
+}
@@ -7,13 +7,16 @@ mod filters;
 pub mod streams;
 pub mod types;
 
-pub use auth::Ed25519Signer;
+pub use auth::{Clock, Ed25519Signer, FixedClock, OffsetClock, SystemClock};
 pub use clients::r#trait::BinanceSpotClient;
-pub use config::{BinanceConfig, RestConfig, StreamConfig, WebSocketConfig};
+pub use config::{
+    BinanceConfig, BinanceEndpoint, RestConfig, ShutdownToken, StreamConfig, WebSocketConfig,
+};
 pub use enums::BINANCE_ENUM_VERSION;
 pub use errors::BINANCE_ERROR_VERSION;
 pub use errors::BinanceError;
 pub use filters::BINANCE_FILTER_VERSION;
+pub use filters::{FilterFailure, PreTradeValidator};
 
 pub type Result<T> = anyhow::Result<T>;
 
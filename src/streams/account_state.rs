@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use rust_decimal::Decimal;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use super::client::TypedSubscription;
+use super::events::{
+    BalanceUpdateEvent, ExternalLockUpdateEvent, OutboundAccountPositionEvent, UserDataEvent,
+};
+use crate::types::responses::{AccountInfo, Balance};
+
+/**
+ * Live account balances built from user data stream events.
+ *
+ * Account position events overwrite the balances they list, while balance
+ * and external lock updates adjust them by their delta. Deltas that are not
+ * newer than the last position received for an asset are already included
+ * in it and are skipped. A lagged subscription loses deltas, so the state
+ * is marked stale until it is resynced from a REST account snapshot.
+ * Cloning the state shares the same underlying map.
+ *
+ * # Fields
+ * - `balances`: Current balance and last position update time per asset.
+ * - `stale`: Whether events were missed since the last snapshot.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct AccountState {
+    balances: Arc<RwLock<HashMap<String, (Balance, u64)>>>,
+    stale: Arc<AtomicBool>,
+}
+
+impl AccountState {
+    /**
+     * Creates an empty account state.
+     *
+     * # Returns
+     * - New AccountState instance.
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Creates an account state seeded from a REST account snapshot.
+     *
+     * # Arguments
+     * - `account`: Account information from `GET /api/v3/account`.
+     *
+     * # Returns
+     * - New AccountState instance.
+     */
+    pub fn from_account_info(account: &AccountInfo) -> Self {
+        let state = Self::new();
+        state.resync(account);
+        state
+    }
+
+    /**
+     * Replaces every balance with a REST account snapshot and clears the stale flag.
+     *
+     * # Arguments
+     * - `account`: Account information from `GET /api/v3/account`.
+     */
+    pub fn resync(&self, account: &AccountInfo) {
+        *self.balances.write().expect("account state lock poisoned") = account
+            .balances
+            .iter()
+            .map(|balance| {
+                (
+                    balance.asset.clone(),
+                    (balance.clone(), account.update_time),
+                )
+            })
+            .collect();
+        self.stale.store(false, Ordering::SeqCst);
+    }
+
+    /**
+     * Marks the balances as stale, e.g. after the subscription lagged.
+     */
+    pub fn invalidate(&self) {
+        self.stale.store(true, Ordering::SeqCst);
+    }
+
+    /**
+     * Checks whether balance events were missed since the last snapshot.
+     *
+     * # Returns
+     * - `bool`: True if the balances should be resynced with `resync`.
+     */
+    pub fn is_stale(&self) -> bool {
+        self.stale.load(Ordering::SeqCst)
+    }
+
+    /**
+     * Applies a user data event to the balances.
+     *
+     * Events that do not affect balances are ignored.
+     *
+     * # Arguments
+     * - `event`: User data stream event.
+     */
+    pub fn apply(&self, event: &UserDataEvent) {
+        match event {
+            UserDataEvent::OutboundAccountPosition(event) => self.apply_account_position(event),
+            UserDataEvent::BalanceUpdate(event) => self.apply_balance_update(event),
+            UserDataEvent::ExternalLockUpdate(event) => self.apply_external_lock_update(event),
+            _ => {}
+        }
+    }
+
+    /**
+     * Replaces the balances listed in an account position event.
+     *
+     * # Arguments
+     * - `event`: Account position event.
+     */
+    pub fn apply_account_position(&self, event: &OutboundAccountPositionEvent) {
+        let mut balances = self.balances.write().expect("account state lock poisoned");
+        for balance in &event.balances {
+            balances.insert(
+                balance.asset.clone(),
+                (balance.clone(), event.last_update_time),
+            );
+        }
+    }
+
+    /**
+     * Adjusts the free balance of an asset by a deposit, withdrawal or transfer.
+     *
+     * # Arguments
+     * - `event`: Balance update event.
+     */
+    pub fn apply_balance_update(&self, event: &BalanceUpdateEvent) {
+        self.adjust(&event.asset, event.clear_time, |balance| {
+            balance.free += event.balance_delta;
+        });
+    }
+
+    /**
+     * Moves balance of an asset between free and locked for an external lock.
+     *
+     * # Arguments
+     * - `event`: External lock update event; a positive delta locks funds.
+     */
+    pub fn apply_external_lock_update(&self, event: &ExternalLockUpdateEvent) {
+        self.adjust(&event.asset, event.transaction_time, |balance| {
+            balance.free -= event.delta;
+            balance.locked += event.delta;
+        });
+    }
+
+    /**
+     * Gets the current balance of an asset.
+     *
+     * # Arguments
+     * - `asset`: Asset symbol to look up.
+     *
+     * # Returns
+     * - `Option<Balance>`: Balance, or None if the asset has not been seen.
+     */
+    pub fn balance(&self, asset: &str) -> Option<Balance> {
+        self.balances
+            .read()
+            .expect("account state lock poisoned")
+            .get(asset)
+            .map(|(balance, _)| balance.clone())
+    }
+
+    /**
+     * Gets the current balances of all assets.
+     *
+     * # Returns
+     * - `Vec<Balance>`: Balance of every asset seen so far.
+     */
+    pub fn balances(&self) -> Vec<Balance> {
+        self.balances
+            .read()
+            .expect("account state lock poisoned")
+            .values()
+            .map(|(balance, _)| balance.clone())
+            .collect()
+    }
+
+    /**
+     * Consumes a user data subscription in the background.
+     *
+     * The task runs until the subscription is closed. A lagged subscription
+     * marks the state stale, since the skipped balance deltas are lost; it
+     * should then be resynced from a fresh account snapshot with `resync`.
+     *
+     * # Arguments
+     * - `subscription`: User data stream subscription.
+     *
+     * # Returns
+     * - `JoinHandle<()>`: Handle of the background task.
+     */
+    pub fn track(&self, mut subscription: TypedSubscription<UserDataEvent>) -> JoinHandle<()> {
+        let state = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match subscription.recv().await {
+                    Ok(event) => state.apply(&event),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            skipped,
+                            "Account state lagged behind user data stream, resync required"
+                        );
+                        state.invalidate();
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    /**
+     * Applies a delta to an asset unless a newer position already covers it.
+     */
+    fn adjust(&self, asset: &str, time: u64, update: impl FnOnce(&mut Balance)) {
+        let mut balances = self.balances.write().expect("account state lock poisoned");
+        let (balance, position_time) = balances.entry(asset.to_string()).or_insert_with(|| {
+            (
+                Balance {
+                    asset: asset.to_string(),
+                    free: Decimal::ZERO,
+                    locked: Decimal::ZERO,
+                },
+                0,
+            )
+        });
+
+        if time <= *position_time {
+            return;
+        }
+        update(balance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(value: serde_json::Value) -> UserDataEvent {
+        serde_json::from_value(value).unwrap()
+    }
+
+    /**
+     * Tests replaying position, balance and external lock updates.
+     */
+    #[test]
+    fn test_replays_balance_events() {
+        // Arrange
+        let state = AccountState::new();
+        let events = [
+            event(json!({
+                "e": "outboundAccountPosition", "E": 1000, "u": 1000,
+                "B": [
+                    {"a": "BTC", "f": "1.00000000", "l": "0.00000000"},
+                    {"a": "USDT", "f": "500.00000000", "l": "100.00000000"}
+                ]
+            })),
+            event(json!({
+                "e": "balanceUpdate", "E": 1001, "a": "BTC", "d": "0.50000000", "T": 1001
+            })),
+            event(json!({
+                "e": "externalLockUpdate", "E": 1002, "a": "USDT", "d": "50.00000000", "T": 1002
+            })),
+            event(json!({
+                "e": "balanceUpdate", "E": 1003, "a": "ETH", "d": "2.00000000", "T": 1003
+            })),
+        ];
+
+        // Act
+        for event in &events {
+            state.apply(event);
+        }
+
+        // Assert
+        let btc = state.balance("BTC").unwrap();
+        assert_eq!((btc.free, btc.locked), (dec!(1.5), dec!(0)));
+        let usdt = state.balance("USDT").unwrap();
+        assert_eq!((usdt.free, usdt.locked), (dec!(450), dec!(150)));
+        let eth = state.balance("ETH").unwrap();
+        assert_eq!((eth.free, eth.locked), (dec!(2), dec!(0)));
+        assert_eq!(state.balances().len(), 3);
+    }
+
+    /**
+     * Tests that a delta already included in a newer position is not applied twice.
+     */
+    #[test]
+    fn test_skips_delta_covered_by_position() {
+        // Arrange
+        let state = AccountState::new();
+
+        // Act
+        state.apply(&event(json!({
+            "e": "outboundAccountPosition", "E": 2000, "u": 2000,
+            "B": [{"a": "BTC", "f": "1.50000000", "l": "0.00000000"}]
+        })));
+        state.apply(&event(json!({
+            "e": "balanceUpdate", "E": 1999, "a": "BTC", "d": "0.50000000", "T": 1999
+        })));
+
+        // Assert
+        assert_eq!(state.balance("BTC").unwrap().free, dec!(1.5));
+    }
+
+    /**
+     * Tests that a stale state is replaced by the snapshot passed to resync.
+     */
+    #[test]
+    fn test_resync_clears_stale_balances() {
+        // Arrange
+        let state = AccountState::new();
+        state.apply(&event(json!({
+            "e": "outboundAccountPosition", "E": 1000, "u": 1000,
+            "B": [{"a": "BNB", "f": "3.00000000", "l": "0.00000000"}]
+        })));
+        state.invalidate();
+        let account: AccountInfo = serde_json::from_value(json!({
+            "makerCommission": 15, "takerCommission": 15, "buyerCommission": 0, "sellerCommission": 0,
+            "commissionRates": {"maker": "0.00150000", "taker": "0.00150000", "buyer": "0.00000000", "seller": "0.00000000"},
+            "canTrade": true, "canWithdraw": true, "canDeposit": true, "brokered": false,
+            "requireSelfTradePrevention": false, "preventSor": false, "updateTime": 2000,
+            "accountType": "SPOT",
+            "balances": [{"asset": "BTC", "free": "0.50000000", "locked": "0.25000000"}],
+            "permissions": ["SPOT"], "uid": 354937868
+        }))
+        .unwrap();
+
+        // Act
+        let stale = state.is_stale();
+        state.resync(&account);
+
+        // Assert
+        assert!(stale);
+        assert!(!state.is_stale());
+        assert_eq!(state.balance("BNB"), None);
+        assert_eq!(state.balance("BTC").unwrap().locked, dec!(0.25));
+    }
+}
@@ -0,0 +1,582 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+use super::ConnectionStatus;
+use super::events::{DepthLevel, DiffDepthStreamEvent, PartialBookDepthStreamEvent};
+use super::specs::UpdateSpeed;
+use crate::Result;
+use crate::types::responses::{OrderBook, OrderBookLevel};
+
+/**
+ * Local order book maintained from a diff depth stream.
+ *
+ * Diffs received before the book is synced are buffered. `resync` seeds the
+ * book from a REST snapshot and replays the buffer on top, after which diffs
+ * are applied directly. A gap in the update IDs marks the book as unsynced
+ * again, so diffs are buffered until the next `resync`.
+ *
+ * # Fields
+ * - `book`: Current order book.
+ * - `update_speed`: Speed of the diff depth stream maintaining the book.
+ * - `buffered`: Diffs received while the book is unsynced.
+ * - `synced`: Whether the book reflects every diff since its snapshot.
+ */
+#[derive(Debug, Clone)]
+pub struct OrderBookManager {
+    book: OrderBook,
+    update_speed: UpdateSpeed,
+    buffered: Vec<DiffDepthStreamEvent>,
+    synced: bool,
+}
+
+impl OrderBookManager {
+    /**
+     * Creates an unsynced manager that buffers diffs until the first `resync`.
+     *
+     * # Arguments
+     * - `update_speed`: Update speed the diff depth stream was subscribed with.
+     *
+     * # Returns
+     * - New OrderBookManager instance.
+     */
+    pub fn new(update_speed: UpdateSpeed) -> Self {
+        Self {
+            book: OrderBook {
+                last_update_id: 0,
+                bids: Vec::new(),
+                asks: Vec::new(),
+            },
+            update_speed,
+            buffered: Vec::new(),
+            synced: false,
+        }
+    }
+
+    /**
+     * Creates a synced manager from a snapshot.
+     *
+     * # Arguments
+     * - `snapshot`: Depth snapshot to maintain.
+     * - `update_speed`: Update speed the diff depth stream was subscribed with.
+     *
+     * # Returns
+     * - New OrderBookManager instance.
+     */
+    pub fn from_snapshot(snapshot: OrderBook, update_speed: UpdateSpeed) -> Self {
+        Self {
+            book: snapshot,
+            update_speed,
+            buffered: Vec::new(),
+            synced: true,
+        }
+    }
+
+    /**
+     * Gets the current order book.
+     *
+     * # Returns
+     * - `&OrderBook`: Book as of the last applied diff.
+     */
+    pub fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /**
+     * Gets the speed of the diff depth stream maintaining the book.
+     *
+     * # Returns
+     * - `UpdateSpeed`: Update speed of the stream.
+     */
+    pub fn update_speed(&self) -> UpdateSpeed {
+        self.update_speed
+    }
+
+    /**
+     * Checks whether the book reflects every diff since its snapshot.
+     *
+     * # Returns
+     * - `bool`: False while diffs are buffered waiting for a `resync`.
+     */
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /**
+     * Gets how long diffs should be buffered before fetching a snapshot.
+     *
+     * Buffering for at least one update interval ensures the diff that
+     * straddles the snapshot's `last_update_id` has arrived before `resync`
+     * replays the buffer, for both the 1000ms and 100ms streams.
+     *
+     * # Returns
+     * - `Duration`: Update interval of the diff depth stream.
+     */
+    pub fn buffer_window(&self) -> Duration {
+        self.update_speed.interval()
+    }
+
+    /**
+     * Marks the book as stale, buffering diffs until the next `resync`.
+     *
+     * Used after a reconnect or a lagged subscription, when diffs may have
+     * been missed.
+     */
+    pub fn invalidate(&mut self) {
+        self.synced = false;
+        self.buffered.clear();
+    }
+
+    /**
+     * Applies a diff depth update, or buffers it while the book is unsynced.
+     *
+     * Updates already covered by the book are skipped. A gap marks the book
+     * as unsynced and buffers the diff.
+     *
+     * # Arguments
+     * - `event`: Diff depth event to apply.
+     *
+     * # Returns
+     * - `bool`: True if the update was applied, false if it was buffered or already covered.
+     */
+    pub fn apply_diff(&mut self, event: &DiffDepthStreamEvent) -> Result<bool> {
+        if !self.synced {
+            self.buffered.push(event.clone());
+            return Ok(false);
+        }
+
+        apply_diff(&mut self.book, event).inspect_err(|_| {
+            self.invalidate();
+            self.buffered.push(event.clone());
+        })
+    }
+
+    /**
+     * Replaces the book with a partial book depth snapshot.
+     *
+     * An alternative to diff reconciliation: each partial depth event is a
+     * self-contained top-N snapshot, so the book needs no REST snapshot or
+     * gap tracking and simply holds the latest levels. Levels beyond the
+     * subscribed depth are not kept.
+     *
+     * # Arguments
+     * - `event`: Partial book depth event to take the levels from.
+     */
+    pub fn apply_partial(&mut self, event: &PartialBookDepthStreamEvent) {
+        let to_levels = |levels: &[DepthLevel]| -> Vec<OrderBookLevel> {
+            levels
+                .iter()
+                .map(|level| OrderBookLevel {
+                    price: level.price(),
+                    quantity: level.quantity(),
+                })
+                .collect()
+        };
+        self.book.bids = to_levels(&event.bids);
+        self.book.asks = to_levels(&event.asks);
+        self.book.last_update_id = event.last_update_id;
+        self.synced = true;
+        self.buffered.clear();
+    }
+
+    /**
+     * Re-seeds the book from a fresh snapshot and replays buffered diffs on top.
+     *
+     * # Arguments
+     * - `snapshot`: Fresh depth snapshot.
+     *
+     * # Returns
+     * - `()`: Ok if every buffered diff could be applied in sequence; the book
+     *   stays unsynced otherwise.
+     */
+    pub fn resync(&mut self, snapshot: OrderBook) -> Result<()> {
+        self.book = snapshot;
+        for event in std::mem::take(&mut self.buffered) {
+            if let Err(e) = apply_diff(&mut self.book, &event) {
+                self.invalidate();
+                return Err(e);
+            }
+        }
+        self.synced = true;
+        Ok(())
+    }
+
+    /**
+     * Runs a callback every time the stream reconnects.
+     *
+     * A locally maintained book is stale after a reconnect, so the callback
+     * should `invalidate` the manager, refetch a snapshot and call `resync`.
+     * The task ends when the connection is closed or fails permanently.
+     *
+     * # Arguments
+     * - `status_receiver`: Connection status receiver from the stream client.
+     * - `callback`: Async callback invoked after each reconnect.
+     *
+     * # Returns
+     * - `JoinHandle<()>`: Handle of the background task.
+     */
+    pub fn on_resync_required<F, Fut>(
+        mut status_receiver: watch::Receiver<ConnectionStatus>,
+        mut callback: F,
+    ) -> tokio::task::JoinHandle<()>
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        tokio::spawn(async move {
+            let mut connected_once = matches!(
+                *status_receiver.borrow_and_update(),
+                ConnectionStatus::Connected
+            );
+
+            while status_receiver.changed().await.is_ok() {
+                let status = status_receiver.borrow_and_update().clone();
+                match status {
+                    ConnectionStatus::Connected => {
+                        if connected_once {
+                            callback().await;
+                        }
+                        connected_once = true;
+                    }
+                    ConnectionStatus::Disconnected | ConnectionStatus::Failed => break,
+                    ConnectionStatus::Connecting
+                    | ConnectionStatus::Reconnecting { .. }
+                    | ConnectionStatus::Closed(_) => {}
+                }
+            }
+        })
+    }
+}
+
+/**
+ * Applies a diff depth update to a book.
+ *
+ * Updates already covered by the book are skipped. A zero quantity removes
+ * the price level.
+ *
+ * # Arguments
+ * - `book`: Book to update.
+ * - `event`: Diff depth event to apply.
+ *
+ * # Returns
+ * - `bool`: True if the update was applied, false if it was already covered.
+ */
+pub(crate) fn apply_diff(book: &mut OrderBook, event: &DiffDepthStreamEvent) -> Result<bool> {
+    if event.final_update_id <= book.last_update_id {
+        return Ok(false);
+    }
+
+    if event.first_update_id > book.last_update_id + 1 {
+        return Err(anyhow::anyhow!(
+            "Gap in depth updates: expected update {}, got {}..={}",
+            book.last_update_id + 1,
+            event.first_update_id,
+            event.final_update_id
+        ));
+    }
+
+    apply_levels(&mut book.bids, &event.bids, true);
+    apply_levels(&mut book.asks, &event.asks, false);
+    book.last_update_id = event.final_update_id;
+    Ok(true)
+}
+
+/**
+ * Applies depth levels to one side of the book, keeping it sorted.
+ *
+ * # Arguments
+ * - `levels`: Levels on one side of the book.
+ * - `updates`: Depth updates to apply.
+ * - `descending`: True for bids, which are sorted from highest to lowest price.
+ */
+fn apply_levels(levels: &mut Vec<OrderBookLevel>, updates: &[DepthLevel], descending: bool) {
+    for update in updates {
+        let position = levels.binary_search_by(|level| {
+            if descending {
+                update.price().cmp(&level.price)
+            } else {
+                level.price.cmp(&update.price())
+            }
+        });
+
+        match (position, update.quantity().is_zero()) {
+            (Ok(index), true) => {
+                levels.remove(index);
+            }
+            (Ok(index), false) => levels[index].quantity = update.quantity(),
+            (Err(index), false) => levels.insert(
+                index,
+                OrderBookLevel {
+                    price: update.price(),
+                    quantity: update.quantity(),
+                },
+            ),
+            (Err(_), true) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use rust_decimal::dec;
+    use tokio::sync::{Mutex, Notify};
+
+    use super::*;
+    use crate::streams::specs::{DiffDepthStreamSpec, StreamSpec};
+    use crate::streams::test_fixtures::diff_depth;
+
+    /**
+     * Builds a diff depth event updating a single bid level.
+     */
+    fn diff(first_update_id: u64, final_update_id: u64, bid: (&str, &str)) -> DiffDepthStreamEvent {
+        diff_depth("BNBBTC", first_update_id, final_update_id, &[bid], &[])
+    }
+
+    /**
+     * Builds a partial book depth event with one bid and one ask level.
+     */
+    fn partial(
+        last_update_id: u64,
+        bid: (&str, &str),
+        ask: (&str, &str),
+    ) -> PartialBookDepthStreamEvent {
+        serde_json::from_value(serde_json::json!({
+            "lastUpdateId": last_update_id,
+            "bids": [[bid.0, bid.1]], "asks": [[ask.0, ask.1]]
+        }))
+        .unwrap()
+    }
+
+    /**
+     * Tests that each partial depth event replaces the book with its snapshot.
+     */
+    #[test]
+    fn test_apply_partial_reflects_latest_snapshot() {
+        // Arrange
+        let mut manager = OrderBookManager::new(UpdateSpeed::Standard);
+        let events = [
+            partial(160, ("0.0024", "10"), ("0.0026", "100")),
+            partial(165, ("0.0025", "4"), ("0.0026", "80")),
+            partial(40, ("0.0023", "7"), ("0.0027", "5")),
+        ];
+
+        for event in &events {
+            // Act
+            manager.apply_partial(event);
+
+            // Assert
+            let book = manager.book();
+            assert!(manager.is_synced());
+            assert_eq!(book.last_update_id, event.last_update_id);
+            assert_eq!(book.bids.len(), 1);
+            assert_eq!(book.asks.len(), 1);
+            assert_eq!(book.best_bid().unwrap().price, event.bids[0].price());
+            assert_eq!(book.best_bid().unwrap().quantity, event.bids[0].quantity());
+            assert_eq!(book.best_ask().unwrap().price, event.asks[0].price());
+            assert_eq!(book.best_ask().unwrap().quantity, event.asks[0].quantity());
+        }
+        assert_eq!(manager.book().best_bid().unwrap().price, dec!(0.0023));
+    }
+
+    /**
+     * Tests that a reconnect re-seeds the book from a fresh snapshot and buffered diffs.
+     */
+    #[tokio::test]
+    async fn test_resync_on_reconnect() {
+        // Arrange
+        let stale: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":100,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}"#,
+        )
+        .unwrap();
+        let manager = Arc::new(Mutex::new(OrderBookManager::from_snapshot(
+            stale,
+            UpdateSpeed::Standard,
+        )));
+        let resyncs = Arc::new(AtomicUsize::new(0));
+        let resynced = Arc::new(Notify::new());
+        let (status_sender, status_receiver) = watch::channel(ConnectionStatus::Connecting);
+
+        let handle = OrderBookManager::on_resync_required(status_receiver, {
+            let manager = manager.clone();
+            let resyncs = resyncs.clone();
+            let resynced = resynced.clone();
+            move || {
+                let manager = manager.clone();
+                let resyncs = resyncs.clone();
+                let resynced = resynced.clone();
+                async move {
+                    let mut manager = manager.lock().await;
+                    manager.invalidate();
+                    manager
+                        .apply_diff(&diff(490, 499, ("0.0001", "1")))
+                        .unwrap();
+                    manager
+                        .apply_diff(&diff(495, 510, ("0.0031", "2")))
+                        .unwrap();
+                    let snapshot: OrderBook = serde_json::from_str(
+                        r#"{"lastUpdateId":500,"bids":[["0.0030","3"]],"asks":[["0.0032","8"]]}"#,
+                    )
+                    .unwrap();
+                    manager.resync(snapshot).unwrap();
+                    resyncs.fetch_add(1, Ordering::SeqCst);
+                    resynced.notify_one();
+                }
+            }
+        });
+
+        // Act
+        status_sender.send(ConnectionStatus::Connected).unwrap();
+        tokio::task::yield_now().await;
+        status_sender
+            .send(ConnectionStatus::Reconnecting { attempt: 1 })
+            .unwrap();
+        tokio::task::yield_now().await;
+        status_sender.send(ConnectionStatus::Connected).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), resynced.notified())
+            .await
+            .unwrap();
+
+        // Assert
+        let manager = manager.lock().await;
+        let book = manager.book();
+        assert_eq!(resyncs.load(Ordering::SeqCst), 1);
+        assert!(manager.is_synced());
+        assert_eq!(book.last_update_id, 510);
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.0031));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.0032));
+        assert_eq!(book.bids.len(), 2);
+        handle.abort();
+    }
+
+    /**
+     * Tests that a zero quantity removes an existing bid while other levels remain.
+     */
+    #[test]
+    fn test_apply_diff_removes_zero_quantity_level() {
+        // Arrange
+        let mut book: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":100,"bids":[["0.0025","5"],["0.0024","10"],["0.0023","7"]],"asks":[["0.0026","100"]]}"#,
+        )
+        .unwrap();
+
+        // Act
+        let applied = apply_diff(&mut book, &diff(101, 101, ("0.0024", "0"))).unwrap();
+
+        // Assert
+        assert!(applied);
+        assert_eq!(
+            book.bids
+                .iter()
+                .map(|level| level.price)
+                .collect::<Vec<_>>(),
+            [dec!(0.0025), dec!(0.0023)]
+        );
+        assert_eq!(book.asks.len(), 1);
+        assert_eq!(book.last_update_id, 101);
+    }
+
+    /**
+     * Tests that non-zero quantities update existing levels and insert new ones in order.
+     */
+    #[test]
+    fn test_apply_diff_updates_and_inserts_levels() {
+        // Arrange
+        let mut book: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":100,"bids":[["0.0025","5"],["0.0023","7"]],"asks":[]}"#,
+        )
+        .unwrap();
+
+        // Act
+        apply_diff(&mut book, &diff(101, 101, ("0.0025", "6"))).unwrap();
+        apply_diff(&mut book, &diff(102, 102, ("0.0024", "1"))).unwrap();
+        apply_diff(&mut book, &diff(103, 103, ("0.0010", "0"))).unwrap();
+
+        // Assert
+        assert!(book.is_valid());
+        assert_eq!(book.bids.len(), 3);
+        assert_eq!(book.bids[0].quantity, dec!(6));
+        assert_eq!(book.bids[1].price, dec!(0.0024));
+    }
+
+    /**
+     * Tests that a gap marks the book unsynced and buffers diffs until the next resync.
+     */
+    #[test]
+    fn test_apply_diff_gap_requires_resync() {
+        // Arrange
+        let snapshot: OrderBook =
+            serde_json::from_str(r#"{"lastUpdateId":100,"bids":[],"asks":[]}"#).unwrap();
+        let mut manager = OrderBookManager::from_snapshot(snapshot, UpdateSpeed::Standard);
+
+        // Act
+        let gap = manager.apply_diff(&diff(105, 110, ("0.0024", "1")));
+        let buffered = manager
+            .apply_diff(&diff(111, 111, ("0.0023", "2")))
+            .unwrap();
+
+        // Assert
+        assert!(gap.is_err());
+        assert!(!buffered);
+        assert!(!manager.is_synced());
+        assert_eq!(manager.book().last_update_id, 100);
+
+        let fresh: OrderBook =
+            serde_json::from_str(r#"{"lastUpdateId":106,"bids":[],"asks":[]}"#).unwrap();
+        manager.resync(fresh).unwrap();
+        assert!(manager.is_synced());
+        assert_eq!(manager.book().last_update_id, 111);
+        assert_eq!(manager.book().bids.len(), 2);
+    }
+
+    /**
+     * Tests seeding a book from a snapshot and reconciling diffs at either update speed.
+     */
+    #[test]
+    fn test_resync_with_update_speed() {
+        // Arrange
+        let cases = [
+            (UpdateSpeed::Fast100ms, "bnbbtc@depth@100ms", 100),
+            (UpdateSpeed::Standard, "bnbbtc@depth", 1000),
+        ];
+        let buffered = [
+            diff(90, 99, ("0.0001", "1")),
+            diff(100, 104, ("0.0025", "4")),
+            diff(105, 107, ("0.0024", "0")),
+            diff(108, 112, ("0.0023", "9")),
+        ];
+
+        for (update_speed, stream_name, window_ms) in cases {
+            let spec = DiffDepthStreamSpec::new("BNBBTC").with_update_speed(update_speed);
+            let mut manager = OrderBookManager::new(spec.update_speed());
+            for event in &buffered {
+                manager.apply_diff(event).unwrap();
+            }
+            let snapshot: OrderBook = serde_json::from_str(
+                r#"{"lastUpdateId":102,"bids":[["0.0025","5"],["0.0024","10"]],"asks":[["0.0026","100"]]}"#,
+            )
+            .unwrap();
+
+            // Act
+            manager.resync(snapshot).unwrap();
+
+            // Assert
+            let book = manager.book();
+            assert_eq!(spec.stream_name(), stream_name);
+            assert_eq!(manager.update_speed(), update_speed);
+            assert_eq!(manager.buffer_window(), Duration::from_millis(window_ms));
+            assert!(book.is_valid());
+            assert_eq!(book.last_update_id, 112);
+            assert_eq!(
+                book.bids
+                    .iter()
+                    .map(|level| (level.price, level.quantity))
+                    .collect::<Vec<_>>(),
+                [(dec!(0.0025), dec!(4)), (dec!(0.0023), dec!(9))]
+            );
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use rust_decimal::Decimal;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use super::book_manager::OrderBookManager;
+use super::client::TypedSubscription;
+use super::events::DiffDepthStreamEvent;
+use super::specs::UpdateSpeed;
+use crate::Result;
+use crate::types::responses::OrderBook;
+
+/**
+ * Local order books for several symbols maintained from diff depth events.
+ *
+ * Each book is seeded from a REST snapshot and kept up to date by routing
+ * diff depth events to the book of their symbol. A book that misses diffs,
+ * through a gap in the update IDs or a lagged subscription, is marked stale
+ * and left out of `best_quotes` until it is resynced from a fresh snapshot.
+ * Cloning the registry shares the same underlying books.
+ *
+ * # Fields
+ * - `books`: Order book manager per symbol.
+ * - `update_speed`: Speed of the diff depth stream maintaining the books.
+ */
+#[derive(Debug, Clone)]
+pub struct BookRegistry {
+    books: Arc<RwLock<HashMap<String, OrderBookManager>>>,
+    update_speed: UpdateSpeed,
+}
+
+impl Default for BookRegistry {
+    fn default() -> Self {
+        Self {
+            books: Arc::default(),
+            update_speed: UpdateSpeed::Standard,
+        }
+    }
+}
+
+impl BookRegistry {
+    /**
+     * Creates an empty registry.
+     *
+     * # Returns
+     * - New BookRegistry instance.
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Sets the speed of the diff depth stream maintaining the books.
+     *
+     * # Arguments
+     * - `update_speed`: Update speed the diff depth stream was subscribed with.
+     *
+     * # Returns
+     * - `Self`: Registry with the update speed set.
+     */
+    pub fn with_update_speed(mut self, update_speed: UpdateSpeed) -> Self {
+        self.update_speed = update_speed;
+        self
+    }
+
+    /**
+     * Adds or replaces the book of a symbol.
+     *
+     * # Arguments
+     * - `symbol`: Symbol the book belongs to (case-insensitive).
+     * - `book`: Order book snapshot to maintain.
+     */
+    pub fn insert(&self, symbol: impl Into<String>, book: OrderBook) {
+        self.books
+            .write()
+            .expect("book registry lock poisoned")
+            .insert(
+                symbol.into().to_uppercase(),
+                OrderBookManager::from_snapshot(book, self.update_speed),
+            );
+    }
+
+    /**
+     * Gets a copy of the current book of a symbol.
+     *
+     * The book may be stale; check `is_synced` before relying on it.
+     *
+     * # Arguments
+     * - `symbol`: Symbol to look up (case-insensitive).
+     *
+     * # Returns
+     * - `Option<OrderBook>`: Book, or None if the symbol is not registered.
+     */
+    pub fn book(&self, symbol: &str) -> Option<OrderBook> {
+        self.books
+            .read()
+            .expect("book registry lock poisoned")
+            .get(&symbol.to_uppercase())
+            .map(|manager| manager.book().clone())
+    }
+
+    /**
+     * Checks whether the book of a symbol reflects every diff since its snapshot.
+     *
+     * # Arguments
+     * - `symbol`: Symbol to look up (case-insensitive).
+     *
+     * # Returns
+     * - `bool`: True if the symbol is registered and its book is not stale.
+     */
+    pub fn is_synced(&self, symbol: &str) -> bool {
+        self.books
+            .read()
+            .expect("book registry lock poisoned")
+            .get(&symbol.to_uppercase())
+            .is_some_and(OrderBookManager::is_synced)
+    }
+
+    /**
+     * Gets the symbols whose book is stale and needs a fresh snapshot.
+     *
+     * # Returns
+     * - `Vec<String>`: Symbols to pass to `resync`.
+     */
+    pub fn stale_symbols(&self) -> Vec<String> {
+        self.books
+            .read()
+            .expect("book registry lock poisoned")
+            .iter()
+            .filter(|(_, manager)| !manager.is_synced())
+            .map(|(symbol, _)| symbol.clone())
+            .collect()
+    }
+
+    /**
+     * Re-seeds the book of a symbol from a fresh snapshot.
+     *
+     * Diffs buffered since the book went stale are replayed on top of the
+     * snapshot. Symbols without a registered book are added.
+     *
+     * # Arguments
+     * - `symbol`: Symbol the snapshot belongs to (case-insensitive).
+     * - `snapshot`: Fresh depth snapshot.
+     *
+     * # Returns
+     * - `()`: Ok if the book is synced again.
+     */
+    pub fn resync(&self, symbol: &str, snapshot: OrderBook) -> Result<()> {
+        let mut books = self.books.write().expect("book registry lock poisoned");
+        match books.get_mut(&symbol.to_uppercase()) {
+            Some(manager) => manager.resync(snapshot),
+            None => {
+                books.insert(
+                    symbol.to_uppercase(),
+                    OrderBookManager::from_snapshot(snapshot, self.update_speed),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /**
+     * Marks every book as stale, e.g. after the subscription lagged.
+     */
+    pub fn invalidate_all(&self) {
+        self.books
+            .write()
+            .expect("book registry lock poisoned")
+            .values_mut()
+            .for_each(OrderBookManager::invalidate);
+    }
+
+    /**
+     * Applies a diff depth event to the book of its symbol.
+     *
+     * Events for symbols without a registered book are ignored, and events
+     * for stale books are buffered until their `resync`.
+     *
+     * # Arguments
+     * - `event`: Diff depth event to apply.
+     *
+     * # Returns
+     * - `bool`: True if the update was applied to a book; an error marks the book stale.
+     */
+    pub fn apply(&self, event: &DiffDepthStreamEvent) -> Result<bool> {
+        let mut books = self.books.write().expect("book registry lock poisoned");
+        match books.get_mut(&event.symbol) {
+            Some(manager) => manager.apply_diff(event),
+            None => Ok(false),
+        }
+    }
+
+    /**
+     * Gets the best bid and ask price of every synced book.
+     *
+     * Stale books and books missing either side are left out.
+     *
+     * # Returns
+     * - `HashMap<String, (Decimal, Decimal)>`: Best bid and ask price per symbol.
+     */
+    pub fn best_quotes(&self) -> HashMap<String, (Decimal, Decimal)> {
+        self.books
+            .read()
+            .expect("book registry lock poisoned")
+            .iter()
+            .filter(|(_, manager)| manager.is_synced())
+            .filter_map(|(symbol, manager)| {
+                let bid = manager.book().best_bid()?.price;
+                let ask = manager.book().best_ask()?.price;
+                Some((symbol.clone(), (bid, ask)))
+            })
+            .collect()
+    }
+
+    /**
+     * Consumes a diff depth subscription in the background.
+     *
+     * The task runs until the subscription is closed. A book with a gap is
+     * marked stale, and a lagged subscription marks every book stale, since
+     * the skipped diffs are lost. Stale books should be resynced from a fresh
+     * snapshot with `resync`; see `stale_symbols`.
+     *
+     * # Arguments
+     * - `subscription`: Diff depth stream subscription for the registered symbols.
+     *
+     * # Returns
+     * - `JoinHandle<()>`: Handle of the background task.
+     */
+    pub fn track(
+        &self,
+        mut subscription: TypedSubscription<DiffDepthStreamEvent>,
+    ) -> JoinHandle<()> {
+        let registry = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match subscription.recv().await {
+                    Ok(event) => {
+                        if let Err(e) = registry.apply(&event) {
+                            warn!(symbol = %event.symbol, error = %e, "Book went stale, resync required");
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            skipped,
+                            "Book registry lagged behind depth stream, resync required"
+                        );
+                        registry.invalidate_all();
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::streams::test_fixtures::diff_depth;
+
+    fn diff(symbol: &str, update_id: u64, bid: &str, ask: &str) -> DiffDepthStreamEvent {
+        diff_depth(symbol, update_id, update_id, &[(bid, "1")], &[(ask, "1")])
+    }
+
+    fn book(bid: &str, ask: &str) -> OrderBook {
+        serde_json::from_value(json!({
+            "lastUpdateId": 100, "bids": [[bid, "5"]], "asks": [[ask, "5"]]
+        }))
+        .unwrap()
+    }
+
+    /**
+     * Tests that diffs for two symbols update the best quotes of both books.
+     */
+    #[test]
+    fn test_best_quotes_update_per_symbol() {
+        // Arrange
+        let registry = BookRegistry::new();
+        registry.insert("btcusdt", book("100.00", "101.00"));
+        registry.insert("ETHUSDT", book("10.00", "10.50"));
+
+        // Act
+        let btc_applied = registry
+            .apply(&diff("BTCUSDT", 101, "100.50", "100.80"))
+            .unwrap();
+        let eth_applied = registry
+            .apply(&diff("ETHUSDT", 101, "10.10", "10.40"))
+            .unwrap();
+        let unknown_applied = registry
+            .apply(&diff("BNBUSDT", 101, "1.00", "1.10"))
+            .unwrap();
+        let quotes = registry.best_quotes();
+
+        // Assert
+        assert!(btc_applied);
+        assert!(eth_applied);
+        assert!(!unknown_applied);
+        assert_eq!(quotes.len(), 2);
+        assert_eq!(quotes["BTCUSDT"], (dec!(100.50), dec!(100.80)));
+        assert_eq!(quotes["ETHUSDT"], (dec!(10.10), dec!(10.40)));
+    }
+
+    /**
+     * Tests that a gap or lag leaves books out of the best quotes until they are resynced.
+     */
+    #[test]
+    fn test_stale_book_excluded_until_resync() {
+        // Arrange
+        let registry = BookRegistry::new();
+        registry.insert("BTCUSDT", book("100.00", "101.00"));
+        registry.insert("ETHUSDT", book("10.00", "10.50"));
+
+        // Act
+        let gap = registry.apply(&diff("BTCUSDT", 105, "100.50", "100.80"));
+        let stale_quotes = registry.best_quotes();
+        registry.invalidate_all();
+        let lagged_quotes = registry.best_quotes();
+        let mut stale_symbols = registry.stale_symbols();
+        stale_symbols.sort();
+        registry
+            .resync("btcusdt", book("100.20", "100.90"))
+            .unwrap();
+        let resynced_quotes = registry.best_quotes();
+
+        // Assert
+        assert!(gap.is_err());
+        assert!(!stale_quotes.contains_key("BTCUSDT"));
+        assert!(stale_quotes.contains_key("ETHUSDT"));
+        assert!(lagged_quotes.is_empty());
+        assert_eq!(stale_symbols, ["BTCUSDT", "ETHUSDT"]);
+        assert!(registry.is_synced("BTCUSDT"));
+        assert!(!registry.is_synced("ETHUSDT"));
+        assert_eq!(resynced_quotes.len(), 1);
+        assert_eq!(resynced_quotes["BTCUSDT"], (dec!(100.20), dec!(100.90)));
+    }
+}
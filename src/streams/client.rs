@@ -1,19 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use anyhow::Context;
-use tokio::sync::{broadcast, mpsc, oneshot};
-use tracing::{debug, info, instrument};
+use serde_json::Value;
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+use tracing::{debug, info, instrument, warn};
 
 use super::connection::{
     ConnectionManager, ConnectionStatus, MarketDataConnectionManager, StreamMessage,
     UserDataConnectionManager, ValueReceiver, ValueSender,
 };
-use super::specs::StreamSpec;
+use super::events::{
+    NormalizeDecimals, PartialBookDepthStreamEvent, PartialDepthMessage, UserDataEvent,
+};
+use super::specs::{StreamSpec, UserDataStreamSpec};
 use crate::Result;
 use crate::config::{StreamMode, StreamType};
 use crate::{BinanceConfig, StreamConfig};
 
 type TypedReceiver<T> = broadcast::Receiver<T>;
+type ActiveSubscriptions = HashMap<String, (ValueSender, usize)>;
 
 /**
  * Typed subscription handle for WebSocket streams.
@@ -27,23 +33,115 @@ type TypedReceiver<T> = broadcast::Receiver<T>;
  * # Fields
  * - `receiver`: Broadcast receiver for typed events.
  * - `task_handle`: Background task handle for automatic cleanup.
+ * - `buffer_size`: Capacity of the typed channel.
+ * - `paused`: Whether events are being held while processing is paused.
+ * - `pending`: Events held during a pause, delivered before newer events.
  */
 pub struct TypedSubscription<T> {
     receiver: TypedReceiver<T>,
     task_handle: tokio::task::JoinHandle<()>,
+    buffer_size: usize,
+    paused: bool,
+    pending: VecDeque<T>,
+}
+
+impl<T> TypedSubscription<T> {
+    /**
+     * Creates a subscription reading from a typed channel.
+     *
+     * # Arguments
+     * - `receiver`: Broadcast receiver for typed events.
+     * - `task_handle`: Background task feeding the channel.
+     * - `buffer_size`: Capacity of the typed channel.
+     *
+     * # Returns
+     * - New TypedSubscription instance.
+     */
+    fn new(
+        receiver: TypedReceiver<T>,
+        task_handle: tokio::task::JoinHandle<()>,
+        buffer_size: usize,
+    ) -> Self {
+        Self {
+            receiver,
+            task_handle,
+            buffer_size,
+            paused: false,
+            pending: VecDeque::new(),
+        }
+    }
 }
 
 impl<T: Clone> TypedSubscription<T> {
     /**
      * Receives the next event from the stream.
      *
+     * Events held during a pause are returned first. While the subscription
+     * is paused no event is delivered and the returned future stays pending,
+     * so callers waiting in `select!` or under a timeout can `resume` later.
+     *
      * # Returns
      * - Result containing the next event or receive error.
      */
     pub async fn recv(&mut self) -> std::result::Result<T, broadcast::error::RecvError> {
+        if self.paused {
+            return std::future::pending().await;
+        }
+        if let Some(event) = self.pending.pop_front() {
+            return Ok(event);
+        }
         self.receiver.recv().await
     }
 
+    /**
+     * Pauses processing without unsubscribing from the server.
+     *
+     * The upstream stream stays alive. No events are delivered by `recv`
+     * until `resume` is called. While paused, the most recent `buffer_size`
+     * events are held locally and older ones are dropped.
+     */
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /**
+     * Resumes processing after a pause.
+     *
+     * Held events are delivered by `recv` in the order they were received.
+     * Does nothing if the subscription is not paused.
+     *
+     * # Returns
+     * - `u64`: Number of events dropped because the buffer was full.
+     */
+    pub fn resume(&mut self) -> u64 {
+        if !std::mem::take(&mut self.paused) {
+            return 0;
+        }
+
+        let mut dropped = 0;
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => self.pending.push_back(event),
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => dropped += skipped,
+                Err(_) => break,
+            }
+        }
+        if dropped > 0 {
+            warn!(dropped, "Subscription dropped events while paused");
+        }
+        dropped
+    }
+
+    /**
+     * Checks whether the subscription is paused.
+     *
+     * # Returns
+     * - `true` between `pause` and `resume`.
+     */
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
     /**
      * Gets a mutable reference to the underlying receiver.
      *
@@ -55,6 +153,141 @@ impl<T: Clone> TypedSubscription<T> {
     }
 }
 
+impl<T: Clone + Send + 'static> TypedSubscription<T> {
+    /**
+     * Converts the subscription into a plain broadcast receiver.
+     *
+     * The subscription is moved into a forwarding task that lives until the
+     * returned receiver is dropped or the stream closes. A paused
+     * subscription is resumed first.
+     *
+     * # Returns
+     * - Broadcast receiver yielding the subscription's events.
+     */
+    pub fn into_receiver(mut self) -> TypedReceiver<T> {
+        self.resume();
+        let (forward_sender, forward_receiver) = broadcast::channel(self.buffer_size);
+
+        tokio::spawn(async move {
+            loop {
+                match self.recv().await {
+                    Ok(event) => {
+                        if forward_sender.send(event).is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Receiver forwarding lagged behind stream");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        forward_receiver
+    }
+}
+
+impl TypedSubscription<UserDataEvent> {
+    /**
+     * Narrows the subscription to events for a single symbol.
+     *
+     * Order and order list events for other symbols are dropped. Account-wide
+     * events such as balance updates carry no symbol and are passed through.
+     *
+     * # Arguments
+     * - `symbol`: Symbol to keep events for (case-insensitive).
+     *
+     * # Returns
+     * - TypedSubscription receiving only events for the symbol.
+     */
+    pub fn only_symbol(mut self, symbol: impl Into<String>) -> Self {
+        let symbol = symbol.into().to_uppercase();
+        let buffer_size = self.buffer_size;
+        let (filtered_sender, filtered_receiver) = broadcast::channel(buffer_size);
+
+        let task_handle = tokio::spawn(async move {
+            loop {
+                match self.recv().await {
+                    Ok(event) => {
+                        if event.symbol().is_some_and(|s| s != symbol) {
+                            continue;
+                        }
+                        if filtered_sender.send(event).is_err() {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Symbol filter lagged behind user data stream");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        TypedSubscription::new(filtered_receiver, task_handle, buffer_size)
+    }
+}
+
+impl TypedSubscription<PartialBookDepthStreamEvent> {
+    /**
+     * Tracks `lastUpdateId` continuity across partial book depth events.
+     *
+     * Every event is forwarded as `PartialDepthMessage::Depth`. When an
+     * event's update id is lower than the previous one, a
+     * `PartialDepthMessage::DepthReset` marker is sent ahead of it.
+     *
+     * # Returns
+     * - TypedSubscription receiving depth events and reset markers.
+     */
+    pub fn with_reset_detection(mut self) -> TypedSubscription<PartialDepthMessage> {
+        let buffer_size = self.buffer_size;
+        let (message_sender, message_receiver) = broadcast::channel(buffer_size);
+
+        let task_handle = tokio::spawn(async move {
+            let mut previous_update_id = None;
+            loop {
+                match self.recv().await {
+                    Ok(event) => {
+                        if let Some(previous_update_id) = previous_update_id
+                            && event.last_update_id < previous_update_id
+                        {
+                            warn!(
+                                previous_update_id,
+                                last_update_id = event.last_update_id,
+                                "Partial depth update id decreased"
+                            );
+                            let reset = PartialDepthMessage::DepthReset {
+                                previous_update_id,
+                                last_update_id: event.last_update_id,
+                            };
+                            if message_sender.send(reset).is_err() {
+                                break;
+                            }
+                        }
+                        previous_update_id = Some(event.last_update_id);
+                        if message_sender
+                            .send(PartialDepthMessage::Depth(event))
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            skipped,
+                            "Reset detection lagged behind partial depth stream"
+                        );
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+
+        TypedSubscription::new(message_receiver, task_handle, buffer_size)
+    }
+}
+
 impl<T> Drop for TypedSubscription<T> {
     fn drop(&mut self) {
         self.task_handle.abort();
@@ -115,10 +348,12 @@ pub type UserDataStreamClient = StreamClient<UserDataConnectionManager>;
  * # Fields
  * - `connection_manager`: Manages WebSocket connection lifecycle.
  * - `mode`: Client operation mode with associated resources.
+ * - `active_subscriptions`: Raw senders and subscriber counts of dynamic streams, keyed by stream name.
  */
 pub struct StreamClient<M: ConnectionManager> {
     connection_manager: M,
     mode: ClientMode,
+    active_subscriptions: Arc<Mutex<ActiveSubscriptions>>,
 }
 
 impl<M: ConnectionManager> StreamClient<M> {
@@ -136,8 +371,72 @@ impl<M: ConnectionManager> StreamClient<M> {
         Self {
             connection_manager,
             mode,
+            active_subscriptions: Arc::new(Mutex::new(HashMap::new())),
         }
     }
+
+    /**
+     * Locks the raw senders and subscriber counts of dynamic streams.
+     *
+     * # Returns
+     * - Guard over the active subscriptions.
+     */
+    fn subscriptions(&self) -> MutexGuard<'_, ActiveSubscriptions> {
+        self.active_subscriptions
+            .lock()
+            .expect("subscription registry lock poisoned")
+    }
+
+    /**
+     * Watches a subscription queued while the connection is pending.
+     *
+     * When the server rejects the subscription, the new streams are removed
+     * from the active subscriptions and the shared streams lose the
+     * subscribers it added. The channels of the new streams then close, so
+     * every subscriber sees the stream end instead of waiting for events that
+     * never arrive.
+     *
+     * # Arguments
+     * - `new_streams`: Names and raw senders of the newly requested streams.
+     * - `shared_streams`: Names and raw senders of the already active streams it shares.
+     * - `response`: Receiver for the subscription result.
+     */
+    fn watch_queued_subscription(
+        &self,
+        new_streams: Vec<(String, ValueSender)>,
+        shared_streams: Vec<(String, ValueSender)>,
+        response: oneshot::Receiver<Result<()>>,
+    ) {
+        let active_subscriptions = Arc::clone(&self.active_subscriptions);
+        let downgrade = |streams: Vec<(String, ValueSender)>| -> Vec<_> {
+            streams
+                .into_iter()
+                .map(|(stream_name, sender)| (stream_name, sender.downgrade()))
+                .collect()
+        };
+        let new_streams = downgrade(new_streams);
+        let shared_streams = downgrade(shared_streams);
+        tokio::spawn(async move {
+            let Ok(Err(e)) = response.await else {
+                return;
+            };
+            warn!(error = %e, "Queued subscription failed, closing its streams");
+            let upgrade = |streams: Vec<(String, broadcast::WeakSender<Value>)>| -> Vec<_> {
+                streams
+                    .into_iter()
+                    .filter_map(|(stream_name, weak_sender)| {
+                        weak_sender.upgrade().map(|sender| (stream_name, sender))
+                    })
+                    .collect()
+            };
+            let new_streams = upgrade(new_streams);
+            let shared_streams = upgrade(shared_streams);
+            let mut active_subscriptions = active_subscriptions
+                .lock()
+                .expect("subscription registry lock poisoned");
+            rollback_subscriptions(&mut active_subscriptions, &new_streams, &shared_streams);
+        });
+    }
 }
 
 impl StreamClient<MarketDataConnectionManager> {
@@ -206,6 +505,10 @@ impl<M: ConnectionManager> StreamClient<M> {
     /**
      * Subscribes to a WebSocket stream.
      *
+     * May be called before the connection is established, in which case the
+     * subscription is queued and sent once connected. If the server then
+     * rejects it, the subscription's channel is closed.
+     *
      * # Arguments
      * - `spec`: Stream specification defining the subscription.
      *
@@ -226,6 +529,9 @@ impl<M: ConnectionManager> StreamClient<M> {
         let stream_name = spec.stream_name();
 
         let raw_receiver = match &self.mode {
+            ClientMode::Dynamic { .. } if self.subscriptions().contains_key(&stream_name) => {
+                self.share_subscription(&stream_name)?
+            }
             ClientMode::Dynamic { sender } => {
                 let (tx, rx) = broadcast::channel(buffer_size);
                 let (response_tx, response_rx) = oneshot::channel();
@@ -233,14 +539,30 @@ impl<M: ConnectionManager> StreamClient<M> {
                 sender
                     .send(StreamMessage::Subscribe {
                         stream_name: stream_name.clone(),
-                        sender: tx,
+                        sender: tx.clone(),
                         response: response_tx,
                     })
                     .context("Failed to send subscribe message")?;
+                self.subscriptions()
+                    .insert(stream_name.clone(), (tx.clone(), 1));
 
-                response_rx
-                    .await
-                    .context("Failed to receive subscribe response")??;
+                if self.connection_manager.is_connected() {
+                    let response = response_rx
+                        .await
+                        .context("Failed to receive subscribe response")
+                        .and_then(|result| result);
+                    if response.is_err() {
+                        self.subscriptions().remove(&stream_name);
+                    }
+                    response?;
+                } else {
+                    debug!(stream = %stream_name, "Connection pending, subscription queued");
+                    self.watch_queued_subscription(
+                        vec![(stream_name.clone(), tx)],
+                        Vec::new(),
+                        response_rx,
+                    );
+                }
 
                 rx
             }
@@ -264,9 +586,169 @@ impl<M: ConnectionManager> StreamClient<M> {
         Ok(subscription)
     }
 
+    /**
+     * Subscribes to several WebSocket streams with a single SUBSCRIBE frame.
+     *
+     * Every specification is validated before anything is sent, so either all
+     * streams are requested or none are. May be called before the connection
+     * is established, in which case the batch is queued and sent once connected.
+     * If sending fails or the server rejects the batch, the subscriber counts
+     * of shared streams are restored and the new streams are removed, closing
+     * their channels.
+     *
+     * # Arguments
+     * - `specs`: Stream specifications defining the subscriptions.
+     *
+     * # Returns
+     * - TypedSubscriptions for receiving stream events, in the order of `specs`.
+     */
+    #[instrument(skip(self, specs), fields(streams = specs.len()))]
+    pub(crate) async fn subscribe_all<S: StreamSpec>(
+        &mut self,
+        specs: &[S],
+    ) -> Result<Vec<TypedSubscription<S::Event>>>
+    where
+        S::Event: serde::de::DeserializeOwned + Clone + Send + 'static,
+    {
+        let start = std::time::Instant::now();
+        for spec in specs {
+            spec.validate()?;
+        }
+
+        let ClientMode::Dynamic { sender } = &self.mode else {
+            return Err(anyhow::anyhow!(
+                "Subscribe all not supported in static mode"
+            ));
+        };
+
+        let error_on_duplicate = self
+            .connection_manager
+            .stream_config()
+            .error_on_duplicate_subscribe;
+        let mut seen = std::collections::HashSet::new();
+        for spec in specs {
+            let stream_name = spec.stream_name();
+            let duplicate =
+                self.subscriptions().contains_key(&stream_name) || !seen.insert(stream_name);
+            if duplicate && error_on_duplicate {
+                return Err(anyhow::anyhow!(
+                    "Already subscribed to stream '{}'",
+                    spec.stream_name()
+                ));
+            }
+        }
+
+        let mut new_streams = Vec::new();
+        let mut shared_streams = Vec::new();
+        let mut receivers = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let stream_name = spec.stream_name();
+            let buffer_size = spec.buffer_size(self.connection_manager.stream_config());
+            let mut active_subscriptions = self.subscriptions();
+            let rx = match active_subscriptions.get_mut(&stream_name) {
+                Some((tx, count)) => {
+                    *count += 1;
+                    shared_streams.push((stream_name, tx.clone()));
+                    tx.subscribe()
+                }
+                None => {
+                    let (tx, rx) = broadcast::channel(buffer_size);
+                    new_streams.push((stream_name.clone(), tx.clone()));
+                    active_subscriptions.insert(stream_name, (tx, 1));
+                    rx
+                }
+            };
+            drop(active_subscriptions);
+            receivers.push((rx, buffer_size));
+        }
+
+        let (response_tx, response_rx) = oneshot::channel();
+        if new_streams.is_empty() {
+            let _ = response_tx.send(Ok(()));
+        } else {
+            let sent = sender
+                .send(StreamMessage::SubscribeAll {
+                    subscriptions: new_streams.clone(),
+                    response: response_tx,
+                })
+                .context("Failed to send subscribe message");
+            if sent.is_err() {
+                rollback_subscriptions(&mut self.subscriptions(), &new_streams, &shared_streams);
+            }
+            sent?;
+        }
+
+        if self.connection_manager.is_connected() {
+            let response = response_rx
+                .await
+                .context("Failed to receive subscribe response")
+                .and_then(|result| result);
+            if response.is_err() {
+                rollback_subscriptions(&mut self.subscriptions(), &new_streams, &shared_streams);
+            }
+            response?;
+        } else {
+            debug!(
+                streams = specs.len(),
+                "Connection pending, batch subscription queued"
+            );
+            self.watch_queued_subscription(new_streams, shared_streams, response_rx);
+        }
+
+        let subscriptions = receivers
+            .into_iter()
+            .map(|(rx, buffer_size)| self.create_typed_subscription::<S>(rx, buffer_size))
+            .collect();
+
+        info!(
+            streams = specs.len(),
+            duration_us = start.elapsed().as_micros(),
+            "Batch stream subscription completed"
+        );
+
+        Ok(subscriptions)
+    }
+
+    /**
+     * Shares the raw channel of an already active dynamic stream.
+     *
+     * Fails instead when the configuration opts into erroring on duplicate
+     * subscriptions.
+     *
+     * # Arguments
+     * - `stream_name`: Name of the active stream.
+     *
+     * # Returns
+     * - Raw receiver on the existing stream channel.
+     */
+    fn share_subscription(&mut self, stream_name: &str) -> Result<ValueReceiver> {
+        if self
+            .connection_manager
+            .stream_config()
+            .error_on_duplicate_subscribe
+        {
+            return Err(anyhow::anyhow!(
+                "Already subscribed to stream '{}'",
+                stream_name
+            ));
+        }
+
+        let mut active_subscriptions = self.subscriptions();
+        let (sender, count) = active_subscriptions
+            .get_mut(stream_name)
+            .ok_or_else(|| anyhow::anyhow!("Stream '{}' is not subscribed", stream_name))?;
+        *count += 1;
+        debug!(stream = %stream_name, subscribers = *count, "Sharing existing subscription");
+        Ok(sender.subscribe())
+    }
+
     /**
      * Unsubscribes from a WebSocket stream.
      *
+     * When the stream was subscribed more than once, only the subscriber count
+     * is decreased and the UNSUBSCRIBE request is sent once the last
+     * subscriber is gone.
+     *
      * # Arguments
      * - `spec`: Stream specification to unsubscribe from.
      *
@@ -274,15 +756,34 @@ impl<M: ConnectionManager> StreamClient<M> {
      * - `()` on successful unsubscription.
      */
     #[instrument(skip(self, spec), fields(stream_name = spec.stream_name()))]
-    pub(crate) async fn unsubscribe<S: StreamSpec>(&self, spec: S) -> Result<()> {
+    pub(crate) async fn unsubscribe<S: StreamSpec>(&mut self, spec: S) -> Result<()> {
         let _start = std::time::Instant::now();
         match &self.mode {
             ClientMode::Dynamic { sender } => {
+                let stream_name = spec.stream_name();
+                let remaining = {
+                    let mut active_subscriptions = self.subscriptions();
+                    let remaining = active_subscriptions
+                        .get_mut(&stream_name)
+                        .map(|(_, count)| {
+                            *count -= 1;
+                            *count
+                        });
+                    if remaining == Some(0) {
+                        active_subscriptions.remove(&stream_name);
+                    }
+                    remaining
+                };
+                if let Some(subscribers) = remaining.filter(|count| *count > 0) {
+                    debug!(stream = %stream_name, subscribers, "Stream still shared");
+                    return Ok(());
+                }
+
                 let (response_tx, response_rx) = oneshot::channel();
 
                 sender
                     .send(StreamMessage::Unsubscribe {
-                        stream_names: vec![spec.stream_name()],
+                        stream_names: vec![stream_name],
                         response: response_tx,
                     })
                     .context("Failed to send unsubscribe message")?;
@@ -351,6 +852,7 @@ impl<M: ConnectionManager> StreamClient<M> {
         S::Event: serde::de::DeserializeOwned + Clone + Send + 'static,
     {
         let (typed_sender, typed_receiver) = broadcast::channel(buffer_size);
+        let normalize_decimals = self.stream_config().normalize_decimals;
 
         let task_handle = tokio::spawn(async move {
             let mut message_count = 0u64;
@@ -360,7 +862,7 @@ impl<M: ConnectionManager> StreamClient<M> {
             while let Ok(value) = raw_receiver.recv().await {
                 message_count += 1;
 
-                match serde_json::from_value::<S::Event>(value.clone()) {
+                match parse_event::<S::Event>(value, normalize_decimals) {
                     Ok(typed_event) => {
                         if typed_sender.send(typed_event).is_err() {
                             debug!(
@@ -377,7 +879,7 @@ impl<M: ConnectionManager> StreamClient<M> {
                     }
                 }
 
-                if message_count % 1000 == 0 {
+                if message_count.is_multiple_of(1000) {
                     let rate = message_count as f64 / last_stats_time.elapsed().as_secs_f64();
                     debug!(
                         messages_processed = message_count,
@@ -389,16 +891,17 @@ impl<M: ConnectionManager> StreamClient<M> {
             }
         });
 
-        TypedSubscription {
-            receiver: typed_receiver,
-            task_handle,
-        }
+        TypedSubscription::new(typed_receiver, task_handle, buffer_size)
     }
 
     pub(crate) fn connection_status(&self) -> ConnectionStatus {
         self.connection_manager.connection_status()
     }
 
+    pub(crate) fn connection_status_receiver(&self) -> watch::Receiver<ConnectionStatus> {
+        self.connection_manager.connection_status_receiver()
+    }
+
     pub(crate) fn is_connected(&self) -> bool {
         self.connection_manager.is_connected()
     }
@@ -498,6 +1001,10 @@ impl BinanceSpotStreamClient {
     /**
      * Subscribes to a WebSocket stream.
      *
+     * May be called before the connection is established, in which case the
+     * subscription is queued and sent once connected. If the server then
+     * rejects it, the subscription's channel is closed.
+     *
      * # Arguments
      * - `spec`: Stream specification defining the subscription.
      *
@@ -517,6 +1024,53 @@ impl BinanceSpotStreamClient {
         }
     }
 
+    /**
+     * Subscribes to several WebSocket streams with a single SUBSCRIBE frame.
+     *
+     * Reduces startup round-trips compared to calling `subscribe` once per
+     * stream. Only market data streams can be subscribed in a batch.
+     *
+     * # Arguments
+     * - `specs`: Stream specifications defining the subscriptions.
+     *
+     * # Returns
+     * - TypedSubscriptions for receiving stream events, in the order of `specs`.
+     */
+    pub async fn subscribe_all<S: StreamSpec>(
+        &mut self,
+        specs: &[S],
+    ) -> Result<Vec<TypedSubscription<S::Event>>>
+    where
+        S::Event: serde::de::DeserializeOwned + Clone + Send + 'static,
+    {
+        match self {
+            BinanceSpotStreamClient::MarketData(client) => client.subscribe_all(specs).await,
+            BinanceSpotStreamClient::UserData(client) => client.subscribe_all(specs).await,
+        }
+    }
+
+    /**
+     * Subscribes to the user data stream as a single typed event receiver.
+     *
+     * Execution reports, account position and balance updates, list status
+     * and stream termination events are all delivered on the same channel
+     * as `UserDataEvent` variants.
+     *
+     * # Returns
+     * - Broadcast receiver for user data events.
+     */
+    pub async fn subscribe_user_data(&mut self) -> Result<broadcast::Receiver<UserDataEvent>> {
+        match self {
+            BinanceSpotStreamClient::MarketData(_) => Err(anyhow::anyhow!(
+                "User data events require a user data stream client"
+            )),
+            BinanceSpotStreamClient::UserData(client) => Ok(client
+                .subscribe(&UserDataStreamSpec::new())
+                .await?
+                .into_receiver()),
+        }
+    }
+
     /**
      * Unsubscribes from a WebSocket stream.
      *
@@ -553,6 +1107,19 @@ impl BinanceSpotStreamClient {
         }
     }
 
+    /**
+     * Gets a receiver that observes connection status changes.
+     *
+     * # Returns
+     * - Watch receiver for the connection status.
+     */
+    pub fn connection_status_receiver(&self) -> watch::Receiver<ConnectionStatus> {
+        match self {
+            BinanceSpotStreamClient::MarketData(client) => client.connection_status_receiver(),
+            BinanceSpotStreamClient::UserData(client) => client.connection_status_receiver(),
+        }
+    }
+
     pub fn is_connected(&self) -> bool {
         match self {
             BinanceSpotStreamClient::MarketData(client) => client.is_connected(),
@@ -588,3 +1155,829 @@ impl BinanceSpotStreamClient {
         }
     }
 }
+
+/**
+ * Deserializes a raw stream message into its typed event.
+ *
+ * # Arguments
+ * - `value`: Raw JSON message.
+ * - `normalize_decimals`: Whether to strip trailing zeros from the event's decimal fields.
+ *
+ * # Returns
+ * - `E`: Typed event, or a deserialization error.
+ */
+fn parse_event<E: serde::de::DeserializeOwned + NormalizeDecimals>(
+    value: Value,
+    normalize_decimals: bool,
+) -> serde_json::Result<E> {
+    let mut event: E = serde_json::from_value(value)?;
+    if normalize_decimals {
+        event.normalize_decimals();
+    }
+    Ok(event)
+}
+
+/**
+ * Undoes the changes a failed subscription made to the active subscriptions.
+ *
+ * New streams are removed and shared streams lose the subscribers the
+ * subscription added. Entries whose channel was replaced in the meantime are
+ * left untouched.
+ *
+ * # Arguments
+ * - `active_subscriptions`: Active subscriptions to roll back.
+ * - `new_streams`: Names and raw senders of the streams the subscription inserted.
+ * - `shared_streams`: Names and raw senders of the streams whose count it incremented, once per increment.
+ */
+fn rollback_subscriptions(
+    active_subscriptions: &mut ActiveSubscriptions,
+    new_streams: &[(String, ValueSender)],
+    shared_streams: &[(String, ValueSender)],
+) {
+    for (stream_name, sender) in new_streams {
+        let is_same_channel = active_subscriptions
+            .get(stream_name)
+            .is_some_and(|(active, _)| active.same_channel(sender));
+        if is_same_channel {
+            active_subscriptions.remove(stream_name);
+        }
+    }
+    for (stream_name, sender) in shared_streams {
+        if let Some((active, count)) = active_subscriptions.get_mut(stream_name)
+            && active.same_channel(sender)
+        {
+            *count = count.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+    use crate::ShutdownToken;
+    use crate::clients::tests::helpers::MOCK_ED25519_PEM;
+    use crate::streams::events::TradeStreamEvent;
+    use crate::streams::specs::TradeStreamSpec;
+    use crate::streams::test_fixtures;
+
+    /**
+     * Builds an execution report event for the given symbol.
+     */
+    fn execution_report(symbol: &str, order_id: u64) -> UserDataEvent {
+        serde_json::from_value(test_fixtures::execution_report(serde_json::json!({
+            "s": symbol, "c": "filtered", "i": order_id, "I": order_id
+        })))
+        .unwrap()
+    }
+
+    /**
+     * Tests that a symbol-filtered subscription only yields events for that symbol.
+     */
+    #[tokio::test]
+    async fn test_only_symbol_filters_user_data_events() {
+        // Arrange
+        let (sender, receiver) = broadcast::channel(16);
+        let subscription = TypedSubscription::new(receiver, tokio::spawn(async {}), 16);
+        let mut filtered = subscription.only_symbol("bnbbtc");
+
+        // Act
+        sender.send(execution_report("ETHBTC", 1)).unwrap();
+        sender.send(execution_report("BNBBTC", 2)).unwrap();
+        sender.send(execution_report("ETHBTC", 3)).unwrap();
+        sender.send(execution_report("BNBBTC", 4)).unwrap();
+        drop(sender);
+
+        // Assert
+        let mut order_ids = Vec::new();
+        while let Ok(event) = filtered.recv().await {
+            let UserDataEvent::ExecutionReport(report) = event else {
+                panic!("Expected execution report");
+            };
+            assert_eq!(report.symbol, "BNBBTC");
+            order_ids.push(report.order_id);
+        }
+        assert_eq!(order_ids, [2, 4]);
+    }
+
+    /**
+     * Builds a partial book depth event with the given update id.
+     */
+    fn partial_depth(last_update_id: u64) -> PartialBookDepthStreamEvent {
+        serde_json::from_value(serde_json::json!({
+            "lastUpdateId": last_update_id,
+            "bids": [["0.0024", "10"]],
+            "asks": [["0.0026", "100"]]
+        }))
+        .unwrap()
+    }
+
+    /**
+     * Tests that a decreasing update id emits a reset marker ahead of the event.
+     */
+    #[tokio::test]
+    async fn test_with_reset_detection_marks_decreasing_update_id() {
+        // Arrange
+        let (sender, receiver) = broadcast::channel(16);
+        let subscription = TypedSubscription::new(receiver, tokio::spawn(async {}), 16);
+        let mut tracked = subscription.with_reset_detection();
+
+        // Act
+        for last_update_id in [100, 105, 40, 41] {
+            sender.send(partial_depth(last_update_id)).unwrap();
+        }
+        drop(sender);
+
+        // Assert
+        let mut messages = Vec::new();
+        while let Ok(message) = tracked.recv().await {
+            messages.push(message);
+        }
+        assert_eq!(
+            messages,
+            [
+                PartialDepthMessage::Depth(partial_depth(100)),
+                PartialDepthMessage::Depth(partial_depth(105)),
+                PartialDepthMessage::DepthReset {
+                    previous_update_id: 105,
+                    last_update_id: 40,
+                },
+                PartialDepthMessage::Depth(partial_depth(40)),
+                PartialDepthMessage::Depth(partial_depth(41)),
+            ]
+        );
+    }
+
+    /**
+     * Tests that a paused subscription keeps the newest events and drops the oldest.
+     */
+    #[tokio::test]
+    async fn test_pause_buffers_newest_events_until_resume() {
+        // Arrange
+        let (sender, receiver) = broadcast::channel(4);
+        let mut subscription = TypedSubscription::new(receiver, tokio::spawn(async {}), 4);
+        sender.send(1).unwrap();
+        assert_eq!(subscription.recv().await.unwrap(), 1);
+
+        // Act
+        subscription.pause();
+        for event in 2..=7 {
+            sender.send(event).unwrap();
+        }
+        let paused = subscription.is_paused();
+        let dropped = subscription.resume();
+        sender.send(8).unwrap();
+        drop(sender);
+
+        // Assert
+        assert!(paused);
+        assert!(!subscription.is_paused());
+        assert_eq!(dropped, 2);
+        let mut events = Vec::new();
+        while let Ok(event) = subscription.recv().await {
+            events.push(event);
+        }
+        assert_eq!(events, [4, 5, 6, 7, 8]);
+    }
+
+    /**
+     * Tests that no events are delivered between pause and resume.
+     */
+    #[tokio::test]
+    async fn test_recv_waits_while_paused() {
+        // Arrange
+        let (sender, receiver) = broadcast::channel(4);
+        let mut subscription = TypedSubscription::new(receiver, tokio::spawn(async {}), 4);
+        subscription.pause();
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+
+        // Act
+        let while_paused =
+            tokio::time::timeout(std::time::Duration::from_millis(50), subscription.recv()).await;
+        subscription.resume();
+        let after_resume = subscription.recv().await;
+
+        // Assert
+        assert!(while_paused.is_err());
+        assert_eq!(after_resume.unwrap(), 1);
+        assert_eq!(subscription.recv().await.unwrap(), 2);
+    }
+
+    /**
+     * Tests that decimal strings lose their trailing zeros only when normalization is enabled.
+     */
+    #[test]
+    fn test_parse_event_normalizes_decimals() {
+        // Arrange
+        let frame = serde_json::json!({
+            "e": "trade", "E": 1672515782136u64, "s": "BTCUSDT", "t": 12345,
+            "p": "1.10000000", "q": "100.00000000", "T": 1672515782136u64,
+            "m": true, "M": true
+        });
+
+        // Act
+        let normalized: TradeStreamEvent = parse_event(frame.clone(), true).unwrap();
+        let preserved: TradeStreamEvent = parse_event(frame, false).unwrap();
+
+        // Assert
+        assert_eq!(normalized.trade.price.to_string(), "1.1");
+        assert_eq!(normalized.trade.quantity.to_string(), "100");
+        assert_eq!(normalized.symbol, "BTCUSDT");
+        assert_eq!(preserved.trade.price.to_string(), "1.10000000");
+        assert_eq!(preserved.trade.quantity.to_string(), "100.00000000");
+    }
+
+    /**
+     * Tests that normalization leaves a client order ID that looks like a decimal untouched.
+     */
+    #[test]
+    fn test_parse_event_preserves_dotted_client_order_id() {
+        // Arrange
+        let mut frame = serde_json::to_value(execution_report("BNBBTC", 1)).unwrap();
+        frame["c"] = serde_json::json!("12.50");
+        frame["C"] = serde_json::json!("3.10");
+
+        // Act
+        let event: UserDataEvent = parse_event(frame, true).unwrap();
+
+        // Assert
+        let UserDataEvent::ExecutionReport(report) = event else {
+            panic!("Expected execution report");
+        };
+        assert_eq!(report.client_order_id, "12.50");
+        assert_eq!(report.original_client_order_id, "3.10");
+        assert_eq!(report.price.to_string(), "0.001");
+    }
+
+    /**
+     * Spawns a WebSocket server that acknowledges one SUBSCRIBE and then pushes a trade event.
+     */
+    async fn spawn_mock_stream_server() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                if request["method"] != "SUBSCRIBE" {
+                    continue;
+                }
+                let stream = request["params"][0].as_str().unwrap().to_string();
+                let ack = serde_json::json!({ "result": null, "id": request["id"] });
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+
+                let event = serde_json::json!({
+                    "stream": stream,
+                    "data": {
+                        "e": "trade", "E": 1672515782136u64, "s": "BTCUSDT", "t": 12345,
+                        "p": "0.00100000", "q": "100.50000000", "T": 1672515782136u64,
+                        "m": true, "M": true
+                    }
+                });
+                ws.send(Message::Text(event.to_string())).await.unwrap();
+            }
+        });
+
+        format!("ws://{}", addr)
+    }
+
+    /**
+     * Tests that subscribing before the connection is established queues the subscription.
+     */
+    #[tokio::test]
+    async fn test_subscribe_before_connection() {
+        // Arrange
+        let url = spawn_mock_stream_server().await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        let mut client = StreamClient::new_market_data(config).unwrap();
+
+        // Act
+        assert!(!client.is_connected());
+        let mut subscription = client
+            .subscribe(&TradeStreamSpec::new("BTCUSDT"))
+            .await
+            .unwrap();
+        client.wait_for_connection().await.unwrap();
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), subscription.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(event.symbol, "BTCUSDT");
+        assert_eq!(event.trade.id, 12345);
+    }
+
+    /**
+     * Tests that a queued subscription rejected by the server closes its
+     * channel and frees the stream for a new subscription.
+     */
+    #[tokio::test]
+    async fn test_rejected_queued_subscription_closes_channel() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let mut rejected = false;
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let response = if rejected {
+                    serde_json::json!({ "result": null, "id": request["id"] })
+                } else {
+                    rejected = true;
+                    serde_json::json!({
+                        "error": { "code": 2, "msg": "Invalid request" },
+                        "id": request["id"]
+                    })
+                };
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+            }
+        });
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .with_error_on_duplicate_subscribe(true)
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        let mut client = StreamClient::new_market_data(config).unwrap();
+        let spec = TradeStreamSpec::new("BTCUSDT");
+        let mut subscription = client.subscribe(&spec).await.unwrap();
+        client.wait_for_connection().await.unwrap();
+
+        // Act
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(5), subscription.recv())
+            .await
+            .unwrap();
+        let resubscribed = client.subscribe(&spec).await;
+
+        // Assert
+        assert!(matches!(closed, Err(broadcast::error::RecvError::Closed)));
+        assert!(resubscribed.is_ok());
+    }
+
+    /**
+     * Tests that triggering a shared shutdown token closes every stream client
+     * built with it.
+     */
+    #[tokio::test]
+    async fn test_shutdown_token_closes_all_clients() {
+        // Arrange
+        let token = ShutdownToken::new();
+        let mut clients = Vec::new();
+        for _ in 0..2 {
+            let url = spawn_mock_stream_server().await;
+            let mut config = BinanceConfig::<StreamConfig>::builder()
+                .with_shutdown_token(token.clone())
+                .with_market_data()
+                .with_dynamic_streams()
+                .build()
+                .unwrap();
+            config.stream_config_mut().market_data_url = url;
+            let mut client = StreamClient::new_market_data(config).unwrap();
+            client.wait_for_connection().await.unwrap();
+            clients.push(client);
+        }
+
+        // Act
+        token.trigger();
+
+        // Assert
+        for client in &clients {
+            let mut status = client.connection_status_receiver();
+            tokio::time::timeout(
+                std::time::Duration::from_secs(5),
+                status.wait_for(|status| *status == ConnectionStatus::Disconnected),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        }
+    }
+
+    /**
+     * Spawns a WebSocket server that acknowledges SUBSCRIBE frames, pushing a trade
+     * event for every requested stream, and reports each frame's stream names.
+     */
+    async fn spawn_mock_batch_stream_server() -> (String, mpsc::UnboundedReceiver<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (frames_tx, frames_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                if request["method"] != "SUBSCRIBE" {
+                    continue;
+                }
+                let streams: Vec<String> =
+                    serde_json::from_value(request["params"].clone()).unwrap();
+                let ack = serde_json::json!({ "result": null, "id": request["id"] });
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+
+                for (index, stream) in streams.iter().enumerate() {
+                    let symbol = stream.split('@').next().unwrap().to_uppercase();
+                    let event = serde_json::json!({
+                        "stream": stream,
+                        "data": {
+                            "e": "trade", "E": 1672515782136u64, "s": symbol, "t": index,
+                            "p": "0.00100000", "q": "100.50000000", "T": 1672515782136u64,
+                            "m": true, "M": true
+                        }
+                    });
+                    ws.send(Message::Text(event.to_string())).await.unwrap();
+                }
+                frames_tx.send(streams).unwrap();
+            }
+        });
+
+        (format!("ws://{}", addr), frames_rx)
+    }
+
+    /**
+     * Tests that a batch subscription sends one SUBSCRIBE frame and routes every stream.
+     */
+    #[tokio::test]
+    async fn test_subscribe_all_sends_single_frame() {
+        // Arrange
+        let (url, mut frames) = spawn_mock_batch_stream_server().await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        let mut client = StreamClient::new_market_data(config).unwrap();
+        client.wait_for_connection().await.unwrap();
+        let symbols = ["BTCUSDT", "ETHUSDT", "BNBUSDT", "SOLUSDT", "XRPUSDT"];
+        let specs = symbols.map(TradeStreamSpec::new);
+
+        // Act
+        let subscriptions = client.subscribe_all(&specs).await.unwrap();
+        let mut received = Vec::new();
+        for mut subscription in subscriptions {
+            let event =
+                tokio::time::timeout(std::time::Duration::from_secs(5), subscription.recv())
+                    .await
+                    .unwrap()
+                    .unwrap();
+            received.push(event.symbol);
+        }
+
+        // Assert
+        assert_eq!(received, symbols);
+        assert_eq!(frames.recv().await.unwrap().len(), 5);
+        assert!(frames.try_recv().is_err());
+    }
+
+    /**
+     * Tests that a duplicate subscription shares the existing stream without
+     * sending another SUBSCRIBE frame.
+     */
+    #[tokio::test]
+    async fn test_duplicate_subscribe_shares_stream() {
+        // Arrange
+        let (url, mut frames) = spawn_mock_batch_stream_server().await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        let mut client = StreamClient::new_market_data(config).unwrap();
+        let spec = TradeStreamSpec::new("BTCUSDT");
+
+        // Act
+        let mut first = client.subscribe(&spec).await.unwrap();
+        let mut second = client.subscribe(&spec).await.unwrap();
+        client.wait_for_connection().await.unwrap();
+        let timeout = std::time::Duration::from_secs(5);
+        let first_event = tokio::time::timeout(timeout, first.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let second_event = tokio::time::timeout(timeout, second.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(first_event.symbol, "BTCUSDT");
+        assert_eq!(second_event.symbol, "BTCUSDT");
+        assert_eq!(frames.recv().await.unwrap(), vec!["btcusdt@trade"]);
+        assert!(frames.try_recv().is_err());
+    }
+
+    /**
+     * Spawns a WebSocket server that acknowledges the first `accepted` SUBSCRIBE
+     * frames, rejects every later one and acknowledges all other requests.
+     */
+    async fn spawn_mock_rejecting_stream_server(accepted: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let mut subscribes = 0;
+
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                if request["method"] == "SUBSCRIBE" {
+                    subscribes += 1;
+                }
+                let response = if request["method"] == "SUBSCRIBE" && subscribes > accepted {
+                    serde_json::json!({
+                        "error": { "code": 2, "msg": "Invalid request" },
+                        "id": request["id"]
+                    })
+                } else {
+                    serde_json::json!({ "result": null, "id": request["id"] })
+                };
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+            }
+        });
+
+        format!("ws://{}", addr)
+    }
+
+    /**
+     * Creates a dynamic market data client connecting to the given URL.
+     */
+    fn dynamic_client(url: String) -> StreamClient<MarketDataConnectionManager> {
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        StreamClient::new_market_data(config).unwrap()
+    }
+
+    /**
+     * Gets the subscriber count of an active stream.
+     */
+    fn subscriber_count(
+        client: &StreamClient<MarketDataConnectionManager>,
+        stream_name: &str,
+    ) -> Option<usize> {
+        client
+            .subscriptions()
+            .get(stream_name)
+            .map(|(_, count)| *count)
+    }
+
+    /**
+     * Tests that a batch subscription rejected by the server restores the count
+     * of a shared stream and removes its new streams, so the shared stream can
+     * still be fully unsubscribed.
+     */
+    #[tokio::test]
+    async fn test_rejected_subscribe_all_restores_subscriber_counts() {
+        // Arrange
+        let url = spawn_mock_rejecting_stream_server(1).await;
+        let mut client = dynamic_client(url);
+        client.wait_for_connection().await.unwrap();
+        client
+            .subscribe(&TradeStreamSpec::new("BTCUSDT"))
+            .await
+            .unwrap();
+        let specs = ["BTCUSDT", "ETHUSDT"].map(TradeStreamSpec::new);
+
+        // Act
+        let result = client.subscribe_all(&specs).await;
+        let count_after_rejection = subscriber_count(&client, "btcusdt@trade");
+        client
+            .unsubscribe(TradeStreamSpec::new("BTCUSDT"))
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(count_after_rejection, Some(1));
+        assert_eq!(subscriber_count(&client, "ethusdt@trade"), None);
+        assert_eq!(subscriber_count(&client, "btcusdt@trade"), None);
+    }
+
+    /**
+     * Tests that a batch subscription whose SUBSCRIBE message cannot be sent
+     * restores the count of a shared stream and removes its new streams.
+     */
+    #[tokio::test]
+    async fn test_unsent_subscribe_all_restores_subscriber_counts() {
+        // Arrange
+        let url = spawn_mock_rejecting_stream_server(1).await;
+        let mut client = dynamic_client(url);
+        client.wait_for_connection().await.unwrap();
+        client
+            .subscribe(&TradeStreamSpec::new("BTCUSDT"))
+            .await
+            .unwrap();
+        let (sender, _) = mpsc::unbounded_channel();
+        client.mode = ClientMode::Dynamic { sender };
+        let specs = ["BTCUSDT", "ETHUSDT"].map(TradeStreamSpec::new);
+
+        // Act
+        let result = client.subscribe_all(&specs).await;
+
+        // Assert
+        assert!(
+            result
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("Failed to send subscribe message")
+        );
+        assert_eq!(subscriber_count(&client, "btcusdt@trade"), Some(1));
+        assert_eq!(subscriber_count(&client, "ethusdt@trade"), None);
+    }
+
+    /**
+     * Tests that a queued batch subscription rejected by the server restores the
+     * count of a shared stream and closes the channels of its new streams.
+     */
+    #[tokio::test]
+    async fn test_rejected_queued_subscribe_all_restores_subscriber_counts() {
+        // Arrange
+        let url = spawn_mock_rejecting_stream_server(1).await;
+        let mut client = dynamic_client(url);
+        client
+            .subscribe(&TradeStreamSpec::new("BTCUSDT"))
+            .await
+            .unwrap();
+        let specs = ["BTCUSDT", "ETHUSDT"].map(TradeStreamSpec::new);
+        let mut subscriptions = client.subscribe_all(&specs).await.unwrap();
+        let count_while_queued = subscriber_count(&client, "btcusdt@trade");
+
+        // Act
+        client.wait_for_connection().await.unwrap();
+        let closed =
+            tokio::time::timeout(std::time::Duration::from_secs(5), subscriptions[1].recv())
+                .await
+                .unwrap();
+
+        // Assert
+        assert_eq!(count_while_queued, Some(2));
+        assert!(matches!(closed, Err(broadcast::error::RecvError::Closed)));
+        assert_eq!(subscriber_count(&client, "btcusdt@trade"), Some(1));
+        assert_eq!(subscriber_count(&client, "ethusdt@trade"), None);
+    }
+
+    /**
+     * Tests that a duplicate subscription fails when the configuration opts
+     * into erroring on duplicates.
+     */
+    #[tokio::test]
+    async fn test_duplicate_subscribe_errors_when_configured() {
+        // Arrange
+        let (url, _frames) = spawn_mock_batch_stream_server().await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .with_error_on_duplicate_subscribe(true)
+            .build()
+            .unwrap();
+        config.stream_config_mut().market_data_url = url;
+        let mut client = StreamClient::new_market_data(config).unwrap();
+        let spec = TradeStreamSpec::new("BTCUSDT");
+        client.subscribe(&spec).await.unwrap();
+
+        // Act
+        let duplicate = client.subscribe(&spec).await;
+        let duplicate_batch = client.subscribe_all(std::slice::from_ref(&spec)).await;
+
+        // Assert
+        assert!(
+            duplicate
+                .err()
+                .unwrap()
+                .to_string()
+                .contains("Already subscribed")
+        );
+        assert!(duplicate_batch.is_err());
+    }
+
+    /**
+     * Spawns a WebSocket server that accepts a user data logon and subscription
+     * and then pushes the given events in the WebSocket API event format.
+     */
+    async fn spawn_mock_user_data_server(events: Vec<serde_json::Value>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let ack = serde_json::json!({ "id": request["id"], "status": 200, "result": {} });
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+
+                if request["method"] == "userDataStream.subscribe" {
+                    for event in &events {
+                        let frame = serde_json::json!({ "subscriptionId": 0, "event": event });
+                        ws.send(Message::Text(frame.to_string())).await.unwrap();
+                    }
+                }
+            }
+        });
+
+        format!("ws://{}", addr)
+    }
+
+    /**
+     * Tests that mixed user data frames arrive on one receiver as the matching variants.
+     */
+    #[tokio::test]
+    async fn test_subscribe_user_data_maps_mixed_events() {
+        // Arrange
+        let events = vec![
+            serde_json::to_value(execution_report("BNBBTC", 7)).unwrap(),
+            serde_json::json!({
+                "e": "outboundAccountPosition", "E": 1001, "u": 1001,
+                "B": [{"a": "BTC", "f": "1.00000000", "l": "0.00000000"}]
+            }),
+            serde_json::json!({
+                "e": "balanceUpdate", "E": 1002, "a": "BTC", "d": "0.50000000", "T": 1002
+            }),
+            serde_json::json!({
+                "e": "listStatus", "E": 1003, "s": "BNBBTC", "g": 2, "c": "OCO",
+                "l": "EXEC_STARTED", "L": "EXECUTING", "r": "NONE",
+                "C": "list", "T": 1003,
+                "O": [{"s": "BNBBTC", "i": 7, "c": "leg"}]
+            }),
+            serde_json::json!({ "e": "eventStreamTerminated", "E": 1004 }),
+        ];
+        let url = spawn_mock_user_data_server(events).await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_credentials("test_api_key", MOCK_ED25519_PEM)
+            .with_user_data()
+            .build()
+            .unwrap();
+        config.stream_config_mut().user_data_url = url;
+        let mut client = BinanceSpotStreamClient::new(config).unwrap();
+        client.wait_for_connection().await.unwrap();
+
+        // Act
+        let mut receiver = client.subscribe_user_data().await.unwrap();
+        let mut received = Vec::new();
+        for _ in 0..5 {
+            let event = tokio::time::timeout(std::time::Duration::from_secs(5), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            received.push(event);
+        }
+
+        // Assert
+        assert!(matches!(&received[0], UserDataEvent::ExecutionReport(r) if r.order_id == 7));
+        assert!(matches!(
+            &received[1],
+            UserDataEvent::OutboundAccountPosition(_)
+        ));
+        assert!(matches!(&received[2], UserDataEvent::BalanceUpdate(b) if b.asset == "BTC"));
+        assert!(matches!(&received[3], UserDataEvent::ListStatus(l) if l.order_list_id == 2));
+        assert!(matches!(
+            &received[4],
+            UserDataEvent::EventStreamTerminated(_)
+        ));
+    }
+
+    /**
+     * Tests that a market data client rejects user data subscriptions.
+     */
+    #[tokio::test]
+    async fn test_subscribe_user_data_requires_user_data_client() {
+        // Arrange
+        let config = BinanceConfig::<StreamConfig>::builder()
+            .with_market_data()
+            .with_dynamic_streams()
+            .build()
+            .unwrap();
+        let mut client = BinanceSpotStreamClient::new(config).unwrap();
+
+        // Act
+        let result = client.subscribe_user_data().await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
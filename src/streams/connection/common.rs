@@ -5,11 +5,12 @@ use tracing::{debug, error, info, warn};
 
 use super::{
     handler::UnifiedConnectionHandler,
-    types::{ConnectionStatus, WsStream},
+    types::{CloseReason, ConnectionStatus, WsStream},
     websocket::WebSocketConnection,
 };
 use crate::Result;
 use crate::StreamConfig;
+use crate::config::{ShutdownToken, wait_for_shutdown};
 
 /**
  * Trait for connection managers that handle different stream types.
@@ -23,6 +24,14 @@ pub trait ConnectionManager {
      */
     fn connection_status(&self) -> ConnectionStatus;
 
+    /**
+     * Gets a receiver that observes connection status changes.
+     *
+     * # Returns
+     * - Watch receiver for the connection status.
+     */
+    fn connection_status_receiver(&self) -> watch::Receiver<ConnectionStatus>;
+
     /**
      * Checks if the connection is currently established.
      *
@@ -96,29 +105,34 @@ impl ConnectionUtils {
      * - `config`: Stream configuration with connection parameters.
      * - `status_sender`: Channel for sending connection status updates.
      * - `handler`: Connection handler for processing messages.
+     * - `shutdown_token`: Optional shared signal that stops reconnecting when triggered.
      */
     pub async fn run_connection(
         url: String,
         config: StreamConfig,
         status_sender: watch::Sender<ConnectionStatus>,
-        mut handler: UnifiedConnectionHandler,
+        handler: UnifiedConnectionHandler,
+        shutdown_token: Option<ShutdownToken>,
     ) {
+        let mut handler = handler.with_shutdown_token(shutdown_token.clone());
         let mut reconnect_attempts = 0;
-        let mut failed_due_to_max_retries = false;
+        let mut failed = false;
 
         loop {
-            let stream = match Self::establish_connection(
-                &url,
-                &config,
-                &mut reconnect_attempts,
-                &status_sender,
-            )
-            .await
-            {
+            let connection_result = tokio::select! {
+                result = Self::establish_connection(
+                    &url,
+                    &config,
+                    &mut reconnect_attempts,
+                    &status_sender,
+                ) => result,
+                _ = wait_for_shutdown(shutdown_token.as_ref()) => break,
+            };
+            let stream = match connection_result {
                 Ok(stream) => stream,
                 Err(_) => {
                     if reconnect_attempts > config.max_reconnect_attempts {
-                        failed_due_to_max_retries = true;
+                        failed = true;
                         break;
                     }
                     continue;
@@ -132,14 +146,40 @@ impl ConnectionUtils {
                 continue;
             }
 
-            if handler.handle_connection(&mut ws_connection).await.is_ok() {
+            let reason = match handler.handle_connection(&mut ws_connection).await {
+                Ok(reason) => reason,
+                Err(e) => {
+                    warn!(error = %e, "WebSocket connection failed");
+                    CloseReason::Abnormal
+                }
+            };
+
+            if !reason.should_reconnect() {
                 break;
             }
 
-            tokio::time::sleep(config.initial_retry_delay).await;
+            if let Some(code) = reason.close_code()
+                && !config.reconnects_on(code)
+            {
+                error!(
+                    code,
+                    "WebSocket connection closed with a code not configured to reconnect"
+                );
+                let _ = status_sender.send(ConnectionStatus::Failed);
+                failed = true;
+                break;
+            }
+
+            warn!(reason = ?reason, "WebSocket connection closed, reconnecting");
+            let _ = status_sender.send(ConnectionStatus::Closed(reason));
+
+            tokio::select! {
+                _ = tokio::time::sleep(config.initial_retry_delay) => {}
+                _ = wait_for_shutdown(shutdown_token.as_ref()) => break,
+            }
         }
 
-        if !failed_due_to_max_retries {
+        if !failed {
             let _ = status_sender.send(ConnectionStatus::Disconnected);
         }
     }
@@ -345,3 +385,190 @@ impl ConnectionUtils {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /**
+     * Tests that a connection dropped without a close frame is reported as an
+     * abnormal closure (1006) and re-established.
+     */
+    #[tokio::test]
+    async fn test_abnormal_close_reconnects() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            drop(ws);
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(socket).await.unwrap()
+        });
+        let config = StreamConfig::builder()
+            .with_initial_retry_delay(std::time::Duration::from_millis(100))
+            .build();
+        let (status_sender, mut status_receiver) = watch::channel(ConnectionStatus::Disconnected);
+        let (_message_sender, message_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let handler = UnifiedConnectionHandler::new_dynamic(message_receiver, None);
+
+        // Act
+        let connection = tokio::spawn(ConnectionUtils::run_connection(
+            url,
+            config,
+            status_sender,
+            handler,
+            None,
+        ));
+        let mut statuses = Vec::new();
+        while statuses.last() != Some(&ConnectionStatus::Connected)
+            || !statuses.contains(&ConnectionStatus::Closed(CloseReason::Abnormal))
+        {
+            tokio::time::timeout(std::time::Duration::from_secs(5), status_receiver.changed())
+                .await
+                .unwrap()
+                .unwrap();
+            statuses.push(status_receiver.borrow_and_update().clone());
+        }
+        connection.abort();
+
+        // Assert
+        let closed_at = statuses
+            .iter()
+            .position(|status| *status == ConnectionStatus::Closed(CloseReason::Abnormal))
+            .unwrap();
+        assert!(statuses[closed_at..].contains(&ConnectionStatus::Connecting));
+        assert!(server.await.is_ok());
+    }
+
+    /**
+     * Tests that an abnormal closure reconnects when 1006 is listed as a
+     * reconnect code.
+     */
+    #[tokio::test]
+    async fn test_reconnect_on_listed_code() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            drop(ws);
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(socket).await.unwrap()
+        });
+        let config = StreamConfig::builder()
+            .with_initial_retry_delay(std::time::Duration::from_millis(100))
+            .with_reconnect_on(&[1001, 1006])
+            .build();
+        let (status_sender, mut status_receiver) = watch::channel(ConnectionStatus::Disconnected);
+        let (_message_sender, message_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let handler = UnifiedConnectionHandler::new_dynamic(message_receiver, None);
+
+        // Act
+        let connection = tokio::spawn(ConnectionUtils::run_connection(
+            url,
+            config,
+            status_sender,
+            handler,
+            None,
+        ));
+        let mut statuses = Vec::new();
+        while statuses.last() != Some(&ConnectionStatus::Connected)
+            || !statuses.contains(&ConnectionStatus::Closed(CloseReason::Abnormal))
+        {
+            tokio::time::timeout(std::time::Duration::from_secs(5), status_receiver.changed())
+                .await
+                .unwrap()
+                .unwrap();
+            statuses.push(status_receiver.borrow_and_update().clone());
+        }
+        connection.abort();
+
+        // Assert
+        assert!(!statuses.contains(&ConnectionStatus::Failed));
+        assert!(server.await.is_ok());
+    }
+
+    /**
+     * Tests that a close code missing from the reconnect list fails the
+     * connection instead of reconnecting.
+     */
+    #[tokio::test]
+    async fn test_no_reconnect_on_excluded_code() {
+        // Arrange
+        use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.close(Some(CloseFrame {
+                code: CloseCode::Policy,
+                reason: "policy violation".into(),
+            }))
+            .await
+            .unwrap();
+            tokio::time::timeout(std::time::Duration::from_millis(500), listener.accept())
+                .await
+                .is_ok()
+        });
+        let config = StreamConfig::builder()
+            .with_initial_retry_delay(std::time::Duration::from_millis(10))
+            .with_reconnect_on(&[1001, 1006])
+            .build();
+        let (status_sender, status_receiver) = watch::channel(ConnectionStatus::Disconnected);
+        let (_message_sender, message_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let handler = UnifiedConnectionHandler::new_dynamic(message_receiver, None);
+
+        // Act
+        tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            ConnectionUtils::run_connection(url, config, status_sender, handler, None),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(*status_receiver.borrow(), ConnectionStatus::Failed);
+        assert!(!server.await.unwrap());
+    }
+
+    /**
+     * Tests that close frames map to the matching close reason.
+     */
+    #[test]
+    fn test_close_reason_from_close_frame() {
+        // Arrange
+        use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+        let away = CloseFrame {
+            code: CloseCode::Away,
+            reason: "server restart".into(),
+        };
+        let abnormal = CloseFrame {
+            code: CloseCode::Abnormal,
+            reason: "".into(),
+        };
+
+        // Act
+        let away_reason = CloseReason::from_close_frame(Some(&away));
+        let abnormal_reason = CloseReason::from_close_frame(Some(&abnormal));
+
+        // Assert
+        assert_eq!(
+            away_reason,
+            CloseReason::Normal {
+                code: 1001,
+                reason: "server restart".to_string()
+            }
+        );
+        assert_eq!(abnormal_reason, CloseReason::Abnormal);
+        assert!(abnormal_reason.should_reconnect());
+        assert!(!CloseReason::Shutdown.should_reconnect());
+    }
+}
@@ -12,11 +12,12 @@ use uuid::Uuid;
 use super::{
     router::MessageRouter,
     state::ConnectionState,
-    types::{HandlerMode, StreamMessage, ValueSender},
+    types::{CloseReason, HandlerMode, StreamMessage, ValueSender},
     websocket::WebSocketConnection,
 };
 use crate::Result;
 use crate::auth::SignatureProvider;
+use crate::config::{ShutdownToken, wait_for_shutdown};
 
 /**
  * Unified connection handler for both dynamic and static modes.
@@ -30,6 +31,8 @@ use crate::auth::SignatureProvider;
  * - `message_router`: Routes incoming messages to appropriate channels.
  * - `state`: Tracks active subscriptions for reconnection.
  * - `signer`: Optional signature provider for user data stream authentication.
+ * - `reconnect_on_stream_terminated`: Whether `eventStreamTerminated` restarts the connection.
+ * - `shutdown_token`: Optional shared signal that closes the connection when triggered.
  */
 pub(super) struct UnifiedConnectionHandler {
     mode: HandlerMode,
@@ -37,6 +40,8 @@ pub(super) struct UnifiedConnectionHandler {
     message_router: MessageRouter,
     state: ConnectionState,
     signer: Option<Arc<dyn SignatureProvider>>,
+    reconnect_on_stream_terminated: bool,
+    shutdown_token: Option<ShutdownToken>,
 }
 
 impl UnifiedConnectionHandler {
@@ -60,6 +65,8 @@ impl UnifiedConnectionHandler {
             message_router: MessageRouter::new(),
             state: ConnectionState::new(),
             signer,
+            reconnect_on_stream_terminated: false,
+            shutdown_token: None,
         }
     }
 
@@ -83,9 +90,41 @@ impl UnifiedConnectionHandler {
             message_router: MessageRouter::new(),
             state: ConnectionState::new(),
             signer: None,
+            reconnect_on_stream_terminated: false,
+            shutdown_token: None,
         }
     }
 
+    /**
+     * Restarts the connection when the user data stream is terminated.
+     *
+     * Binance sends `eventStreamTerminated` once the user data subscription
+     * is no longer valid. With this enabled the handler drops the connection
+     * after forwarding the event, so the reconnect loop opens a new session
+     * and restores the subscription instead of leaving the stream silent.
+     *
+     * # Returns
+     * - Handler with stream termination reconnects enabled.
+     */
+    pub fn with_stream_termination_reconnect(mut self) -> Self {
+        self.reconnect_on_stream_terminated = true;
+        self
+    }
+
+    /**
+     * Closes the connection when the given shutdown token is triggered.
+     *
+     * # Arguments
+     * - `token`: Optional shared shutdown signal.
+     *
+     * # Returns
+     * - Handler observing the shutdown token.
+     */
+    pub fn with_shutdown_token(mut self, token: Option<ShutdownToken>) -> Self {
+        self.shutdown_token = token;
+        self
+    }
+
     /**
      * Handles post-connection setup and state restoration.
      *
@@ -106,14 +145,12 @@ impl UnifiedConnectionHandler {
             let market_data_streams: Vec<_> =
                 active_subs.iter().filter(|s| *s != "userData").collect();
 
-            if !user_data_streams.is_empty() && self.signer.is_some() {
+            if !user_data_streams.is_empty()
+                && let Some(signer) = self.signer.as_ref()
+            {
                 let logon_id = uuid::Uuid::new_v4().to_string();
                 let timestamp = Utc::now().timestamp_millis();
                 let recv_window = 5000;
-                let signer = self
-                    .signer
-                    .as_ref()
-                    .expect("Signer must be available for user data stream restoration");
                 let api_key = signer.get_api_key();
 
                 let signature_payload = format!(
@@ -187,13 +224,13 @@ impl UnifiedConnectionHandler {
      * - `ws_connection`: The WebSocket connection to handle
      *
      * # Returns
-     * - Result indicating the reason for connection termination
+     * - `CloseReason` describing why the connection ended.
      */
     #[instrument(skip(self, ws_connection))]
     pub async fn handle_connection(
         &mut self,
         ws_connection: &mut WebSocketConnection,
-    ) -> Result<()> {
+    ) -> Result<CloseReason> {
         let mut message_count = 0u64;
         let connection_start = std::time::Instant::now();
         loop {
@@ -205,16 +242,35 @@ impl UnifiedConnectionHandler {
                             messages_processed = message_count,
                             "WebSocket connection shutting down gracefully"
                         );
-                        return Ok(());
+                        return Ok(CloseReason::Shutdown);
                     }
                 }
-                Some(msg) = ws_connection.next_message() => {
-                    message_count += 1;
-                    if !self.handle_websocket_message(msg, ws_connection).await? {
+                _ = wait_for_shutdown(self.shutdown_token.as_ref()) => {
+                    self.message_router.shutdown_all_pending();
+                    let _ = ws_connection.close().await;
+                    info!(
+                        connection_duration_us = connection_start.elapsed().as_micros(),
+                        messages_processed = message_count,
+                        "WebSocket connection shutting down on shutdown signal"
+                    );
+                    return Ok(CloseReason::Shutdown);
+                }
+                msg = ws_connection.next_message() => {
+                    let Some(msg) = msg else {
                         break;
+                    };
+                    message_count += 1;
+                    if let Some(reason) = self.handle_websocket_message(msg, ws_connection).await? {
+                        info!(
+                            connection_duration_us = connection_start.elapsed().as_micros(),
+                            messages_processed = message_count,
+                            reason = ?reason,
+                            "WebSocket connection closed"
+                        );
+                        return Ok(reason);
                     }
 
-                    if message_count % 1000 == 0 {
+                    if message_count.is_multiple_of(1000) {
                         debug!(
                             messages_processed = message_count,
                             uptime_us = connection_start.elapsed().as_micros(),
@@ -231,7 +287,7 @@ impl UnifiedConnectionHandler {
             messages_processed = message_count,
             "WebSocket connection lost"
         );
-        Err(anyhow::anyhow!("Connection lost"))
+        Ok(CloseReason::Abnormal)
     }
 
     /**
@@ -329,8 +385,11 @@ impl UnifiedConnectionHandler {
                             self.message_router
                                 .add_subscription(stream_name.clone(), sender);
                             self.state.add_subscription(stream_name.clone());
-                            self.message_router
-                                .add_pending_request(request_id, response);
+                            self.message_router.add_pending_subscribe(
+                                request_id,
+                                vec![stream_name.clone()],
+                                response,
+                            );
 
                             info!(
                                 stream = %stream_name,
@@ -347,6 +406,65 @@ impl UnifiedConnectionHandler {
                 }
                 Ok(false)
             }
+            StreamMessage::SubscribeAll {
+                subscriptions,
+                response,
+            } => {
+                match &self.mode {
+                    HandlerMode::Dynamic => {
+                        if subscriptions.iter().any(|(name, _)| name == "userData") {
+                            let _ = response.send(Err(anyhow::anyhow!(
+                                "User data streams cannot be batch subscribed"
+                            )));
+                            return Ok(false);
+                        }
+
+                        let stream_names: Vec<&String> =
+                            subscriptions.iter().map(|(name, _)| name).collect();
+                        debug!(streams = ?stream_names, "Processing batch market data subscription");
+                        let request_id = Uuid::new_v4().to_string();
+                        let subscribe_msg = json!({
+                            "method": "SUBSCRIBE",
+                            "params": stream_names,
+                            "id": request_id
+                        });
+
+                        if let Err(e) = ws_connection
+                            .send_message(Message::Text(subscribe_msg.to_string()))
+                            .await
+                        {
+                            let _ = response.send(Err(e.context("Failed to send subscription")));
+                            return Err(anyhow::anyhow!("Failed to send subscription"));
+                        }
+
+                        let stream_count = subscriptions.len();
+                        let mut requested_streams = Vec::with_capacity(stream_count);
+                        for (stream_name, sender) in subscriptions {
+                            self.message_router
+                                .add_subscription(stream_name.clone(), sender);
+                            self.state.add_subscription(stream_name.clone());
+                            requested_streams.push(stream_name);
+                        }
+                        self.message_router.add_pending_subscribe(
+                            request_id,
+                            requested_streams,
+                            response,
+                        );
+
+                        info!(
+                            streams = stream_count,
+                            duration_us = start.elapsed().as_micros(),
+                            "Batch market data subscription completed"
+                        );
+                    }
+                    HandlerMode::Static { .. } => {
+                        let _ = response.send(Err(anyhow::anyhow!(
+                            "Subscribe not supported in static mode"
+                        )));
+                    }
+                }
+                Ok(false)
+            }
             StreamMessage::Unsubscribe {
                 stream_names,
                 response,
@@ -448,15 +566,24 @@ impl UnifiedConnectionHandler {
      * - `ws_connection`: WebSocket connection for sending responses
      *
      * # Returns
-     * - Result<bool> where false indicates connection should be restarted
+     * - `Option<CloseReason>`: Reason the connection ended, or None to keep it open.
+     *   Read errors mean the connection dropped without a close frame and map
+     *   to `CloseReason::Abnormal`.
      */
     async fn handle_websocket_message(
         &mut self,
         message: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
         ws_connection: &mut WebSocketConnection,
-    ) -> Result<bool> {
+    ) -> Result<Option<CloseReason>> {
         let message_start = std::time::Instant::now();
-        match message? {
+        let message = match message {
+            Ok(message) => message,
+            Err(e) => {
+                warn!(error = %e, "WebSocket connection dropped without close frame");
+                return Ok(Some(CloseReason::Abnormal));
+            }
+        };
+        match message {
             Message::Text(text) => {
                 if let Ok(value) = serde_json::from_str::<serde_json::Value>(&text) {
                     if let Some(subscribe_msg) =
@@ -474,6 +601,15 @@ impl UnifiedConnectionHandler {
                     }
 
                     self.message_router.route_message(&value, &self.mode);
+                    let failed_subscriptions = self.message_router.take_failed_subscriptions();
+                    if !failed_subscriptions.is_empty() {
+                        self.state.remove_subscriptions(&failed_subscriptions);
+                    }
+
+                    if self.reconnect_on_stream_terminated && is_stream_terminated(&value) {
+                        warn!("User data stream terminated, reconnecting with a new session");
+                        return Ok(Some(CloseReason::StreamTerminated));
+                    }
                 }
 
                 info!(
@@ -481,7 +617,7 @@ impl UnifiedConnectionHandler {
                     message_type = "text",
                     "WebSocket text message processed"
                 );
-                Ok(true)
+                Ok(None)
             }
             Message::Ping(data) => {
                 let pong_start = std::time::Instant::now();
@@ -492,19 +628,33 @@ impl UnifiedConnectionHandler {
                     "WebSocket ping/pong handled"
                 );
                 result?;
-                Ok(true)
+                Ok(None)
             }
-            Message::Close(_) => {
-                info!("WebSocket close message received");
-                Ok(false)
+            Message::Close(frame) => {
+                info!(frame = ?frame, "WebSocket close message received");
+                Ok(Some(CloseReason::from_close_frame(frame.as_ref())))
             }
             _ => {
                 info!(
                     message_duration_us = message_start.elapsed().as_micros(),
                     "WebSocket message processed (other type)"
                 );
-                Ok(true)
+                Ok(None)
             }
         }
     }
 }
+
+/**
+ * Checks whether a message is a user data `eventStreamTerminated` event.
+ *
+ * # Arguments
+ * - `value`: The incoming JSON message, either direct or nested in `event`.
+ *
+ * # Returns
+ * - `true` if the message signals the user data stream was terminated.
+ */
+fn is_stream_terminated(value: &serde_json::Value) -> bool {
+    let event = value.get("event").unwrap_or(value);
+    event.get("e").and_then(|e| e.as_str()) == Some("eventStreamTerminated")
+}
@@ -51,12 +51,14 @@ impl MarketDataConnectionManager {
 
         let url = StreamEndpoint::from_config(&config).build_url(config.market_data_url());
         let stream_config = config.stream_config().clone();
+        let shutdown_token = config.shutdown_token().cloned();
 
         let task_handle = tokio::spawn(ConnectionUtils::run_connection(
             url,
             stream_config,
             status_sender,
             UnifiedConnectionHandler::new_dynamic(message_receiver, None),
+            shutdown_token,
         ));
 
         let manager = Self {
@@ -116,6 +118,7 @@ impl MarketDataConnectionManager {
 
         let url = StreamEndpoint::from_config(&config).build_url(config.market_data_url());
         let stream_config = config.stream_config().clone();
+        let shutdown_token = config.shutdown_token().cloned();
         let senders_clone = senders.clone();
 
         let task_handle = tokio::spawn(ConnectionUtils::run_connection(
@@ -123,6 +126,7 @@ impl MarketDataConnectionManager {
             stream_config,
             status_sender,
             UnifiedConnectionHandler::new_static(message_receiver, senders_clone),
+            shutdown_token,
         ));
 
         let manager = Self {
@@ -147,6 +151,10 @@ impl ConnectionManager for MarketDataConnectionManager {
         self.status_receiver.borrow().clone()
     }
 
+    fn connection_status_receiver(&self) -> watch::Receiver<ConnectionStatus> {
+        self.status_receiver.clone()
+    }
+
     fn stream_config(&self) -> &StreamConfig {
         self.config.stream_config()
     }
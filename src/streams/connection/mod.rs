@@ -2,6 +2,7 @@ mod common;
 mod endpoint;
 mod handler;
 mod market_data_manager;
+mod replay;
 mod router;
 mod state;
 mod types;
@@ -10,5 +11,6 @@ mod websocket;
 
 pub use common::ConnectionManager;
 pub use market_data_manager::MarketDataConnectionManager;
-pub use types::{ConnectionStatus, StreamMessage, ValueReceiver, ValueSender};
+pub use replay::ReplaySource;
+pub use types::{CloseReason, ConnectionStatus, StreamMessage, ValueReceiver, ValueSender};
 pub use user_data_manager::UserDataConnectionManager;
@@ -7,6 +7,7 @@ use uuid::Uuid;
 
 use super::types::{HandlerMode, ValueSender};
 use crate::Result;
+use crate::streams::specs::StreamName;
 
 /**
  * Message router for WebSocket stream data.
@@ -20,11 +21,15 @@ use crate::Result;
  * - `pending_requests`: Map of request IDs to response senders for tracking subscription/unsubscription requests.
  * - `pending_user_data_logons`: Map of session.logon request IDs to user data subscription context,
  *   used for the two-step user data authentication flow.
+ * - `pending_subscribe_streams`: Map of subscribe request IDs to the streams they request.
+ * - `failed_subscriptions`: Streams whose subscription was rejected since the last check.
  */
 pub(super) struct MessageRouter {
     dynamic_channels: HashMap<String, ValueSender>,
     pending_requests: HashMap<String, oneshot::Sender<Result<()>>>,
     pending_user_data_logons: HashMap<String, (String, ValueSender, oneshot::Sender<Result<()>>)>,
+    pending_subscribe_streams: HashMap<String, Vec<String>>,
+    failed_subscriptions: Vec<String>,
 }
 
 impl MessageRouter {
@@ -39,6 +44,8 @@ impl MessageRouter {
             dynamic_channels: HashMap::new(),
             pending_requests: HashMap::new(),
             pending_user_data_logons: HashMap::new(),
+            pending_subscribe_streams: HashMap::new(),
+            failed_subscriptions: Vec::new(),
         }
     }
 
@@ -58,6 +65,37 @@ impl MessageRouter {
         self.pending_requests.insert(request_id, response_sender);
     }
 
+    /**
+     * Tracks a subscribe request whose streams are dropped if it is rejected.
+     *
+     * # Arguments
+     * - `request_id`: ID of the subscribe request.
+     * - `stream_names`: Streams requested by the subscribe request.
+     * - `response_sender`: Sender for the subscription result.
+     */
+    pub fn add_pending_subscribe(
+        &mut self,
+        request_id: String,
+        stream_names: Vec<String>,
+        response_sender: oneshot::Sender<Result<()>>,
+    ) {
+        self.pending_subscribe_streams
+            .insert(request_id.clone(), stream_names);
+        self.add_pending_request(request_id, response_sender);
+    }
+
+    /**
+     * Takes the streams whose subscription was rejected since the last call.
+     *
+     * Their channels have already been removed from the router.
+     *
+     * # Returns
+     * - Names of the rejected streams.
+     */
+    pub fn take_failed_subscriptions(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.failed_subscriptions)
+    }
+
     pub fn add_pending_user_data_logon(
         &mut self,
         logon_id: String,
@@ -134,7 +172,12 @@ impl MessageRouter {
     fn handle_subscription_response(&mut self, value: &Value) -> bool {
         if let Some(id) = value.get("id").and_then(|id| id.as_str()) {
             if let Some(sender) = self.pending_requests.remove(id) {
+                let stream_names = self.pending_subscribe_streams.remove(id);
                 let result = if value.get("error").is_some() {
+                    for stream_name in stream_names.unwrap_or_default() {
+                        self.dynamic_channels.remove(&stream_name);
+                        self.failed_subscriptions.push(stream_name);
+                    }
                     Err(anyhow::anyhow!("WebSocket error: {:?}", value.get("error")))
                 } else {
                     Ok(())
@@ -191,9 +234,9 @@ impl MessageRouter {
             return None;
         }
 
-        self.add_subscription(stream_name, sender);
+        self.add_subscription(stream_name.clone(), sender);
         let subscribe_id = Uuid::new_v4().to_string();
-        self.add_pending_request(subscribe_id.clone(), response);
+        self.add_pending_subscribe(subscribe_id.clone(), vec![stream_name], response);
 
         debug!(
             logon_success = true,
@@ -240,10 +283,15 @@ impl MessageRouter {
      */
     fn route_combined_format(&self, value: &Value) -> bool {
         if let (Some(stream_name), Some(data)) = (value.get("stream"), value.get("data")) {
-            if let Some(stream_name_str) = stream_name.as_str()
-                && let Some(sender) = self.dynamic_channels.get(stream_name_str)
-            {
-                let _ = sender.send(data.clone());
+            if let Some(stream_name_str) = stream_name.as_str() {
+                if let Some(sender) = self.dynamic_channels.get(stream_name_str) {
+                    let _ = sender.send(data.clone());
+                } else if StreamName::parse(stream_name_str).is_none() {
+                    debug!(
+                        stream = stream_name_str,
+                        "Received frame for unrecognised stream name"
+                    );
+                }
             }
             true
         } else {
@@ -378,6 +426,7 @@ impl MessageRouter {
         let pending_count = self.pending_requests.len();
         let user_data_count = self.pending_user_data_logons.len();
 
+        self.pending_subscribe_streams.clear();
         for (_, sender) in self.pending_requests.drain() {
             let _ = sender.send(Err(anyhow::anyhow!("Connection shutting down")));
         }
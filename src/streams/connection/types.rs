@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use serde_json::Value;
 use tokio::sync::{broadcast, oneshot};
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
 
 use crate::Result;
 
@@ -31,6 +32,7 @@ pub(super) type WsRead = futures_util::stream::SplitStream<WsStream>;
  * - `Connecting`: Initial connection attempt in progress.
  * - `Connected`: Successfully connected and operational.
  * - `Reconnecting`: Connection lost, attempting to reconnect with attempt count.
+ * - `Closed`: Connection closed by the server or the network; reconnection follows.
  * - `Disconnected`: Connection closed gracefully.
  * - `Failed`: Connection failed permanently after maximum retry attempts.
  */
@@ -39,15 +41,98 @@ pub enum ConnectionStatus {
     Connecting,
     Connected,
     Reconnecting { attempt: u32 },
+    Closed(CloseReason),
     Disconnected,
     Failed,
 }
 
+/**
+ * Reason a WebSocket connection ended.
+ *
+ * # Variants
+ * - `Normal`: Server sent a close frame with the given code and reason.
+ * - `Abnormal`: Connection dropped without a close handshake (close code 1006).
+ * - `StreamTerminated`: User data stream was terminated by the server.
+ * - `Shutdown`: Client requested the connection to close.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CloseReason {
+    Normal { code: u16, reason: String },
+    Abnormal,
+    StreamTerminated,
+    Shutdown,
+}
+
+impl CloseReason {
+    /**
+     * Close code reported for connections dropped without a close frame.
+     */
+    pub const ABNORMAL_CLOSURE_CODE: u16 = 1006;
+
+    /**
+     * Close code reported for close frames without a status code.
+     */
+    const NO_STATUS_CODE: u16 = 1005;
+
+    /**
+     * Maps a received close frame to a close reason.
+     *
+     * # Arguments
+     * - `frame`: Close frame sent by the server, if it carried a payload.
+     *
+     * # Returns
+     * - `CloseReason`: `Abnormal` for code 1006, otherwise `Normal`.
+     */
+    pub(crate) fn from_close_frame(frame: Option<&CloseFrame>) -> Self {
+        let Some(frame) = frame else {
+            return Self::Normal {
+                code: Self::NO_STATUS_CODE,
+                reason: String::new(),
+            };
+        };
+
+        let code = u16::from(frame.code);
+        if code == Self::ABNORMAL_CLOSURE_CODE {
+            return Self::Abnormal;
+        }
+        Self::Normal {
+            code,
+            reason: frame.reason.to_string(),
+        }
+    }
+
+    /**
+     * Checks whether the connection should be re-established.
+     *
+     * # Returns
+     * - `true` for every reason except a client-requested shutdown.
+     */
+    pub fn should_reconnect(&self) -> bool {
+        !matches!(self, Self::Shutdown)
+    }
+
+    /**
+     * Gets the WebSocket close code the connection ended with.
+     *
+     * # Returns
+     * - `Option<u16>`: Close code, or None for stream terminations and
+     *   client-requested shutdowns, which are not WebSocket closures.
+     */
+    pub fn close_code(&self) -> Option<u16> {
+        match self {
+            Self::Normal { code, .. } => Some(*code),
+            Self::Abnormal => Some(Self::ABNORMAL_CLOSURE_CODE),
+            Self::StreamTerminated | Self::Shutdown => None,
+        }
+    }
+}
+
 /**
  * Message types for communicating with the connection handler.
  *
  * # Variants
  * - `Subscribe`: Request to subscribe to a specific stream.
+ * - `SubscribeAll`: Request to subscribe to several market data streams in one frame.
  * - `Unsubscribe`: Request to unsubscribe from specific streams.
  * - `Shutdown`: Request to gracefully shutdown the connection.
  */
@@ -67,6 +152,18 @@ pub enum StreamMessage {
         response: oneshot::Sender<Result<()>>,
     },
 
+    /**
+     * Subscribe to several market data streams with a single SUBSCRIBE frame.
+     *
+     * # Fields
+     * - `subscriptions`: Stream names with the broadcast senders routing their data.
+     * - `response`: Channel to send subscription result.
+     */
+    SubscribeAll {
+        subscriptions: Vec<(String, ValueSender)>,
+        response: oneshot::Sender<Result<()>>,
+    },
+
     /**
      * Unsubscribe from streams.
      *
@@ -14,6 +14,8 @@ use crate::{BinanceConfig, StreamConfig};
  *
  * Manages user data stream connections using the WebSocket API method.
  * Always operates in dynamic mode and requires API key authentication.
+ * An `eventStreamTerminated` event triggers a reconnect with a freshly
+ * authenticated session so the stream resumes instead of going silent.
  *
  * # Fields
  * - `config`: Binance configuration with authentication credentials.
@@ -54,13 +56,16 @@ impl UserDataConnectionManager {
 
         let url = config.user_data_url().to_string();
         let stream_config = config.stream_config().clone();
+        let shutdown_token = config.shutdown_token().cloned();
         let signer = config.signer().clone();
 
         let task_handle = tokio::spawn(ConnectionUtils::run_connection(
             url,
             stream_config,
             status_sender,
-            UnifiedConnectionHandler::new_dynamic(message_receiver, signer),
+            UnifiedConnectionHandler::new_dynamic(message_receiver, signer)
+                .with_stream_termination_reconnect(),
+            shutdown_token,
         ));
 
         let manager = Self {
@@ -85,6 +90,10 @@ impl ConnectionManager for UserDataConnectionManager {
         self.status_receiver.borrow().clone()
     }
 
+    fn connection_status_receiver(&self) -> watch::Receiver<ConnectionStatus> {
+        self.status_receiver.clone()
+    }
+
     fn stream_config(&self) -> &StreamConfig {
         self.config.stream_config()
     }
@@ -109,3 +118,105 @@ impl ConnectionManager for UserDataConnectionManager {
         ConnectionUtils::abort_connection(&self._task_handles);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::{broadcast, oneshot};
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+    use crate::clients::tests::helpers::MOCK_ED25519_PEM;
+
+    /**
+     * Spawns a user data server that terminates the stream on the first
+     * session and pushes a balance update on every later one.
+     */
+    async fn spawn_terminating_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let logons = Arc::new(AtomicUsize::new(0));
+        let server_logons = logons.clone();
+
+        tokio::spawn(async move {
+            for session in 0.. {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+                while let Some(Ok(Message::Text(text))) = ws.next().await {
+                    let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                    if request["method"] == "session.logon" {
+                        server_logons.fetch_add(1, Ordering::SeqCst);
+                    }
+                    let ack =
+                        serde_json::json!({ "id": request["id"], "status": 200, "result": {} });
+                    ws.send(Message::Text(ack.to_string())).await.unwrap();
+
+                    if request["method"] == "userDataStream.subscribe" {
+                        let event = if session == 0 {
+                            serde_json::json!({ "e": "eventStreamTerminated", "E": 1000 })
+                        } else {
+                            serde_json::json!({
+                                "e": "balanceUpdate", "E": 1001, "a": "BTC",
+                                "d": "0.50000000", "T": 1001
+                            })
+                        };
+                        let frame =
+                            serde_json::json!({ "subscriptionId": session, "event": event });
+                        let _ = ws.send(Message::Text(frame.to_string())).await;
+                    }
+                }
+            }
+        });
+
+        (format!("ws://{}", addr), logons)
+    }
+
+    /**
+     * Tests that an `eventStreamTerminated` event re-authenticates and resumes the stream.
+     */
+    #[tokio::test]
+    async fn test_stream_terminated_reconnects_with_new_session() {
+        // Arrange
+        let (url, logons) = spawn_terminating_server().await;
+        let mut config = BinanceConfig::<StreamConfig>::builder()
+            .with_credentials("test_api_key", MOCK_ED25519_PEM)
+            .with_user_data()
+            .build()
+            .unwrap();
+        config.stream_config_mut().user_data_url = url;
+        config.stream_config_mut().initial_retry_delay = Duration::from_millis(10);
+        let (manager, message_sender) = UserDataConnectionManager::new(config).unwrap();
+        manager.wait_for_connection().await.unwrap();
+        let (sender, mut receiver) = broadcast::channel(16);
+        let (response_sender, response_receiver) = oneshot::channel();
+
+        // Act
+        message_sender
+            .send(StreamMessage::Subscribe {
+                stream_name: "userData".to_string(),
+                sender,
+                response: response_sender,
+            })
+            .unwrap();
+        response_receiver.await.unwrap().unwrap();
+        let mut events = Vec::new();
+        for _ in 0..2 {
+            let event = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            events.push(event);
+        }
+
+        // Assert
+        assert_eq!(events[0]["e"], "eventStreamTerminated");
+        assert_eq!(events[1]["e"], "balanceUpdate");
+        assert_eq!(logons.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::types::responses::TickerBook;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /**
@@ -19,3 +20,75 @@ pub struct BookTickerStreamEvent {
     #[serde(flatten)]
     pub ticker: TickerBook,
 }
+
+impl BookTickerStreamEvent {
+    /**
+     * Calculates the mid price between the best bid and best ask.
+     *
+     * # Returns
+     * - `Decimal`: Average of the best bid and best ask prices.
+     */
+    pub fn mid_price(&self) -> Decimal {
+        (self.ticker.bid_price + self.ticker.ask_price) / Decimal::TWO
+    }
+
+    /**
+     * Calculates the absolute spread between the best ask and best bid.
+     *
+     * # Returns
+     * - `Decimal`: Best ask price minus best bid price.
+     */
+    pub fn spread(&self) -> Decimal {
+        self.ticker.ask_price - self.ticker.bid_price
+    }
+
+    /**
+     * Calculates the spread in basis points relative to the mid price.
+     *
+     * # Returns
+     * - `Decimal`: Spread divided by mid price times 10,000, or zero when the mid price is zero.
+     */
+    pub fn spread_bps(&self) -> Decimal {
+        self.spread()
+            .checked_div(self.mid_price())
+            .map(|ratio| ratio * Decimal::from(10_000))
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::dec;
+
+    /**
+     * Tests mid price, spread, and basis-point spread over a synthetic event.
+     */
+    #[test]
+    fn test_mid_price_and_spread() {
+        // Arrange
+        let event: BookTickerStreamEvent = serde_json::from_str(
+            r#"{"u":400900217,"s":"BNBUSDT","b":"99.95","B":"31.21","a":"100.05","A":"40.66"}"#,
+        )
+        .unwrap();
+
+        // Act & Assert
+        assert_eq!(event.mid_price(), dec!(100));
+        assert_eq!(event.spread(), dec!(0.10));
+        assert_eq!(event.spread_bps(), dec!(10));
+    }
+
+    /**
+     * Tests that a zero-priced book yields a zero basis-point spread.
+     */
+    #[test]
+    fn test_spread_bps_zero_mid_price() {
+        // Arrange
+        let event: BookTickerStreamEvent =
+            serde_json::from_str(r#"{"u":1,"s":"BNBUSDT","b":"0","B":"0","a":"0","A":"0"}"#)
+                .unwrap();
+
+        // Act & Assert
+        assert_eq!(event.spread_bps(), Decimal::ZERO);
+    }
+}
@@ -65,6 +65,25 @@ pub struct PartialBookDepthStreamEvent {
     pub asks: Vec<DepthLevel>,
 }
 
+/**
+ * Partial Book Depth Message
+ *
+ * Partial book depth event annotated with `lastUpdateId` continuity.
+ *
+ * # Variants
+ * - `Depth`: Partial book depth event.
+ * - `DepthReset`: The update id decreased, indicating the book may have been reset.
+ *   Sent before the event that carried the lower id.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialDepthMessage {
+    Depth(PartialBookDepthStreamEvent),
+    DepthReset {
+        previous_update_id: u64,
+        last_update_id: u64,
+    },
+}
+
 /**
  * Diff Depth Stream Event
  *
@@ -54,3 +54,68 @@ pub struct KlineStreamEvent {
     #[serde(rename = "k")]
     pub kline: KlineStreamData,
 }
+
+impl KlineStreamData {
+    /**
+     * Checks whether this kline is closed and its values are final.
+     *
+     * # Returns
+     * - `bool`: True once the kline interval has ended.
+     */
+    pub fn is_closed(&self) -> bool {
+        self.is_kline_closed
+    }
+}
+
+impl KlineStreamEvent {
+    /**
+     * Checks whether the kline carried by this event is closed.
+     *
+     * # Returns
+     * - `bool`: True once the kline interval has ended.
+     */
+    pub fn is_closed(&self) -> bool {
+        self.kline.is_closed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Builds a kline stream frame with the given closed flag.
+     */
+    fn kline_frame(closed: bool) -> String {
+        format!(
+            r#"{{"e":"kline","E":1672515782136,"s":"BNBBTC","k":{{"t":1672515780000,"T":1672515839999,"s":"BNBBTC","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":{},"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}}}"#,
+            closed
+        )
+    }
+
+    /**
+     * Tests that an open kline reports itself as not closed.
+     */
+    #[test]
+    fn test_open_kline_is_not_closed() {
+        // Act
+        let event: KlineStreamEvent = serde_json::from_str(&kline_frame(false)).unwrap();
+
+        // Assert
+        assert!(!event.is_closed());
+        assert!(!event.kline.is_closed());
+    }
+
+    /**
+     * Tests that a closed kline reports itself as closed.
+     */
+    #[test]
+    fn test_closed_kline_is_closed() {
+        // Act
+        let event: KlineStreamEvent = serde_json::from_str(&kline_frame(true)).unwrap();
+
+        // Assert
+        assert!(event.is_closed());
+        assert!(event.kline.is_closed());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::types::responses::TickerStatisticsFull;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /**
@@ -23,6 +24,18 @@ pub struct TickerStreamEvent {
     pub ticker: TickerStatisticsFull,
 }
 
+impl TickerStreamEvent {
+    /**
+     * Gets the 24hr relative price change as a fraction rather than a percentage.
+     *
+     * # Returns
+     * - `Decimal`: Price change ratio (e.g., `-0.0125` for `-1.25` percent).
+     */
+    pub fn price_change_ratio(&self) -> Decimal {
+        self.ticker.price_change_ratio()
+    }
+}
+
 /**
  * All Market Tickers Stream Event
  *
@@ -37,3 +50,26 @@ pub struct TickerStreamEvent {
 pub struct AllTickersStreamEvent {
     pub tickers: Vec<TickerStreamEvent>,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that a negative price change percent deserializes as a Decimal and converts to a ratio.
+     */
+    #[test]
+    fn test_negative_price_change_ratio() {
+        // Arrange
+        let json = r#"{"e":"24hrTicker","E":1672515782136,"s":"BNBBTC","p":"-0.00012500","P":"-1.250","w":"0.01001000","x":"0.01000000","c":"0.00987500","Q":"10","b":"0.00987400","B":"10","a":"0.00987600","A":"100","o":"0.01000000","h":"0.01010000","l":"0.00980000","v":"10000","q":"100","O":0,"C":86400000,"F":0,"L":18150,"n":18151}"#;
+
+        // Act
+        let event: TickerStreamEvent = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(event.ticker.price_change_percent, dec!(-1.25));
+        assert_eq!(event.price_change_ratio(), dec!(-0.0125));
+    }
+}
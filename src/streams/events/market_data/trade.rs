@@ -1,6 +1,11 @@
-use crate::types::responses::Trade;
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::Result;
+use crate::types::responses::Trade;
+
 /**
  * Trade Stream Event
  *
@@ -24,3 +29,99 @@ pub struct TradeStreamEvent {
     #[serde(flatten)]
     pub trade: Trade,
 }
+
+/**
+ * Lightweight Trade Tick
+ *
+ * Minimal view of a trade stream event for high-throughput consumers. Parsed with
+ * `TradeTick::from_frame`, which borrows the numeric strings straight from the frame
+ * and skips every other field, so no heap allocation takes place.
+ *
+ * # Fields:
+ * - `price`: Trade execution price
+ * - `quantity`: Base asset quantity traded
+ * - `trade_time`: Trade execution timestamp in milliseconds since Unix epoch
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeTick {
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub trade_time: u64,
+}
+
+/**
+ * Borrowed subset of a raw trade frame used by the fast path.
+ */
+#[derive(Deserialize)]
+struct RawTradeTick<'a> {
+    #[serde(rename = "p")]
+    price: &'a str,
+    #[serde(rename = "q")]
+    quantity: &'a str,
+    #[serde(rename = "T")]
+    trade_time: u64,
+}
+
+impl TradeTick {
+    /**
+     * Parses price, quantity, and trade time from a raw trade stream frame.
+     *
+     * # Arguments
+     * - `frame`: Raw `<symbol>@trade` event JSON.
+     *
+     * # Returns
+     * - `TradeTick`: Extracted trade values.
+     */
+    pub fn from_frame(frame: &str) -> Result<Self> {
+        let raw: RawTradeTick<'_> = serde_json::from_str(frame)?;
+        Ok(Self {
+            price: Decimal::from_str(raw.price)?,
+            quantity: Decimal::from_str(raw.quantity)?,
+            trade_time: raw.trade_time,
+        })
+    }
+}
+
+impl From<&TradeStreamEvent> for TradeTick {
+    fn from(event: &TradeStreamEvent) -> Self {
+        Self {
+            price: event.trade.price,
+            quantity: event.trade.quantity,
+            trade_time: event.trade.time,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRADE_FRAME: &str = r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.00100000","q":"100.50000000","T":1672515782136,"m":true,"M":true}"#;
+
+    /**
+     * Tests that the fast path yields the same values as the full parser.
+     */
+    #[test]
+    fn test_fast_path_matches_full_parser() {
+        // Arrange
+        let event: TradeStreamEvent = serde_json::from_str(TRADE_FRAME).unwrap();
+
+        // Act
+        let tick = TradeTick::from_frame(TRADE_FRAME).unwrap();
+
+        // Assert
+        assert_eq!(tick, TradeTick::from(&event));
+    }
+
+    /**
+     * Tests that frames missing required fields are rejected.
+     */
+    #[test]
+    fn test_fast_path_rejects_incomplete_frame() {
+        // Act
+        let result = TradeTick::from_frame(r#"{"e":"trade","p":"1.0"}"#);
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -1,5 +1,7 @@
 mod market_data;
+mod normalize_decimals;
 mod user_data;
 
 pub use market_data::*;
+pub use normalize_decimals::NormalizeDecimals;
 pub use user_data::*;
@@ -0,0 +1,166 @@
+use rust_decimal::Decimal;
+
+use super::{
+    AggregateTradeStreamEvent, AllMiniTickersStreamEvent, AllRollingWindowTickersStreamEvent,
+    AllTickersStreamEvent, AveragePriceStreamEvent, BalanceUpdateEvent, BookTickerStreamEvent,
+    DepthLevel, DiffDepthStreamEvent, EventStreamTerminatedEvent, ExecutionReportEvent,
+    ExternalLockUpdateEvent, KlineStreamData, KlineStreamEvent, ListStatusEvent, MiniTickerData,
+    MiniTickerStreamEvent, OutboundAccountPositionEvent, PartialBookDepthStreamEvent,
+    RollingWindowTickerStreamEvent, TickerStreamEvent, TradeStreamEvent, UserDataEvent,
+};
+use crate::types::responses::{
+    AggregateTrade, Balance, Kline, TickerBook, TickerStatisticsFull, Trade,
+};
+
+/**
+ * Strips superfluous trailing zeros from the decimal fields of a stream event.
+ *
+ * Only typed `Decimal` fields are rewritten, so string fields that happen to
+ * look like decimals, such as client order IDs, are left untouched.
+ */
+pub trait NormalizeDecimals {
+    /**
+     * Rewrites every decimal field in its normalized form.
+     */
+    fn normalize_decimals(&mut self);
+}
+
+impl NormalizeDecimals for Decimal {
+    fn normalize_decimals(&mut self) {
+        *self = self.normalize();
+    }
+}
+
+impl<T: NormalizeDecimals> NormalizeDecimals for Option<T> {
+    fn normalize_decimals(&mut self) {
+        if let Some(value) = self {
+            value.normalize_decimals();
+        }
+    }
+}
+
+impl<T: NormalizeDecimals> NormalizeDecimals for Vec<T> {
+    fn normalize_decimals(&mut self) {
+        self.iter_mut()
+            .for_each(NormalizeDecimals::normalize_decimals);
+    }
+}
+
+/**
+ * Implements `NormalizeDecimals` by normalizing the listed fields.
+ */
+macro_rules! normalize_fields {
+    ($type:ty { $($field:tt),* }) => {
+        impl NormalizeDecimals for $type {
+            fn normalize_decimals(&mut self) {
+                $(self.$field.normalize_decimals();)*
+            }
+        }
+    };
+}
+
+normalize_fields!(Trade {
+    price,
+    quantity,
+    quote_quantity
+});
+normalize_fields!(AggregateTrade { price, quantity });
+normalize_fields!(TickerBook {
+    bid_price,
+    bid_quantity,
+    ask_price,
+    ask_quantity
+});
+normalize_fields!(TickerStatisticsFull {
+    price_change,
+    price_change_percent,
+    weighted_avg_price,
+    prev_close_price,
+    last_quantity,
+    bid_price,
+    bid_quantity,
+    ask_price,
+    ask_quantity,
+    open_price,
+    high_price,
+    low_price,
+    last_price,
+    volume,
+    quote_volume
+});
+normalize_fields!(Kline {
+    open_price,
+    high_price,
+    low_price,
+    close_price,
+    volume,
+    quote_asset_volume,
+    taker_buy_base_asset_volume,
+    taker_buy_quote_asset_volume
+});
+normalize_fields!(Balance { free, locked });
+
+normalize_fields!(TradeStreamEvent { trade });
+normalize_fields!(AggregateTradeStreamEvent { aggregate_trade });
+normalize_fields!(AveragePriceStreamEvent { price });
+normalize_fields!(BookTickerStreamEvent { ticker });
+normalize_fields!(DepthLevel { 0, 1 });
+normalize_fields!(PartialBookDepthStreamEvent { bids, asks });
+normalize_fields!(DiffDepthStreamEvent { bids, asks });
+normalize_fields!(KlineStreamData { kline });
+normalize_fields!(KlineStreamEvent { kline });
+normalize_fields!(MiniTickerStreamEvent {
+    close_price,
+    open_price,
+    high_price,
+    low_price,
+    volume,
+    quote_volume
+});
+normalize_fields!(MiniTickerData {
+    close_price,
+    open_price,
+    high_price,
+    low_price,
+    volume,
+    quote_volume
+});
+normalize_fields!(AllMiniTickersStreamEvent { tickers });
+normalize_fields!(TickerStreamEvent { ticker });
+normalize_fields!(AllTickersStreamEvent { tickers });
+normalize_fields!(RollingWindowTickerStreamEvent { ticker });
+normalize_fields!(AllRollingWindowTickersStreamEvent { tickers });
+
+normalize_fields!(ExecutionReportEvent {
+    quantity,
+    price,
+    stop_price,
+    iceberg_quantity,
+    last_executed_quantity,
+    cumulative_filled_quantity,
+    last_executed_price,
+    commission_amount,
+    cumulative_quote_quantity,
+    last_quote_quantity,
+    quote_order_quantity,
+    prevented_quantity,
+    last_prevented_quantity
+});
+normalize_fields!(OutboundAccountPositionEvent { balances });
+normalize_fields!(BalanceUpdateEvent { balance_delta });
+normalize_fields!(ListStatusEvent {});
+normalize_fields!(ExternalLockUpdateEvent { delta });
+normalize_fields!(EventStreamTerminatedEvent {});
+
+impl NormalizeDecimals for UserDataEvent {
+    fn normalize_decimals(&mut self) {
+        match self {
+            UserDataEvent::ExecutionReport(event) => event.normalize_decimals(),
+            UserDataEvent::OutboundAccountPosition(event) => event.normalize_decimals(),
+            UserDataEvent::BalanceUpdate(event) => event.normalize_decimals(),
+            UserDataEvent::ListStatus(event) => event.normalize_decimals(),
+            UserDataEvent::ExternalLockUpdate(event) => event.normalize_decimals(),
+            UserDataEvent::EventStreamTerminated(event) => event.normalize_decimals(),
+        }
+    }
+}
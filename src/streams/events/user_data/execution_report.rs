@@ -1,7 +1,10 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::enums::{OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, TimeInForce};
+use crate::enums::{
+    MatchType, OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, TimeInForce,
+    WorkingFloor,
+};
 
 /**
  * Execution Report Event
@@ -10,7 +13,11 @@ use crate::enums::{OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, T
  * This is the primary event for tracking order lifecycle.
  *
  * Based on Binance WebSocket API documentation, includes all fields that may appear
- * in executionReport events from the WebSocket stream.
+ * in executionReport events from the WebSocket stream. The self-trade prevention
+ * fields (`v`, `A`, `B`, `u`, `U`) are only sent when an order expired due to STP.
+ * Commission is reported per fill in `n` and `N`; `N` is null when no commission
+ * was charged. The trailing, strategy and SOR allocation fields (`d`, `D`, `j`,
+ * `J`, `b`, `a`, `k`, `uS`) are only sent for orders using those features.
  */
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionReportEvent {
@@ -91,4 +98,191 @@ pub struct ExecutionReportEvent {
     pub working_time: u64,
     #[serde(rename = "V")]
     pub self_trade_prevention_mode: SelfTradePreventionMode,
+    #[serde(rename = "v")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevented_match_id: Option<u64>,
+    #[serde(rename = "A")]
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevented_quantity: Option<Decimal>,
+    #[serde(rename = "B")]
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_prevented_quantity: Option<Decimal>,
+    #[serde(rename = "u")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_group_id: Option<u64>,
+    #[serde(rename = "U")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counter_order_id: Option<u64>,
+    #[serde(rename = "d")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<u32>,
+    #[serde(rename = "D")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailing_time: Option<i64>,
+    #[serde(rename = "j")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy_id: Option<u64>,
+    #[serde(rename = "J")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy_type: Option<u32>,
+    #[serde(rename = "b")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<MatchType>,
+    #[serde(rename = "a")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocation_id: Option<u64>,
+    #[serde(rename = "k")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_floor: Option<WorkingFloor>,
+    #[serde(rename = "uS")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_sor: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::streams::test_fixtures::execution_report;
+
+    /**
+     * Builds an execution report payload for an order expired by self-trade prevention.
+     */
+    fn expired_in_match_report() -> serde_json::Value {
+        execution_report(json!({
+            "c": "stp", "q": "2.00000000", "x": "TRADE_PREVENTION", "X": "EXPIRED_IN_MATCH",
+            "i": 42, "I": 7, "w": false, "V": "EXPIRE_TAKER",
+            "v": 3, "A": "2.00000000", "B": "2.00000000", "u": 1, "U": 37
+        }))
+    }
+
+    /**
+     * Tests that the self-trade prevention fields are typed when present.
+     */
+    #[test]
+    fn test_deserialize_prevented_fields() {
+        // Act
+        let event: ExecutionReportEvent =
+            serde_json::from_value(expired_in_match_report()).unwrap();
+
+        // Assert
+        assert_eq!(event.prevented_match_id, Some(3));
+        assert_eq!(event.prevented_quantity, Some(dec!(2)));
+        assert_eq!(event.last_prevented_quantity, Some(dec!(2)));
+        assert_eq!(event.trade_group_id, Some(1));
+        assert_eq!(event.counter_order_id, Some(37));
+    }
+
+    /**
+     * Tests that reports without self-trade prevention leave the fields unset.
+     */
+    #[test]
+    fn test_deserialize_without_prevented_fields() {
+        // Arrange
+        let mut json = expired_in_match_report();
+        for field in ["v", "A", "B", "u", "U"] {
+            json.as_object_mut().unwrap().remove(field);
+        }
+
+        // Act
+        let event: ExecutionReportEvent = serde_json::from_value(json).unwrap();
+
+        // Assert
+        assert_eq!(event.prevented_match_id, None);
+        assert_eq!(event.prevented_quantity, None);
+        assert_eq!(event.trade_group_id, None);
+        assert_eq!(event.counter_order_id, None);
+    }
+
+    /**
+     * Builds an execution report payload for a SOR fill charged commission in BNB.
+     */
+    fn sor_trade_report() -> serde_json::Value {
+        execution_report(json!({
+            "E": 2000, "s": "BNBUSDT", "c": "sor1", "p": "300.00000000",
+            "x": "TRADE", "X": "PARTIALLY_FILLED",
+            "i": 4293153, "l": "0.40000000", "z": "0.40000000", "L": "299.50000000",
+            "n": "0.00030000", "N": "BNB", "T": 1999, "t": 7721, "I": 8641984,
+            "O": 1999, "Z": "119.80000000", "Y": "119.80000000", "W": 1999,
+            "d": 100, "D": 1500, "j": 1000000, "J": 1000000,
+            "b": "ONE_PARTY_TRADE_REPORT", "a": 1234, "k": "SOR", "uS": true
+        }))
+    }
+
+    /**
+     * Tests that a complete trade report with commission is typed field by field.
+     */
+    #[test]
+    fn test_deserialize_trade_with_commission() {
+        // Act
+        let event: ExecutionReportEvent = serde_json::from_value(sor_trade_report()).unwrap();
+
+        // Assert
+        assert_eq!(event.event_time, 2000);
+        assert_eq!(event.symbol, "BNBUSDT");
+        assert_eq!(event.client_order_id, "sor1");
+        assert_eq!(event.side, OrderSide::Buy);
+        assert_eq!(event.order_type, OrderType::Limit);
+        assert_eq!(event.time_in_force, TimeInForce::GTC);
+        assert_eq!(event.quantity, dec!(1));
+        assert_eq!(event.price, dec!(300));
+        assert_eq!(event.stop_price, Decimal::ZERO);
+        assert_eq!(event.iceberg_quantity, Decimal::ZERO);
+        assert_eq!(event.order_list_id, -1);
+        assert_eq!(event.original_client_order_id, "");
+        assert_eq!(event.execution_type, "TRADE");
+        assert_eq!(event.order_status, OrderStatus::PartiallyFilled);
+        assert_eq!(event.order_reject_reason, "NONE");
+        assert_eq!(event.order_id, 4293153);
+        assert_eq!(event.last_executed_quantity, dec!(0.4));
+        assert_eq!(event.cumulative_filled_quantity, dec!(0.4));
+        assert_eq!(event.last_executed_price, dec!(299.5));
+        assert_eq!(event.commission_amount, dec!(0.0003));
+        assert_eq!(event.commission_asset.as_deref(), Some("BNB"));
+        assert_eq!(event.transaction_time, 1999);
+        assert_eq!(event.trade_id, Some(7721));
+        assert_eq!(event.execution_id, 8641984);
+        assert!(event.is_on_book);
+        assert!(!event.is_maker);
+        assert_eq!(event.order_creation_time, 1999);
+        assert_eq!(event.cumulative_quote_quantity, dec!(119.8));
+        assert_eq!(event.last_quote_quantity, dec!(119.8));
+        assert_eq!(event.quote_order_quantity, Decimal::ZERO);
+        assert_eq!(event.working_time, 1999);
+        assert_eq!(
+            event.self_trade_prevention_mode,
+            SelfTradePreventionMode::NoProtection
+        );
+        assert_eq!(event.trailing_delta, Some(100));
+        assert_eq!(event.trailing_time, Some(1500));
+        assert_eq!(event.strategy_id, Some(1000000));
+        assert_eq!(event.strategy_type, Some(1000000));
+        assert_eq!(event.match_type, Some(MatchType::OnePartyTradeReport));
+        assert_eq!(event.allocation_id, Some(1234));
+        assert_eq!(event.working_floor, Some(WorkingFloor::SOR));
+        assert_eq!(event.used_sor, Some(true));
+    }
+
+    /**
+     * Tests that a zero-commission fill has no commission asset.
+     */
+    #[test]
+    fn test_deserialize_zero_commission_fill() {
+        // Arrange
+        let mut json = sor_trade_report();
+        json["n"] = json!("0");
+        json["N"] = json!(null);
+
+        // Act
+        let event: ExecutionReportEvent = serde_json::from_value(json).unwrap();
+
+        // Assert
+        assert_eq!(event.commission_amount, Decimal::ZERO);
+        assert_eq!(event.commission_asset, None);
+        assert_eq!(event.last_executed_quantity, dec!(0.4));
+    }
 }
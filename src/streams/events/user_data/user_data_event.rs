@@ -36,3 +36,19 @@ pub enum UserDataEvent {
     #[serde(rename = "eventStreamTerminated")]
     EventStreamTerminated(EventStreamTerminatedEvent),
 }
+
+impl UserDataEvent {
+    /**
+     * Gets the symbol the event refers to.
+     *
+     * # Returns
+     * - `Option<&str>`: Symbol for order and order list events, None for account-wide events.
+     */
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            UserDataEvent::ExecutionReport(event) => Some(&event.symbol),
+            UserDataEvent::ListStatus(event) => Some(&event.symbol),
+            _ => None,
+        }
+    }
+}
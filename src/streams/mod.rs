@@ -1,10 +1,21 @@
+mod account_state;
+mod book_manager;
+mod book_registry;
 mod client;
 mod connection;
 pub mod events;
+mod order_tracker;
 pub mod specs;
+#[cfg(test)]
+mod test_fixtures;
 mod tests;
 
-pub use client::BinanceSpotStreamClient;
+pub use account_state::AccountState;
+pub use book_manager::OrderBookManager;
+pub use book_registry::BookRegistry;
+pub use client::{BinanceSpotStreamClient, TypedSubscription};
+pub use connection::{CloseReason, ConnectionStatus, ReplaySource};
+pub use order_tracker::OrderTracker;
 
 use crate::Result;
 use crate::{BinanceConfig, StreamConfig};
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use super::client::TypedSubscription;
+use super::events::{ExecutionReportEvent, UserDataEvent};
+
+/**
+ * Live order state built from user data stream execution reports.
+ *
+ * Keeps the latest execution report for every order seen on the stream and
+ * broadcasts a notification when an order reaches a terminal status. Cloning
+ * the tracker shares the same underlying state.
+ *
+ * # Fields
+ * - `orders`: Latest execution report per symbol and order ID.
+ * - `terminal_sender`: Broadcasts reports for orders that reached a terminal status.
+ */
+#[derive(Debug, Clone)]
+pub struct OrderTracker {
+    orders: Arc<RwLock<HashMap<(String, u64), ExecutionReportEvent>>>,
+    terminal_sender: broadcast::Sender<ExecutionReportEvent>,
+}
+
+impl OrderTracker {
+    /**
+     * Creates an empty order tracker.
+     *
+     * # Arguments
+     * - `buffer_size`: Capacity of the terminal notification channel.
+     *
+     * # Returns
+     * - New OrderTracker instance.
+     */
+    pub fn new(buffer_size: usize) -> Self {
+        let (terminal_sender, _) = broadcast::channel(buffer_size);
+        Self {
+            orders: Arc::new(RwLock::new(HashMap::new())),
+            terminal_sender,
+        }
+    }
+
+    /**
+     * Subscribes to terminal order notifications.
+     *
+     * # Returns
+     * - `broadcast::Receiver<ExecutionReportEvent>`: Receives the final report of each completed order.
+     */
+    pub fn subscribe_terminal(&self) -> broadcast::Receiver<ExecutionReportEvent> {
+        self.terminal_sender.subscribe()
+    }
+
+    /**
+     * Applies a user data event to the tracked state.
+     *
+     * Events other than execution reports are ignored.
+     *
+     * # Arguments
+     * - `event`: User data stream event.
+     */
+    pub fn apply(&self, event: &UserDataEvent) {
+        if let UserDataEvent::ExecutionReport(report) = event {
+            self.apply_execution_report(report);
+        }
+    }
+
+    /**
+     * Applies an execution report to the tracked state.
+     *
+     * Reports older than the one already stored for the order are ignored, so
+     * out-of-order delivery cannot move an order back to an earlier status.
+     * Order IDs are only unique per symbol, so orders are keyed by both.
+     *
+     * # Arguments
+     * - `report`: Execution report for a single order.
+     */
+    pub fn apply_execution_report(&self, report: &ExecutionReportEvent) {
+        {
+            let mut orders = self.orders.write().expect("order tracker lock poisoned");
+            let key = (report.symbol.clone(), report.order_id);
+            if let Some(current) = orders.get(&key)
+                && (current.transaction_time > report.transaction_time
+                    || current.order_status.is_terminal())
+            {
+                debug!(
+                    symbol = %report.symbol,
+                    order_id = report.order_id,
+                    "Ignoring stale execution report"
+                );
+                return;
+            }
+            orders.insert(key, report.clone());
+        }
+
+        if report.order_status.is_terminal() {
+            let _ = self.terminal_sender.send(report.clone());
+        }
+    }
+
+    /**
+     * Gets the latest execution report for an order.
+     *
+     * # Arguments
+     * - `symbol`: Symbol the order was placed on (case-insensitive).
+     * - `order_id`: Order ID to look up.
+     *
+     * # Returns
+     * - `Option<ExecutionReportEvent>`: Latest report, or None if the order has not been seen.
+     */
+    pub fn order(&self, symbol: &str, order_id: u64) -> Option<ExecutionReportEvent> {
+        self.orders
+            .read()
+            .expect("order tracker lock poisoned")
+            .get(&(symbol.to_uppercase(), order_id))
+            .cloned()
+    }
+
+    /**
+     * Gets all orders that have not reached a terminal status.
+     *
+     * # Returns
+     * - `Vec<ExecutionReportEvent>`: Latest report of each open order.
+     */
+    pub fn open_orders(&self) -> Vec<ExecutionReportEvent> {
+        self.orders
+            .read()
+            .expect("order tracker lock poisoned")
+            .values()
+            .filter(|report| !report.order_status.is_terminal())
+            .cloned()
+            .collect()
+    }
+
+    /**
+     * Removes orders that have reached a terminal status.
+     */
+    pub fn prune_terminal(&self) {
+        self.orders
+            .write()
+            .expect("order tracker lock poisoned")
+            .retain(|_, report| !report.order_status.is_terminal());
+    }
+
+    /**
+     * Consumes a user data subscription in the background.
+     *
+     * The task runs until the subscription is closed. Lagged events are
+     * logged and skipped.
+     *
+     * # Arguments
+     * - `subscription`: User data stream subscription.
+     *
+     * # Returns
+     * - `JoinHandle<()>`: Handle of the background task.
+     */
+    pub fn track(&self, mut subscription: TypedSubscription<UserDataEvent>) -> JoinHandle<()> {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            loop {
+                match subscription.recv().await {
+                    Ok(event) => tracker.apply(&event),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Order tracker lagged behind user data stream");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+}
+
+impl Default for OrderTracker {
+    fn default() -> Self {
+        Self::new(64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::OrderStatus;
+    use crate::streams::test_fixtures;
+
+    fn execution_report(
+        execution_type: &str,
+        status: &str,
+        last_quantity: &str,
+        cumulative_quantity: &str,
+        transaction_time: u64,
+    ) -> UserDataEvent {
+        serde_json::from_value(test_fixtures::execution_report(json!({
+            "E": transaction_time, "c": "tracked", "q": "2.00000000",
+            "x": execution_type, "X": status, "i": 42,
+            "l": last_quantity, "z": cumulative_quantity, "L": "0.00100000",
+            "T": transaction_time, "I": transaction_time
+        })))
+        .unwrap()
+    }
+
+    /**
+     * Tests that a NEW, PARTIALLY_FILLED, FILLED sequence ends in a filled order.
+     */
+    #[test]
+    fn test_tracks_order_to_fill() {
+        // Arrange
+        let tracker = OrderTracker::default();
+        let mut terminal = tracker.subscribe_terminal();
+        let events = [
+            execution_report("NEW", "NEW", "0", "0", 1000),
+            execution_report("TRADE", "PARTIALLY_FILLED", "1", "1", 1001),
+            execution_report("TRADE", "FILLED", "1", "2", 1002),
+        ];
+
+        // Act
+        tracker.apply(&events[0]);
+        tracker.apply(&events[1]);
+        let partial = tracker.order("BNBBTC", 42).unwrap();
+        tracker.apply(&events[2]);
+
+        // Assert
+        assert_eq!(partial.order_status, OrderStatus::PartiallyFilled);
+        assert_eq!(tracker.open_orders().len(), 0);
+        let order = tracker.order("BNBBTC", 42).unwrap();
+        assert_eq!(order.order_status, OrderStatus::Filled);
+        assert_eq!(order.cumulative_filled_quantity, dec!(2));
+        let completed = terminal.try_recv().unwrap();
+        assert_eq!(completed.order_id, 42);
+        assert_eq!(completed.order_status, OrderStatus::Filled);
+        assert!(terminal.try_recv().is_err());
+    }
+
+    /**
+     * Tests that a late report cannot move an order back to an earlier status.
+     */
+    #[test]
+    fn test_ignores_stale_reports() {
+        // Arrange
+        let tracker = OrderTracker::default();
+
+        // Act
+        tracker.apply(&execution_report("NEW", "NEW", "0", "0", 1000));
+        tracker.apply(&execution_report("TRADE", "FILLED", "2", "2", 1002));
+        tracker.apply(&execution_report(
+            "TRADE",
+            "PARTIALLY_FILLED",
+            "1",
+            "1",
+            1001,
+        ));
+
+        // Assert
+        assert_eq!(
+            tracker.order("BNBBTC", 42).unwrap().order_status,
+            OrderStatus::Filled
+        );
+    }
+
+    /**
+     * Tests that orders on two symbols sharing an order ID are tracked separately.
+     */
+    #[test]
+    fn test_tracks_same_order_id_per_symbol() {
+        // Arrange
+        let tracker = OrderTracker::default();
+        let UserDataEvent::ExecutionReport(mut other_symbol) =
+            execution_report("NEW", "NEW", "0", "0", 1000)
+        else {
+            panic!("Expected execution report");
+        };
+        other_symbol.symbol = "ETHBTC".to_string();
+
+        // Act
+        tracker.apply(&execution_report("TRADE", "FILLED", "2", "2", 1002));
+        tracker.apply_execution_report(&other_symbol);
+
+        // Assert
+        assert_eq!(
+            tracker.order("BNBBTC", 42).unwrap().order_status,
+            OrderStatus::Filled
+        );
+        assert_eq!(
+            tracker.order("ethbtc", 42).unwrap().order_status,
+            OrderStatus::New
+        );
+        let open_orders = tracker.open_orders();
+        assert_eq!(open_orders.len(), 1);
+        assert_eq!(open_orders[0].symbol, "ETHBTC");
+    }
+}
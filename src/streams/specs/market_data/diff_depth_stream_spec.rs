@@ -76,6 +76,30 @@ impl DiffDepthStreamSpec {
     pub fn fast(symbol: impl Into<String>) -> Self {
         Self::with_fast_updates(symbol)
     }
+
+    /**
+     * Sets the update speed of the stream
+     *
+     * # Arguments
+     * - `update_speed` - Update frequency (1000ms standard or 100ms fast)
+     *
+     * # Returns
+     * - Self with the update speed set
+     */
+    pub fn with_update_speed(mut self, update_speed: UpdateSpeed) -> Self {
+        self.update_speed = update_speed;
+        self
+    }
+
+    /**
+     * Gets the update speed of the stream
+     *
+     * # Returns
+     * - Update frequency of the stream
+     */
+    pub fn update_speed(&self) -> UpdateSpeed {
+        self.update_speed
+    }
 }
 
 impl StreamSpec for DiffDepthStreamSpec {
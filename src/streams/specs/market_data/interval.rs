@@ -22,7 +22,7 @@
  * - `OneWeek`: 1 week interval ("1w")
  * - `OneMonth`: 1 month interval ("1M")
  */
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)]
 pub enum Interval {
     OneSecond,
@@ -70,6 +70,70 @@ impl Interval {
             Self::OneMonth => "1M",
         }
     }
+
+    /**
+     * Parses the string representation of the interval
+     *
+     * # Arguments
+     * - `value` - String representation used in stream names
+     *
+     * # Returns
+     * - Matching interval, or None if the value is not supported
+     */
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "1s" => Some(Self::OneSecond),
+            "1m" => Some(Self::OneMinute),
+            "3m" => Some(Self::ThreeMinutes),
+            "5m" => Some(Self::FiveMinutes),
+            "15m" => Some(Self::FifteenMinutes),
+            "30m" => Some(Self::ThirtyMinutes),
+            "1h" => Some(Self::OneHour),
+            "2h" => Some(Self::TwoHours),
+            "4h" => Some(Self::FourHours),
+            "6h" => Some(Self::SixHours),
+            "8h" => Some(Self::EightHours),
+            "12h" => Some(Self::TwelveHours),
+            "1d" => Some(Self::OneDay),
+            "3d" => Some(Self::ThreeDays),
+            "1w" => Some(Self::OneWeek),
+            "1M" => Some(Self::OneMonth),
+            _ => None,
+        }
+    }
+
+    /**
+     * Gets the fixed length of the interval
+     *
+     * # Returns
+     * - Interval length, or None for `OneMonth` whose length varies
+     */
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        const MINUTE: u64 = 60;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        let seconds = match self {
+            Self::OneSecond => 1,
+            Self::OneMinute => MINUTE,
+            Self::ThreeMinutes => 3 * MINUTE,
+            Self::FiveMinutes => 5 * MINUTE,
+            Self::FifteenMinutes => 15 * MINUTE,
+            Self::ThirtyMinutes => 30 * MINUTE,
+            Self::OneHour => HOUR,
+            Self::TwoHours => 2 * HOUR,
+            Self::FourHours => 4 * HOUR,
+            Self::SixHours => 6 * HOUR,
+            Self::EightHours => 8 * HOUR,
+            Self::TwelveHours => 12 * HOUR,
+            Self::OneDay => DAY,
+            Self::ThreeDays => 3 * DAY,
+            Self::OneWeek => 7 * DAY,
+            Self::OneMonth => return None,
+        };
+
+        Some(std::time::Duration::from_secs(seconds))
+    }
 }
 
 impl std::fmt::Display for Interval {
@@ -169,66 +169,3 @@ impl StreamSpec for KlineWithTimezoneStreamSpec {
         config.kline_buffer_size
     }
 }
-
-use crate::types::responses::Kline;
-use serde::{Deserialize, Serialize};
-
-/**
- * Kline Stream Data
- *
- * Contains kline-specific metadata and flattened kline OHLCV data.
- * This structure represents the "k" field within a KlineStreamEvent.
- *
- * # Fields:
- * - `symbol`: Trading pair symbol (serde field "s")
- * - `interval`: Kline interval string (e.g., "1m", "1h")
- * - `first_trade_id`: First trade ID included in this kline
- * - `last_trade_id`: Last trade ID included in this kline
- * - `is_kline_closed`: Whether this kline period is complete/closed
- * - `kline`: Flattened kline data containing timestamps, OHLCV prices, volumes, and trade counts
- */
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct KlineStreamData {
-    #[serde(rename = "s")]
-    pub symbol: String,
-    #[serde(rename = "i")]
-    pub interval: String,
-    #[serde(rename = "f")]
-    pub first_trade_id: u64,
-    #[serde(rename = "L")]
-    pub last_trade_id: u64,
-    #[serde(rename = "x")]
-    pub is_kline_closed: bool,
-    #[serde(flatten)]
-    pub kline: Kline,
-}
-
-/**
- * Kline Stream Event
- *
- * Real-time kline/candlestick updates pushed every second (for 1s interval) or every 2 seconds (for other intervals).
- * This event is received when subscribed to a kline stream with timezone offset.
- *
- * # Update Speed
- * - 1000ms for 1s interval
- * - 2000ms for all other intervals
- *
- * # Fields:
- * - `event_type`: Event type identifier, always "kline" (serde field "e")
- * - `event_time`: Event timestamp in milliseconds since Unix epoch (serde field "E")
- * - `symbol`: Trading pair symbol (serde field "s")
- * - `kline`: Detailed kline data containing interval, trade IDs, prices, volumes, and metadata (serde field "k")
- */
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
-pub struct KlineStreamEvent {
-    #[serde(rename = "e")]
-    pub event_type: String,
-    #[serde(rename = "E")]
-    pub event_time: u64,
-    #[serde(rename = "s")]
-    pub symbol: String,
-    #[serde(rename = "k")]
-    pub kline: KlineStreamData,
-}
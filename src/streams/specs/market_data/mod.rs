@@ -16,6 +16,7 @@ mod kline_with_timezone_stream_spec;
 mod mini_ticker_stream_spec;
 mod partial_book_depth_stream_spec;
 mod rolling_window_ticker_stream_spec;
+mod stream_name;
 mod ticker_stream_spec;
 mod trade_stream_spec;
 
@@ -46,8 +47,12 @@ pub use partial_book_depth_stream_spec::PartialBookDepthStreamSpec;
 #[allow(unused_imports)]
 pub use rolling_window_ticker_stream_spec::RollingWindowTickerStreamSpec;
 #[allow(unused_imports)]
+pub use stream_name::StreamName;
+#[allow(unused_imports)]
 pub use ticker_stream_spec::TickerStreamSpec;
 #[allow(unused_imports)]
+pub use timezone_offset::TimezoneOffset;
+#[allow(unused_imports)]
 pub use trade_stream_spec::TradeStreamSpec;
 #[allow(unused_imports)]
 pub use update_speed::UpdateSpeed;
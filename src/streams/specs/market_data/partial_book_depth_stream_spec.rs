@@ -22,20 +22,21 @@ pub struct PartialBookDepthStreamSpec {
 #[allow(dead_code)]
 impl PartialBookDepthStreamSpec {
     /**
-     * Creates a new partial book depth stream specification with standard update speed
+     * Creates a new partial book depth stream specification
      *
      * # Arguments
      * - `symbol` - Trading pair symbol (e.g., "BTCUSDT")
      * - `levels` - Number of price levels (5, 10, or 20)
+     * - `update_speed` - Update frequency (1000ms standard or 100ms fast)
      *
      * # Returns
-     * - New PartialBookDepthStreamSpec instance with 1000ms updates
+     * - New PartialBookDepthStreamSpec instance
      */
-    pub fn new(symbol: impl Into<String>, levels: u8) -> Self {
+    pub fn new(symbol: impl Into<String>, levels: u8, update_speed: UpdateSpeed) -> Self {
         Self {
             symbol: symbol.into(),
             levels,
-            update_speed: UpdateSpeed::Standard,
+            update_speed,
         }
     }
 
@@ -50,11 +51,7 @@ impl PartialBookDepthStreamSpec {
      * - New PartialBookDepthStreamSpec instance with 100ms updates
      */
     pub fn with_fast_updates(symbol: impl Into<String>, levels: u8) -> Self {
-        Self {
-            symbol: symbol.into(),
-            levels,
-            update_speed: UpdateSpeed::Fast100ms,
-        }
+        Self::new(symbol, levels, UpdateSpeed::Fast100ms)
     }
 
     /**
@@ -67,7 +64,7 @@ impl PartialBookDepthStreamSpec {
      * - New PartialBookDepthStreamSpec instance with 5 levels and standard updates
      */
     pub fn levels_5(symbol: impl Into<String>) -> Self {
-        Self::new(symbol, 5)
+        Self::new(symbol, 5, UpdateSpeed::Standard)
     }
 
     /**
@@ -80,7 +77,7 @@ impl PartialBookDepthStreamSpec {
      * - New PartialBookDepthStreamSpec instance with 10 levels and standard updates
      */
     pub fn levels_10(symbol: impl Into<String>) -> Self {
-        Self::new(symbol, 10)
+        Self::new(symbol, 10, UpdateSpeed::Standard)
     }
 
     /**
@@ -93,7 +90,7 @@ impl PartialBookDepthStreamSpec {
      * - New PartialBookDepthStreamSpec instance with 20 levels and standard updates
      */
     pub fn levels_20(symbol: impl Into<String>) -> Self {
-        Self::new(symbol, 20)
+        Self::new(symbol, 20, UpdateSpeed::Standard)
     }
 
     /**
@@ -183,3 +180,45 @@ impl StreamSpec for PartialBookDepthStreamSpec {
         config.partial_book_depth_buffer_size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests the stream name generated for every level and update speed combination.
+     */
+    #[test]
+    fn test_stream_names() {
+        // Arrange
+        let cases = [
+            (5, UpdateSpeed::Standard, "btcusdt@depth5"),
+            (10, UpdateSpeed::Standard, "btcusdt@depth10"),
+            (20, UpdateSpeed::Standard, "btcusdt@depth20"),
+            (5, UpdateSpeed::Fast100ms, "btcusdt@depth5@100ms"),
+            (10, UpdateSpeed::Fast100ms, "btcusdt@depth10@100ms"),
+            (20, UpdateSpeed::Fast100ms, "btcusdt@depth20@100ms"),
+        ];
+
+        for (levels, update_speed, expected) in cases {
+            // Act
+            let spec = PartialBookDepthStreamSpec::new("BTCUSDT", levels, update_speed);
+
+            // Assert
+            assert!(spec.validate().is_ok());
+            assert_eq!(spec.stream_name(), expected);
+        }
+    }
+
+    /**
+     * Tests that an unsupported level count is rejected.
+     */
+    #[test]
+    fn test_invalid_levels_rejected() {
+        // Act
+        let spec = PartialBookDepthStreamSpec::new("BTCUSDT", 15, UpdateSpeed::Fast100ms);
+
+        // Assert
+        assert!(spec.validate().is_err());
+    }
+}
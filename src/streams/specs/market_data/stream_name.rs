@@ -0,0 +1,337 @@
+use super::interval::Interval;
+use super::timezone_offset::TimezoneOffset;
+use super::update_speed::UpdateSpeed;
+use super::window_size::WindowSize;
+
+/**
+ * Parsed Binance market data stream name
+ *
+ * Decomposes a stream name such as `btcusdt@kline_1m` or `btcusdt@depth5@100ms`
+ * into its symbol, stream kind and parameters, mirroring the names produced by
+ * the market data stream specs. Symbols are kept in the lowercase form used on the wire.
+ *
+ * # Variants:
+ * - `AggregateTrade`: `<symbol>@aggTrade`
+ * - `Trade`: `<symbol>@trade`
+ * - `Kline`: `<symbol>@kline_<interval>` with an optional `@<timezone_offset>`
+ * - `MiniTicker`: `<symbol>@miniTicker`
+ * - `AllMiniTickers`: `!miniTicker@arr`
+ * - `Ticker`: `<symbol>@ticker`
+ * - `AllTickers`: `!ticker@arr`
+ * - `RollingWindowTicker`: `<symbol>@ticker_<window>`
+ * - `AllRollingWindowTickers`: `!ticker_<window>@arr`
+ * - `BookTicker`: `<symbol>@bookTicker`
+ * - `AveragePrice`: `<symbol>@avgPrice`
+ * - `PartialBookDepth`: `<symbol>@depth<levels>` with an optional `@100ms`
+ * - `DiffDepth`: `<symbol>@depth` with an optional `@100ms`
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamName {
+    AggregateTrade {
+        symbol: String,
+    },
+    Trade {
+        symbol: String,
+    },
+    Kline {
+        symbol: String,
+        interval: Interval,
+        timezone_offset: Option<TimezoneOffset>,
+    },
+    MiniTicker {
+        symbol: String,
+    },
+    AllMiniTickers,
+    Ticker {
+        symbol: String,
+    },
+    AllTickers,
+    RollingWindowTicker {
+        symbol: String,
+        window: WindowSize,
+    },
+    AllRollingWindowTickers {
+        window: WindowSize,
+    },
+    BookTicker {
+        symbol: String,
+    },
+    AveragePrice {
+        symbol: String,
+    },
+    PartialBookDepth {
+        symbol: String,
+        levels: u8,
+        update_speed: UpdateSpeed,
+    },
+    DiffDepth {
+        symbol: String,
+        update_speed: UpdateSpeed,
+    },
+}
+
+impl StreamName {
+    /**
+     * Parses a Binance market data stream name
+     *
+     * # Arguments
+     * - `name` - Stream name as sent in SUBSCRIBE requests and combined stream frames
+     *
+     * # Returns
+     * - Parsed stream name, or None if the name is not a known market data stream
+     */
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "!miniTicker@arr" => return Some(Self::AllMiniTickers),
+            "!ticker@arr" => return Some(Self::AllTickers),
+            _ => {}
+        }
+
+        if let Some(window) = name
+            .strip_prefix("!ticker_")
+            .and_then(|rest| rest.strip_suffix("@arr"))
+        {
+            return Some(Self::AllRollingWindowTickers {
+                window: WindowSize::parse(window)?,
+            });
+        }
+
+        let mut parts = name.split('@');
+        let symbol = parts.next().filter(|symbol| is_symbol(symbol))?.to_string();
+        let kind = parts.next()?;
+        let modifier = parts.next();
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let stream_name = match (kind, modifier) {
+            ("aggTrade", None) => Self::AggregateTrade { symbol },
+            ("trade", None) => Self::Trade { symbol },
+            ("miniTicker", None) => Self::MiniTicker { symbol },
+            ("ticker", None) => Self::Ticker { symbol },
+            ("bookTicker", None) => Self::BookTicker { symbol },
+            ("avgPrice", None) => Self::AveragePrice { symbol },
+            ("depth", modifier) => Self::DiffDepth {
+                symbol,
+                update_speed: parse_update_speed(modifier)?,
+            },
+            (kind, modifier) if kind.starts_with("kline_") => Self::Kline {
+                symbol,
+                interval: Interval::parse(&kind["kline_".len()..])?,
+                timezone_offset: match modifier {
+                    Some(offset) => Some(TimezoneOffset::parse(offset)?),
+                    None => None,
+                },
+            },
+            (kind, None) if kind.starts_with("ticker_") => Self::RollingWindowTicker {
+                symbol,
+                window: WindowSize::parse(&kind["ticker_".len()..])?,
+            },
+            (kind, modifier) if kind.starts_with("depth") => Self::PartialBookDepth {
+                symbol,
+                levels: kind["depth".len()..]
+                    .parse()
+                    .ok()
+                    .filter(|levels| matches!(levels, 5 | 10 | 20))?,
+                update_speed: parse_update_speed(modifier)?,
+            },
+            _ => return None,
+        };
+
+        Some(stream_name)
+    }
+
+    /**
+     * Gets the symbol the stream is for
+     *
+     * # Returns
+     * - Lowercase symbol, or None for all-market streams
+     */
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            Self::AggregateTrade { symbol }
+            | Self::Trade { symbol }
+            | Self::Kline { symbol, .. }
+            | Self::MiniTicker { symbol }
+            | Self::Ticker { symbol }
+            | Self::RollingWindowTicker { symbol, .. }
+            | Self::BookTicker { symbol }
+            | Self::AveragePrice { symbol }
+            | Self::PartialBookDepth { symbol, .. }
+            | Self::DiffDepth { symbol, .. } => Some(symbol),
+            Self::AllMiniTickers | Self::AllTickers | Self::AllRollingWindowTickers { .. } => None,
+        }
+    }
+}
+
+/**
+ * Checks that a stream name symbol is non-empty lowercase alphanumeric
+ */
+fn is_symbol(symbol: &str) -> bool {
+    !symbol.is_empty()
+        && symbol
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+/**
+ * Parses the optional update speed suffix of a depth stream
+ */
+fn parse_update_speed(modifier: Option<&str>) -> Option<UpdateSpeed> {
+    match modifier {
+        None => Some(UpdateSpeed::Standard),
+        Some("100ms") => Some(UpdateSpeed::Fast100ms),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::specs::{
+        KlineWithTimezoneStreamSpec, PartialBookDepthStreamSpec, StreamSpec,
+    };
+
+    /**
+     * Tests parsing of symbol streams with and without parameters
+     */
+    #[test]
+    fn test_parse_symbol_streams() {
+        // Arrange
+        let cases = [
+            (
+                "btcusdt@aggTrade",
+                StreamName::AggregateTrade {
+                    symbol: "btcusdt".to_string(),
+                },
+            ),
+            (
+                "btcusdt@kline_1m",
+                StreamName::Kline {
+                    symbol: "btcusdt".to_string(),
+                    interval: Interval::OneMinute,
+                    timezone_offset: None,
+                },
+            ),
+            (
+                "bnbbtc@kline_1M@+08:00",
+                StreamName::Kline {
+                    symbol: "bnbbtc".to_string(),
+                    interval: Interval::OneMonth,
+                    timezone_offset: Some(TimezoneOffset::UtcPlus8),
+                },
+            ),
+            (
+                "ethusdt@ticker_4h",
+                StreamName::RollingWindowTicker {
+                    symbol: "ethusdt".to_string(),
+                    window: WindowSize::FourHours,
+                },
+            ),
+            (
+                "!ticker_1d@arr",
+                StreamName::AllRollingWindowTickers {
+                    window: WindowSize::OneDay,
+                },
+            ),
+            ("!miniTicker@arr", StreamName::AllMiniTickers),
+        ];
+
+        // Act & Assert
+        for (name, expected) in cases {
+            assert_eq!(StreamName::parse(name), Some(expected), "{name}");
+        }
+    }
+
+    /**
+     * Tests parsing of depth streams with levels and update speed
+     */
+    #[test]
+    fn test_parse_depth_streams() {
+        // Arrange
+        let cases = [
+            ("btcusdt@depth", None, UpdateSpeed::Standard),
+            ("btcusdt@depth@100ms", None, UpdateSpeed::Fast100ms),
+            ("btcusdt@depth5", Some(5), UpdateSpeed::Standard),
+            ("btcusdt@depth20@100ms", Some(20), UpdateSpeed::Fast100ms),
+        ];
+
+        // Act & Assert
+        for (name, levels, update_speed) in cases {
+            let symbol = "btcusdt".to_string();
+            let expected = match levels {
+                Some(levels) => StreamName::PartialBookDepth {
+                    symbol,
+                    levels,
+                    update_speed,
+                },
+                None => StreamName::DiffDepth {
+                    symbol,
+                    update_speed,
+                },
+            };
+            assert_eq!(StreamName::parse(name), Some(expected), "{name}");
+        }
+    }
+
+    /**
+     * Tests that malformed and unknown stream names are rejected
+     */
+    #[test]
+    fn test_parse_invalid_streams() {
+        // Arrange
+        let names = [
+            "",
+            "btcusdt",
+            "BTCUSDT@trade",
+            "btcusdt@depth7",
+            "btcusdt@depth5@250ms",
+            "btcusdt@kline_2w",
+            "btcusdt@trade@100ms",
+            "btcusdt@unknown",
+            "!ticker_2h@arr",
+        ];
+
+        // Act & Assert
+        for name in names {
+            assert_eq!(StreamName::parse(name), None, "{name}");
+        }
+    }
+
+    /**
+     * Tests that names generated by the stream specs parse back to matching parameters
+     */
+    #[test]
+    fn test_parse_spec_stream_names() {
+        // Arrange
+        let kline = KlineWithTimezoneStreamSpec::new(
+            "BTCUSDT",
+            Interval::FifteenMinutes,
+            TimezoneOffset::UtcPlus8,
+        );
+        let depth = PartialBookDepthStreamSpec::new("BTCUSDT", 10, UpdateSpeed::Fast100ms);
+
+        // Act
+        let kline_name = StreamName::parse(&kline.stream_name()).unwrap();
+        let depth_name = StreamName::parse(&depth.stream_name()).unwrap();
+
+        // Assert
+        assert_eq!(
+            kline_name,
+            StreamName::Kline {
+                symbol: "btcusdt".to_string(),
+                interval: Interval::FifteenMinutes,
+                timezone_offset: Some(TimezoneOffset::UtcPlus8),
+            }
+        );
+        assert_eq!(depth_name.symbol(), Some("btcusdt"));
+        assert!(matches!(
+            depth_name,
+            StreamName::PartialBookDepth {
+                levels: 10,
+                update_speed: UpdateSpeed::Fast100ms,
+                ..
+            }
+        ));
+    }
+}
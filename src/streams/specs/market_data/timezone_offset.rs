@@ -2,9 +2,10 @@
  * Timezone offset for Binance kline streams
  *
  * Represents supported timezone offsets for kline stream boundaries.
- * Currently only UTC+8 is supported by Binance.
+ * Currently only UTC+8 is supported by Binance. Also accepted by the REST
+ * klines request through `KlinesSpec::with_timezone`.
  */
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TimezoneOffset {
     UtcPlus8,
 }
@@ -21,6 +22,22 @@ impl TimezoneOffset {
             Self::UtcPlus8 => "+08:00",
         }
     }
+
+    /**
+     * Parses the string representation of the timezone offset
+     *
+     * # Arguments
+     * - `value` - String representation used in stream names
+     *
+     * # Returns
+     * - Matching timezone offset, or None if the value is not supported
+     */
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "+08:00" => Some(Self::UtcPlus8),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for TimezoneOffset {
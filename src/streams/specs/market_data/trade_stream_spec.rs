@@ -71,3 +71,20 @@ impl StreamSpec for TradeStreamSpec {
         config.trade_buffer_size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests that stream names keep the symbol lowercase regardless of input case.
+     */
+    #[test]
+    fn test_stream_name_is_lowercase() {
+        // Act
+        let spec = TradeStreamSpec::new("BTCUSDT");
+
+        // Assert
+        assert_eq!(spec.stream_name(), "btcusdt@trade");
+    }
+}
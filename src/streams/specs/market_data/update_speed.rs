@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 /**
  * Update speed for Binance WebSocket streams
  *
  * Represents the available update frequencies for streams that support multiple speeds.
  */
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UpdateSpeed {
     Standard,
     Fast100ms,
@@ -23,4 +25,17 @@ impl UpdateSpeed {
             Self::Fast100ms => "100ms",
         }
     }
+
+    /**
+     * Returns the interval between consecutive stream updates
+     *
+     * # Returns
+     * - Duration between pushes at this update speed
+     */
+    pub fn interval(&self) -> Duration {
+        match self {
+            Self::Standard => Duration::from_millis(1000),
+            Self::Fast100ms => Duration::from_millis(100),
+        }
+    }
 }
@@ -4,7 +4,7 @@
  * Represents the supported rolling window durations for ticker statistics.
  * Used in rolling window ticker streams to specify the time period for calculations.
  */
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WindowSize {
     OneHour,
     FourHours,
@@ -25,6 +25,24 @@ impl WindowSize {
             Self::OneDay => "1d",
         }
     }
+
+    /**
+     * Parses the string representation of the window size
+     *
+     * # Arguments
+     * - `value` - String representation used in stream names
+     *
+     * # Returns
+     * - Matching window size, or None if the value is not supported
+     */
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "1h" => Some(Self::OneHour),
+            "4h" => Some(Self::FourHours),
+            "1d" => Some(Self::OneDay),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for WindowSize {
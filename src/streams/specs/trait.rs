@@ -1,6 +1,7 @@
 use serde::de::DeserializeOwned;
 
 use crate::StreamConfig;
+use crate::streams::events::NormalizeDecimals;
 
 /**
  * Stream Specification Trait
@@ -14,8 +15,9 @@ pub trait StreamSpec {
      * Associated event type for this stream
      *
      * Must be deserializable, sendable between threads, cloneable, and have static lifetime.
+     * Decimal normalization lets `with_normalize_decimals` rewrite its decimal fields.
      */
-    type Event: DeserializeOwned + Send + Clone + 'static;
+    type Event: DeserializeOwned + NormalizeDecimals + Send + Clone + 'static;
 
     /**
      * Generates the WebSocket stream name for subscription
@@ -0,0 +1,57 @@
+use serde_json::{Value, json};
+
+use super::events::DiffDepthStreamEvent;
+
+/**
+ * Builds an execution report payload for a new LIMIT buy order.
+ *
+ * # Arguments
+ * - `overrides`: Fields replacing or adding to the defaults, keyed by wire name.
+ *
+ * # Returns
+ * - `Value`: Execution report JSON.
+ */
+pub(crate) fn execution_report(overrides: Value) -> Value {
+    let mut report = json!({
+        "e": "executionReport", "E": 1000, "s": "BNBBTC",
+        "c": "client", "S": "BUY", "o": "LIMIT", "f": "GTC",
+        "q": "1.00000000", "p": "0.00100000", "P": "0.00000000", "F": "0.00000000",
+        "g": -1, "C": "", "x": "NEW", "X": "NEW", "r": "NONE",
+        "i": 1, "l": "0.00000000", "z": "0.00000000", "L": "0.00000000",
+        "n": "0", "N": null, "T": 1000, "t": -1, "I": 1,
+        "w": true, "m": false, "M": false, "O": 1000, "Z": "0.00000000",
+        "Y": "0.00000000", "Q": "0.00000000", "W": 1000, "V": "NONE"
+    });
+    for (key, value) in overrides.as_object().expect("overrides must be an object") {
+        report[key] = value.clone();
+    }
+    report
+}
+
+/**
+ * Builds a diff depth event.
+ *
+ * # Arguments
+ * - `symbol`: Symbol of the update.
+ * - `first_update_id`: First update ID in the event.
+ * - `final_update_id`: Final update ID in the event.
+ * - `bids`: Bid price and quantity levels.
+ * - `asks`: Ask price and quantity levels.
+ *
+ * # Returns
+ * - `DiffDepthStreamEvent`: Parsed diff depth event.
+ */
+pub(crate) fn diff_depth(
+    symbol: &str,
+    first_update_id: u64,
+    final_update_id: u64,
+    bids: &[(&str, &str)],
+    asks: &[(&str, &str)],
+) -> DiffDepthStreamEvent {
+    serde_json::from_value(json!({
+        "e": "depthUpdate", "E": 1, "s": symbol,
+        "U": first_update_id, "u": final_update_id,
+        "b": bids, "a": asks
+    }))
+    .unwrap()
+}
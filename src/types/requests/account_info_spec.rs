@@ -0,0 +1,140 @@
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
+use crate::types::requests::{Unvalidated, Validated};
+
+/**
+ * Account information query specification.
+ *
+ * This specification handles parameters for querying the account's
+ * commissions, permissions and balances.
+ *
+ * # Fields
+ * - `omit_zero_balances`: Optional flag to leave out balances that are zero.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountInfoSpec<S = Unvalidated> {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "omitZeroBalances")]
+    pub omit_zero_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "recvWindow")]
+    pub recv_window: Option<u64>,
+    #[serde(skip)]
+    _state: PhantomData<S>,
+}
+
+impl AccountInfoSpec<Unvalidated> {
+    /**
+     * Creates a new account information specification.
+     *
+     * # Returns
+     * - `Self`: New account information specification.
+     */
+    pub fn new() -> Self {
+        Self {
+            omit_zero_balances: None,
+            recv_window: None,
+            _state: PhantomData,
+        }
+    }
+
+    /**
+     * Sets whether zero balances are left out of the response.
+     *
+     * Binance includes every asset by default; omitting zero balances
+     * server-side keeps the payload small for accounts with many assets.
+     *
+     * # Arguments
+     * - `omit_zero_balances`: True to only return non-zero balances.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_omit_zero_balances(mut self, omit_zero_balances: bool) -> Self {
+        self.omit_zero_balances = Some(omit_zero_balances);
+        self
+    }
+
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
+    /**
+     * Builds the account information specification.
+     *
+     * # Returns
+     * - `AccountInfoSpec<Validated>`: Validated specification or error if validation fails.
+     */
+    pub fn build(self) -> Result<AccountInfoSpec<Validated>> {
+        self.validate()
+            .context("Failed to validate AccountInfoSpecification")?;
+
+        Ok(AccountInfoSpec {
+            omit_zero_balances: self.omit_zero_balances,
+            recv_window: self.recv_window,
+            _state: PhantomData::<Validated>,
+        })
+    }
+
+    /**
+     * Validates the account information specification parameters.
+     *
+     * # Returns
+     * - `()`: Ok if valid, error if invalid parameters.
+     */
+    fn validate(&self) -> Result<()> {
+        validate_recv_window(self.recv_window)?;
+
+        Ok(())
+    }
+}
+
+impl Default for AccountInfoSpec<Unvalidated> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::requests::RequestSpec;
+
+    /**
+     * Tests that omitZeroBalances is only serialized when set.
+     */
+    #[test]
+    fn test_with_omit_zero_balances_serialization() {
+        // Arrange
+        let omitted = AccountInfoSpec::new()
+            .with_omit_zero_balances(true)
+            .build()
+            .unwrap();
+        let default = AccountInfoSpec::new().build().unwrap();
+
+        // Act
+        let omitted_query = serde_urlencoded::to_string(&omitted).unwrap();
+        let default_params = default.to_params().unwrap();
+
+        // Assert
+        assert_eq!(omitted_query, "omitZeroBalances=true");
+        assert!(default_params.is_empty());
+    }
+}
@@ -50,7 +50,7 @@ impl AggregateTradesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             from_id: None,
             start_time: None,
             end_time: None,
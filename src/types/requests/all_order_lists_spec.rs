@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -17,6 +19,7 @@ use crate::{
  * - `start_time`: Optional timestamp to start from.
  * - `end_time`: Optional timestamp to end at.
  * - `limit`: Optional number of results to return (default 500, max 1000).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +28,7 @@ pub struct AllOrderListsSpec<S = Unvalidated> {
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
     pub limit: Option<u32>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -42,6 +46,7 @@ impl AllOrderListsSpec<Unvalidated> {
             start_time: None,
             end_time: None,
             limit: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -102,6 +107,22 @@ impl AllOrderListsSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the all order lists specification.
      *
@@ -117,6 +138,7 @@ impl AllOrderListsSpec<Unvalidated> {
             start_time: self.start_time,
             end_time: self.end_time,
             limit: self.limit,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -158,6 +180,8 @@ impl AllOrderListsSpec<Unvalidated> {
             .into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
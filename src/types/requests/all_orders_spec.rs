@@ -1,10 +1,12 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
 use crate::errors::InvalidParameter;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::types::requests::{Unvalidated, Validated};
 
 /**
@@ -19,6 +21,7 @@ use crate::types::requests::{Unvalidated, Validated};
  * - `start_time`: Optional start time filter in milliseconds.
  * - `end_time`: Optional end time filter in milliseconds.
  * - `limit`: Optional limit (default: 500, max: 1000).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct AllOrdersSpec<S = Unvalidated> {
@@ -31,6 +34,8 @@ pub struct AllOrdersSpec<S = Unvalidated> {
     pub end_time: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "recvWindow")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -47,11 +52,12 @@ impl AllOrdersSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_id: None,
             start_time: None,
             end_time: None,
             limit: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -112,6 +118,22 @@ impl AllOrdersSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the all orders specification.
      *
@@ -128,6 +150,7 @@ impl AllOrdersSpec<Unvalidated> {
             start_time: self.start_time,
             end_time: self.end_time,
             limit: self.limit,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -167,6 +190,8 @@ impl AllOrdersSpec<Unvalidated> {
             }
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -22,6 +24,7 @@ use crate::{
  * - `from_allocation_id`: Optional pagination starting point.
  * - `limit`: Optional limit for results (default: 500, max: 1000).
  * - `order_id`: Optional order ID to filter allocations.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +38,7 @@ pub struct AllocationSpec<S = Unvalidated> {
     pub from_allocation_id: Option<u32>,
     pub limit: Option<u32>,
     pub order_id: Option<u64>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -51,12 +55,13 @@ impl AllocationSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             start_time: None,
             end_time: None,
             from_allocation_id: None,
             limit: None,
             order_id: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -131,6 +136,22 @@ impl AllocationSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the allocation specification.
      *
@@ -147,6 +168,7 @@ impl AllocationSpec<Unvalidated> {
             from_allocation_id: self.from_allocation_id,
             limit: self.limit,
             order_id: self.order_id,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -186,6 +208,8 @@ impl AllocationSpec<Unvalidated> {
             }
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -1,10 +1,12 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use rust_decimal::Decimal;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -23,6 +25,7 @@ use crate::{
  * - `orig_client_order_id`: Optional original client order ID to amend (mutually exclusive with orderId).
  * - `new_client_order_id`: Optional new client order ID for the amended order.
  * - `new_quantity`: New quantity to set for the order (must be greater than 0 and less than the original order quantity).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +38,7 @@ pub struct AmendOrderSpec<S = Unvalidated> {
     #[serde(with = "rust_decimal::serde::str")]
     #[serde(rename = "newQty")]
     pub new_quantity: Decimal,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -52,11 +56,12 @@ impl AmendOrderSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>, new_quantity: Decimal) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_id: None,
             original_client_order_id: None,
             new_client_order_id: None,
             new_quantity,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -103,6 +108,22 @@ impl AmendOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the amend order specification.
      *
@@ -119,6 +140,7 @@ impl AmendOrderSpec<Unvalidated> {
             original_client_order_id: self.original_client_order_id,
             new_client_order_id: self.new_client_order_id,
             new_quantity: self.new_quantity,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -158,6 +180,8 @@ impl AmendOrderSpec<Unvalidated> {
             return Err(InvalidParameter::new("new_quantity", "must be greater than 0").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -37,7 +37,7 @@ impl AveragePriceSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             _state: PhantomData,
         }
     }
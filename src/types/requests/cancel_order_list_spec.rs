@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -17,6 +19,7 @@ use crate::{
  * - `order_list_id`: Optional order list ID to cancel (mutually exclusive with list_client_order_id).
  * - `list_client_order_id`: Optional client-specified order list identifier (mutually exclusive with order_list_id).
  * - `new_client_order_id`: Optional new client order ID used to uniquely identify this cancel operation.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +28,7 @@ pub struct CancelOrderListSpec<S = Unvalidated> {
     pub order_list_id: Option<u64>,
     pub list_client_order_id: Option<String>,
     pub new_client_order_id: Option<String>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -41,10 +45,11 @@ impl CancelOrderListSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_list_id: None,
             list_client_order_id: None,
             new_client_order_id: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -91,6 +96,22 @@ impl CancelOrderListSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the cancel order list specification.
      *
@@ -106,6 +127,7 @@ impl CancelOrderListSpec<Unvalidated> {
             order_list_id: self.order_list_id,
             list_client_order_id: self.list_client_order_id,
             new_client_order_id: self.new_client_order_id,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -145,6 +167,8 @@ impl CancelOrderListSpec<Unvalidated> {
             return Err(InvalidParameter::empty("new_client_order_id").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     enums::CancelRestrictions,
     errors::InvalidParameter,
@@ -19,6 +21,7 @@ use crate::{
  * - `original_client_order_id`: Optional original client order ID to cancel.
  * - `new_client_order_id`: Optional new client order ID to assign to the cancellation.
  * - `cancel_restrictions`: Optional restrictions on the cancellation.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -29,6 +32,7 @@ pub struct CancelOrderSpec<S = Unvalidated> {
     pub original_client_order_id: Option<String>,
     pub new_client_order_id: Option<String>,
     pub cancel_restrictions: Option<CancelRestrictions>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -45,11 +49,12 @@ impl CancelOrderSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_id: None,
             original_client_order_id: None,
             new_client_order_id: None,
             cancel_restrictions: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -111,6 +116,22 @@ impl CancelOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the cancel order specification.
      *
@@ -127,6 +148,7 @@ impl CancelOrderSpec<Unvalidated> {
             original_client_order_id: self.original_client_order_id,
             new_client_order_id: self.new_client_order_id,
             cancel_restrictions: self.cancel_restrictions,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -150,6 +172,8 @@ impl CancelOrderSpec<Unvalidated> {
             return Err(InvalidParameter::required("orderId or origClientOrderId").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
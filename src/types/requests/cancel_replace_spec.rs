@@ -1,10 +1,13 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use rust_decimal::Decimal;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
+use crate::types::requests::required_fields::OrderFields;
 use crate::{
     enums::{
         CancelReplaceMode, CancelRestrictions, OrderRateLimitExceededMode, OrderResponseType,
@@ -42,6 +45,7 @@ use crate::{
  * - `iceberg_quantity`: Optional iceberg quantity for the new order.
  * - `new_order_response_type`: Optional response type for the new order.
  * - `self_trade_prevention_mode`: Optional self-trade prevention mode for the new order.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -87,6 +91,8 @@ pub struct CancelReplaceSpec<S = Unvalidated> {
     #[serde(rename = "newOrderRespType")]
     pub new_order_response_type: Option<OrderResponseType>,
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -111,7 +117,7 @@ impl CancelReplaceSpec<Unvalidated> {
         order_type: OrderType,
     ) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             cancel_replace_mode,
             cancel_order_id: None,
             cancel_origin_client_order_id: None,
@@ -132,6 +138,7 @@ impl CancelReplaceSpec<Unvalidated> {
             iceberg_quantity: None,
             new_order_response_type: None,
             self_trade_prevention_mode: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -378,6 +385,22 @@ impl CancelReplaceSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the cancel-replace specification.
      *
@@ -410,6 +433,7 @@ impl CancelReplaceSpec<Unvalidated> {
             iceberg_quantity: self.iceberg_quantity,
             new_order_response_type: self.new_order_response_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -488,6 +512,8 @@ impl CancelReplaceSpec<Unvalidated> {
 
         self.validate_new_order_parameters()?;
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 
@@ -498,89 +524,72 @@ impl CancelReplaceSpec<Unvalidated> {
      * - `()`: Ok if valid, error if invalid parameters.
      */
     fn validate_new_order_parameters(&self) -> Result<()> {
-        use OrderType::*;
-
-        match self.order_type {
-            Limit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::required("time_in_force").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::required("price").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-            }
-            LimitMaker => {
-                if self.price.is_none() {
-                    return Err(InvalidParameter::required("price").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-            }
-            Market => {
-                if self.quantity.is_none() && self.quote_order_quantity.is_none() {
-                    return Err(
-                        InvalidParameter::required("quantity or quote_order_quantity").into(),
-                    );
-                }
-                if self.quantity.is_some() && self.quote_order_quantity.is_some() {
-                    return Err(InvalidParameter::mutually_exclusive(
-                        "quantity",
-                        "quote_order_quantity",
-                    )
-                    .into());
-                }
-            }
-            StopLoss => {
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::required("stop_price or trailing_delta").into());
-                }
-            }
-            StopLossLimit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::required("time_in_force").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::required("price").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::required("stop_price or trailing_delta").into());
-                }
-            }
-            TakeProfit => {
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::required("stop_price or trailing_delta").into());
-                }
-            }
-            TakeProfitLimit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::required("time_in_force").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::required("price").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::required("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::required("stop_price or trailing_delta").into());
-                }
-            }
-            _ => {}
+        if let Some(field) = self.order_fields().missing_required(self.order_type) {
+            return Err(InvalidParameter::required(field).into());
+        }
+
+        if matches!(self.order_type, OrderType::Market)
+            && self.quantity.is_some()
+            && self.quote_order_quantity.is_some()
+        {
+            return Err(
+                InvalidParameter::mutually_exclusive("quantity", "quote_order_quantity").into(),
+            );
         }
 
         Ok(())
     }
+
+    /**
+     * Gets which new order parameters are set, for the required field check.
+     *
+     * # Returns
+     * - `OrderFields`: Presence of each parameter an order type may require.
+     */
+    fn order_fields(&self) -> OrderFields {
+        OrderFields {
+            time_in_force: self.time_in_force.is_some(),
+            quantity: self.quantity.is_some(),
+            quote_order_quantity: self.quote_order_quantity.is_some(),
+            price: self.price.is_some(),
+            stop_price: self.stop_price.is_some(),
+            trailing_delta: self.trailing_delta.is_some(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that the new order response type is serialized as newOrderRespType.
+     */
+    #[test]
+    fn test_new_order_response_type_serialization() {
+        // Arrange
+        let specification = CancelReplaceSpec::new(
+            "BTCUSDT",
+            CancelReplaceMode::StopOnFailure,
+            OrderSide::Buy,
+            OrderType::Limit,
+        )
+        .with_cancel_order_id(9)
+        .with_time_in_force(TimeInForce::GTC)
+        .with_quantity(dec!(0.001))
+        .with_price(dec!(30000))
+        .with_new_order_response_type(OrderResponseType::Full)
+        .build()
+        .unwrap();
+
+        // Act
+        let query = serde_urlencoded::to_string(&specification).unwrap();
+
+        // Assert
+        assert!(query.contains("cancelReplaceMode=STOP_ON_FAILURE"));
+        assert!(query.contains("cancelOrderId=9"));
+        assert!(query.contains("newOrderRespType=FULL"));
+    }
 }
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -17,10 +19,13 @@ use crate::{
  *
  * # Fields
  * - `symbol`: Trading symbol to get commission rates for.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct CommissionRatesSpec<S = Unvalidated> {
     pub symbol: String,
+    #[serde(rename = "recvWindow")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -37,11 +42,28 @@ impl CommissionRatesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
+            recv_window: None,
             _state: PhantomData,
         }
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the commission rates specification.
      *
@@ -54,6 +76,7 @@ impl CommissionRatesSpec<Unvalidated> {
 
         Ok(CommissionRatesSpec {
             symbol: self.symbol,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -69,6 +92,8 @@ impl CommissionRatesSpec<Unvalidated> {
             return Err(InvalidParameter::empty("symbol").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
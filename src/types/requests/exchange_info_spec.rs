@@ -66,7 +66,7 @@ impl ExchangeInfoSpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
-        self.symbol = Some(symbol.into());
+        self.symbol = Some(symbol.into().to_uppercase());
         self
     }
 
@@ -80,7 +80,10 @@ impl ExchangeInfoSpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbols(mut self, symbols: Vec<impl Into<String>>) -> Self {
-        let symbol_strings: Vec<String> = symbols.into_iter().map(|s| s.into()).collect();
+        let symbol_strings: Vec<String> = symbols
+            .into_iter()
+            .map(|s| s.into().to_uppercase())
+            .collect();
         self.symbols = Some(serde_json::to_string(&symbol_strings).unwrap());
         self
     }
@@ -43,7 +43,7 @@ impl HistoricalTradesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             limit: None,
             from_id: None,
             _state: PhantomData,
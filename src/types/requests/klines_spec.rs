@@ -6,6 +6,7 @@ use serde::Serialize;
 use crate::Result;
 use crate::{
     errors::InvalidParameter,
+    streams::specs::TimezoneOffset,
     types::requests::{Unvalidated, Validated},
 };
 
@@ -53,7 +54,7 @@ impl KlinesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>, interval: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             interval: interval.into(),
             start_time: None,
             end_time: None,
@@ -105,11 +106,26 @@ impl KlinesSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the timezone for klines query from a stream timezone offset.
+     *
+     * # Arguments
+     * - `timezone`: Timezone offset shared with `KlineWithTimezoneStreamSpec`.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_timezone(self, timezone: TimezoneOffset) -> Self {
+        self.with_time_zone(timezone.as_str())
+    }
+
     /**
      * Sets the limit for number of klines to return.
      *
+     * When unset the parameter is omitted and Binance returns 500 klines.
+     *
      * # Arguments
-     * - `limit`: Number of klines to return (max: 1000).
+     * - `limit`: Number of klines to return (1-1000).
      *
      * # Returns
      * - `Self`: Updated specification.
@@ -166,7 +182,7 @@ impl KlinesSpec<Unvalidated> {
         }
 
         if let Some(limit) = self.limit
-            && limit > 1000
+            && !(1..=1000).contains(&limit)
         {
             return Err(InvalidParameter::range("limit", 1, 1000).into());
         }
@@ -253,3 +269,77 @@ impl KlinesSpec<Unvalidated> {
         .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests that a timezone offset is serialized as the timeZone parameter.
+     */
+    #[test]
+    fn test_with_timezone_serialization() {
+        // Arrange
+        let spec = KlinesSpec::new("BNBBTC", "1d")
+            .with_timezone(TimezoneOffset::UtcPlus8)
+            .build()
+            .unwrap();
+
+        // Act
+        let query = serde_urlencoded::to_string(&spec).unwrap();
+
+        // Assert
+        assert_eq!(query, "symbol=BNBBTC&interval=1d&timeZone=%2B08%3A00");
+    }
+
+    /**
+     * Tests that the timeZone parameter is omitted when not set.
+     */
+    #[test]
+    fn test_timezone_omitted_by_default() {
+        // Arrange
+        let spec = KlinesSpec::new("BNBBTC", "1d").build().unwrap();
+
+        // Act
+        let query = serde_urlencoded::to_string(&spec).unwrap();
+
+        // Assert
+        assert_eq!(query, "symbol=BNBBTC&interval=1d");
+    }
+
+    /**
+     * Tests that limits within 1-1000, including the boundary, are accepted and serialized.
+     */
+    #[test]
+    fn test_with_limit_accepts_valid_values() {
+        for limit in [500, 1000] {
+            // Act
+            let spec = KlinesSpec::new("BNBBTC", "1m")
+                .with_limit(limit)
+                .build()
+                .unwrap();
+            let query = serde_urlencoded::to_string(&spec).unwrap();
+
+            // Assert
+            assert_eq!(query, format!("symbol=BNBBTC&interval=1m&limit={}", limit));
+        }
+    }
+
+    /**
+     * Tests that limits outside 1-1000 are rejected with a limit parameter error.
+     */
+    #[test]
+    fn test_with_limit_rejects_out_of_range() {
+        for limit in [0, 1001] {
+            // Act
+            let error = KlinesSpec::new("BNBBTC", "1m")
+                .with_limit(limit)
+                .build()
+                .unwrap_err();
+
+            // Assert
+            let parameter = error.downcast_ref::<InvalidParameter>().unwrap();
+            assert_eq!(parameter.param, "limit");
+        }
+    }
+}
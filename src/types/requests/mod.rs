@@ -1,3 +1,4 @@
+mod account_info_spec;
 mod aggregate_trades_spec;
 mod all_order_lists_spec;
 mod all_orders_spec;
@@ -17,7 +18,9 @@ mod oco_order_spec;
 mod open_order_lists_spec;
 mod open_orders_spec;
 mod order_book_spec;
+mod order_leg_preview;
 mod order_list_status_spec;
+mod order_response_default;
 mod order_spec;
 mod oto_order_spec;
 mod otoco_order_spec;
@@ -25,6 +28,9 @@ mod phantom_data;
 mod prevented_matches_spec;
 mod query_order_spec;
 mod recent_trades_spec;
+mod recv_window;
+mod request_spec;
+mod required_fields;
 mod sor_order_spec;
 mod ticker_24hr_spec;
 mod ticker_book_spec;
@@ -32,6 +38,7 @@ mod ticker_price_spec;
 mod ticker_rolling_window_spec;
 mod ticker_trading_day_spec;
 
+pub use account_info_spec::AccountInfoSpec;
 pub use aggregate_trades_spec::AggregateTradesSpec;
 pub use all_order_lists_spec::AllOrderListsSpec;
 pub use all_orders_spec::AllOrdersSpec;
@@ -51,7 +58,9 @@ pub use oco_order_spec::OcoOrderSpec;
 pub use open_order_lists_spec::OpenOrderListsSpec;
 pub use open_orders_spec::OpenOrdersSpec;
 pub use order_book_spec::OrderBookSpec;
+pub use order_leg_preview::{OrderLegPreview, OrderLegRole};
 pub use order_list_status_spec::OrderListStatusSpec;
+pub(crate) use order_response_default::OrderResponseDefault;
 pub use order_spec::OrderSpec;
 pub use oto_order_spec::OtoOrderSpec;
 pub use otoco_order_spec::OtocoOrderSpec;
@@ -59,6 +68,7 @@ pub use phantom_data::{Unvalidated, Validated};
 pub use prevented_matches_spec::PreventedMatchesSpec;
 pub use query_order_spec::QueryOrderSpec;
 pub use recent_trades_spec::RecentTradesSpec;
+pub use request_spec::RequestSpec;
 pub use sor_order_spec::SorOrderSpec;
 pub use ticker_24hr_spec::Ticker24HrSpec;
 pub use ticker_book_spec::TickerBookSpec;
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -14,6 +16,7 @@ use crate::{
  *
  * This specification handles parameters for querying trade history
  * for a specific account and symbol with various filtering options.
+ * Binance requires the symbol even when trades are filtered by order ID.
  *
  * # Fields
  * - `symbol`: Symbol to retrieve trades for (required).
@@ -22,6 +25,7 @@ use crate::{
  * - `end_time`: Optional end time filter in milliseconds.
  * - `from_id`: Optional trade ID to start from.
  * - `limit`: Optional limit (default: 500, max: 1000).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct MyTradesSpec<S = Unvalidated> {
@@ -36,6 +40,8 @@ pub struct MyTradesSpec<S = Unvalidated> {
     pub from_id: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "recvWindow")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -52,12 +58,13 @@ impl MyTradesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_id: None,
             start_time: None,
             end_time: None,
             from_id: None,
             limit: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -65,6 +72,9 @@ impl MyTradesSpec<Unvalidated> {
     /**
      * Sets the order ID filter.
      *
+     * Returns only the fills of this order. Can be combined with `from_id`,
+     * but not with `start_time` or `end_time`.
+     *
      * # Arguments
      * - `order_id`: Order ID to filter trades.
      *
@@ -132,6 +142,22 @@ impl MyTradesSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the my trades specification.
      *
@@ -149,6 +175,7 @@ impl MyTradesSpec<Unvalidated> {
             end_time: self.end_time,
             from_id: self.from_id,
             limit: self.limit,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -198,6 +225,36 @@ impl MyTradesSpec<Unvalidated> {
             );
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests that an order ID is accepted alone or with a trade ID and rejected with a time range.
+     */
+    #[test]
+    fn test_order_id_combinations() {
+        // Arrange
+        let spec = || MyTradesSpec::new("bnbbtc").with_order_id(100);
+
+        // Act
+        let alone = spec().build();
+        let with_from_id = spec().with_from_id(5).build();
+        let with_start_time = spec().with_start_time(1_000).build();
+        let with_end_time = spec().with_end_time(2_000).build();
+
+        // Assert
+        let alone = alone.unwrap();
+        assert_eq!(alone.symbol, "BNBBTC");
+        assert_eq!(alone.order_id, Some(100));
+        assert!(with_from_id.is_ok());
+        assert!(with_start_time.is_err());
+        assert!(with_end_time.is_err());
+    }
+}
@@ -1,13 +1,15 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     enums::{OrderResponseType, OrderSide, OrderType, SelfTradePreventionMode, TimeInForce},
     errors::InvalidParameter,
-    types::requests::{Unvalidated, Validated},
+    types::requests::{OrderLegPreview, OrderLegRole, Unvalidated, Validated},
 };
 
 /**
@@ -42,6 +44,7 @@ use crate::{
  * - `below_strategy_type`: Optional strategy type for the below order.
  * - `response_type`: Optional response format - ACK, RESULT, or FULL.
  * - `self_trade_prevention_mode`: Optional self-trade prevention mode.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -107,8 +110,11 @@ pub struct OcoOrderSpec<S = Unvalidated> {
     pub below_time_in_force: Option<TimeInForce>,
     pub below_strategy_id: Option<u64>,
     pub below_strategy_type: Option<u32>,
+    #[serde(rename = "newOrderRespType")]
     pub new_order_response_type: Option<OrderResponseType>,
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -135,7 +141,7 @@ impl OcoOrderSpec<Unvalidated> {
         below_type: OrderType,
     ) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             list_client_order_id: None,
             side,
             quantity,
@@ -159,6 +165,7 @@ impl OcoOrderSpec<Unvalidated> {
             below_strategy_type: None,
             new_order_response_type: None,
             self_trade_prevention_mode: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -251,6 +258,22 @@ impl OcoOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Validates and builds the OCO order specification.
      *
@@ -285,6 +308,7 @@ impl OcoOrderSpec<Unvalidated> {
             below_strategy_type: self.below_strategy_type,
             new_order_response_type: self.new_order_response_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -479,6 +503,123 @@ impl OcoOrderSpec<Unvalidated> {
             return Err(InvalidParameter::new("below_strategy_type", "must be >= 1000000").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
+
+impl<S> OcoOrderSpec<S> {
+    /**
+     * Previews the orders this OCO would place.
+     *
+     * # Returns
+     * - `Vec<OrderLegPreview>`: The above order followed by the below order.
+     */
+    pub fn preview_legs(&self) -> Vec<OrderLegPreview> {
+        vec![
+            OrderLegPreview {
+                role: OrderLegRole::Above,
+                side: self.side,
+                order_type: self.above_type,
+                quantity: self.quantity,
+                price: self.above_price,
+                stop_price: self.above_stop_price,
+                trailing_delta: self.above_trailing_delta,
+                time_in_force: self.above_time_in_force,
+                client_order_id: self.above_client_order_id.clone(),
+            },
+            OrderLegPreview {
+                role: OrderLegRole::Below,
+                side: self.side,
+                order_type: self.below_type,
+                quantity: self.quantity,
+                price: self.below_price,
+                stop_price: self.below_stop_price,
+                trailing_delta: self.below_trailing_delta,
+                time_in_force: self.below_time_in_force,
+                client_order_id: self.below_client_order_id.clone(),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that an OCO previews its above and below orders.
+     */
+    #[test]
+    fn test_preview_legs() {
+        // Arrange
+        let spec = OcoOrderSpec::new(
+            "BTCUSDT",
+            OrderSide::Sell,
+            dec!(0.5),
+            OrderType::LimitMaker,
+            OrderType::StopLossLimit,
+        )
+        .with_above_price(dec!(110000))
+        .with_above_client_order_id("take-profit")
+        .with_below_price(dec!(89000))
+        .with_below_stop_price(dec!(90000))
+        .with_below_time_in_force(TimeInForce::GTC)
+        .build()
+        .unwrap();
+
+        // Act
+        let legs = spec.preview_legs();
+
+        // Assert
+        assert_eq!(legs.len(), 2);
+
+        assert_eq!(legs[0].role, OrderLegRole::Above);
+        assert_eq!(legs[0].side, OrderSide::Sell);
+        assert_eq!(legs[0].order_type, OrderType::LimitMaker);
+        assert_eq!(legs[0].quantity, dec!(0.5));
+        assert_eq!(legs[0].price, Some(dec!(110000)));
+        assert_eq!(legs[0].stop_price, None);
+        assert_eq!(legs[0].client_order_id.as_deref(), Some("take-profit"));
+
+        assert_eq!(legs[1].role, OrderLegRole::Below);
+        assert_eq!(legs[1].side, OrderSide::Sell);
+        assert_eq!(legs[1].order_type, OrderType::StopLossLimit);
+        assert_eq!(legs[1].quantity, dec!(0.5));
+        assert_eq!(legs[1].price, Some(dec!(89000)));
+        assert_eq!(legs[1].stop_price, Some(dec!(90000)));
+        assert_eq!(legs[1].time_in_force, Some(TimeInForce::GTC));
+    }
+
+    /**
+     * Tests that the response type is sent under Binance's `newOrderRespType` name.
+     */
+    #[test]
+    fn test_response_type_serialization() {
+        // Arrange
+        let spec = OcoOrderSpec::new(
+            "BTCUSDT",
+            OrderSide::Sell,
+            dec!(0.5),
+            OrderType::LimitMaker,
+            OrderType::StopLossLimit,
+        )
+        .with_above_price(dec!(110000))
+        .with_below_price(dec!(89000))
+        .with_below_stop_price(dec!(90000))
+        .with_below_time_in_force(TimeInForce::GTC)
+        .with_response_type(OrderResponseType::Full)
+        .build()
+        .unwrap();
+
+        // Act
+        let query = serde_urlencoded::to_string(&spec).unwrap();
+
+        // Assert
+        assert!(query.contains("newOrderRespType=FULL"));
+        assert!(!query.contains("newOrderResponseType"));
+    }
+}
@@ -1,8 +1,10 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::types::requests::{Unvalidated, Validated};
 
 /**
@@ -14,6 +16,7 @@ use crate::types::requests::{Unvalidated, Validated};
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenOrderListsSpec<S = Unvalidated> {
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -27,10 +30,27 @@ impl OpenOrderListsSpec<Unvalidated> {
      */
     pub fn new() -> Self {
         Self {
+            recv_window: None,
             _state: PhantomData,
         }
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the open order lists specification.
      *
@@ -38,7 +58,10 @@ impl OpenOrderListsSpec<Unvalidated> {
      * - `OpenOrderListsSpec<Validated>`: Validated specification.
      */
     pub fn build(self) -> Result<OpenOrderListsSpec<Validated>> {
+        validate_recv_window(self.recv_window)?;
+
         Ok(OpenOrderListsSpec {
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
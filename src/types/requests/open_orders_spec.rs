@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -17,11 +19,14 @@ use crate::{
  *
  * # Fields
  * - `symbol`: Optional symbol filter - if None, returns orders for all symbols.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct OpenOrdersSpec<S = Unvalidated> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "recvWindow")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -36,6 +41,7 @@ impl OpenOrdersSpec<Unvalidated> {
     pub fn new() -> Self {
         Self {
             symbol: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -50,7 +56,23 @@ impl OpenOrdersSpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
-        self.symbol = Some(symbol.into());
+        self.symbol = Some(symbol.into().to_uppercase());
+        self
+    }
+
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
         self
     }
 
@@ -66,6 +88,7 @@ impl OpenOrdersSpec<Unvalidated> {
 
         Ok(OpenOrdersSpec {
             symbol: self.symbol,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -82,6 +105,8 @@ impl OpenOrdersSpec<Unvalidated> {
         {
             return Err(InvalidParameter::empty("symbol").into());
         }
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -40,7 +40,7 @@ impl OrderBookSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             limit: None,
             _state: PhantomData,
         }
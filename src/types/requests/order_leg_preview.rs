@@ -0,0 +1,49 @@
+use crate::enums::{OrderSide, OrderType, TimeInForce};
+
+/**
+ * Position of an order within an order list.
+ *
+ * # Variants
+ * - `Above`: Above order of an OCO.
+ * - `Below`: Below order of an OCO.
+ * - `Working`: Working order of an OTO or OTOCO.
+ * - `Pending`: Pending order of an OTO.
+ * - `PendingAbove`: Pending above order of an OTOCO.
+ * - `PendingBelow`: Pending below order of an OTOCO.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderLegRole {
+    Above,
+    Below,
+    Working,
+    Pending,
+    PendingAbove,
+    PendingBelow,
+}
+
+/**
+ * Preview of a single order an order list specification would place.
+ *
+ * # Fields
+ * - `role`: Position of the order within the order list.
+ * - `side`: Order side - BUY or SELL.
+ * - `order_type`: Type of the order.
+ * - `quantity`: Order quantity.
+ * - `price`: Limit price, if the order has one.
+ * - `stop_price`: Stop price, if the order has one.
+ * - `trailing_delta`: Trailing delta, if the order has one.
+ * - `time_in_force`: Time in force, if set.
+ * - `client_order_id`: Client order ID, if set.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderLegPreview {
+    pub role: OrderLegRole,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub quantity: rust_decimal::Decimal,
+    pub price: Option<rust_decimal::Decimal>,
+    pub stop_price: Option<rust_decimal::Decimal>,
+    pub trailing_delta: Option<rust_decimal::Decimal>,
+    pub time_in_force: Option<TimeInForce>,
+    pub client_order_id: Option<String>,
+}
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -15,6 +17,7 @@ use crate::{
  * # Fields
  * - `order_list_id`: Optional order list ID to query (mutually exclusive with original_client_order_id).
  * - `original_client_order_id`: Optional original client order ID to query (mutually exclusive with order_list_id).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,6 +25,7 @@ pub struct OrderListStatusSpec<S = Unvalidated> {
     pub order_list_id: Option<u64>,
     #[serde(rename = "origClientOrderId")]
     pub original_client_order_id: Option<String>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -37,6 +41,7 @@ impl OrderListStatusSpec<Unvalidated> {
         Self {
             order_list_id: None,
             original_client_order_id: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -72,6 +77,22 @@ impl OrderListStatusSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the order list status specification.
      *
@@ -85,6 +106,7 @@ impl OrderListStatusSpec<Unvalidated> {
         Ok(OrderListStatusSpec {
             order_list_id: self.order_list_id,
             original_client_order_id: self.original_client_order_id,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -116,6 +138,8 @@ impl OrderListStatusSpec<Unvalidated> {
             return Err(InvalidParameter::empty("original_client_order_id").into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -125,3 +149,78 @@ impl Default for OrderListStatusSpec<Unvalidated> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Extracts the parameter validation error from a failed build.
+     */
+    fn invalid_parameter(result: Result<OrderListStatusSpec<Validated>>) -> InvalidParameter {
+        result
+            .unwrap_err()
+            .downcast_ref::<InvalidParameter>()
+            .cloned()
+            .expect("InvalidParameter error")
+    }
+
+    /**
+     * Tests that setting both identifiers is rejected as mutually exclusive.
+     */
+    #[test]
+    fn test_both_identifiers_rejected() {
+        // Act
+        let result = OrderListStatusSpec::new()
+            .with_order_list_id(1929)
+            .with_original_client_order_id("C3wyj4WVEktd7u9aVBRXcN")
+            .build();
+
+        // Assert
+        assert_eq!(
+            invalid_parameter(result),
+            InvalidParameter::mutually_exclusive("order_list_id", "original_client_order_id")
+        );
+    }
+
+    /**
+     * Tests that omitting both identifiers is rejected.
+     */
+    #[test]
+    fn test_neither_identifier_rejected() {
+        // Act
+        let result = OrderListStatusSpec::new().build();
+
+        // Assert
+        assert_eq!(
+            invalid_parameter(result),
+            InvalidParameter::required("order_list_id or original_client_order_id")
+        );
+    }
+
+    /**
+     * Tests that either identifier on its own builds and serializes.
+     */
+    #[test]
+    fn test_single_identifier_accepted() {
+        // Act
+        let by_id = OrderListStatusSpec::new()
+            .with_order_list_id(1929)
+            .build()
+            .unwrap();
+        let by_client_id = OrderListStatusSpec::new()
+            .with_original_client_order_id("C3wyj4WVEktd7u9aVBRXcN")
+            .build()
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            serde_urlencoded::to_string(&by_id).unwrap(),
+            "orderListId=1929"
+        );
+        assert_eq!(
+            serde_urlencoded::to_string(&by_client_id).unwrap(),
+            "origClientOrderId=C3wyj4WVEktd7u9aVBRXcN"
+        );
+    }
+}
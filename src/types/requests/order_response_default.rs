@@ -0,0 +1,57 @@
+use crate::enums::OrderResponseType;
+use crate::types::requests::{
+    CancelReplaceSpec, OcoOrderSpec, OrderSpec, OtoOrderSpec, OtocoOrderSpec, SorOrderSpec,
+    Validated,
+};
+
+/**
+ * Order specifications carrying a `newOrderRespType` parameter.
+ *
+ * Lets clients fill in a configured default response type without
+ * overriding one set explicitly on the specification.
+ */
+pub(crate) trait OrderResponseDefault: Sized {
+    /**
+     * Gets the response type slot of the specification.
+     *
+     * # Returns
+     * - `&mut Option<OrderResponseType>`: Response type set on the specification.
+     */
+    fn response_type_mut(&mut self) -> &mut Option<OrderResponseType>;
+
+    /**
+     * Applies a default response type if the specification does not set one.
+     *
+     * # Arguments
+     * - `default`: Configured default response type, if any.
+     *
+     * # Returns
+     * - `Self`: Specification with the response type filled in.
+     */
+    fn with_default_response_type(mut self, default: Option<OrderResponseType>) -> Self {
+        let response_type = self.response_type_mut();
+        if response_type.is_none() {
+            *response_type = default;
+        }
+        self
+    }
+}
+
+macro_rules! impl_order_response_default {
+    ($($spec:ident => $field:ident),* $(,)?) => {
+        $(impl OrderResponseDefault for $spec<Validated> {
+            fn response_type_mut(&mut self) -> &mut Option<OrderResponseType> {
+                &mut self.$field
+            }
+        })*
+    };
+}
+
+impl_order_response_default!(
+    CancelReplaceSpec => new_order_response_type,
+    OcoOrderSpec => new_order_response_type,
+    OrderSpec => response_type,
+    OtoOrderSpec => response_type,
+    OtocoOrderSpec => response_type,
+    SorOrderSpec => response_type,
+);
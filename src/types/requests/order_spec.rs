@@ -1,9 +1,12 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
+use crate::types::requests::required_fields::OrderFields;
 use crate::{
     enums::{OrderResponseType, OrderSide, OrderType, SelfTradePreventionMode, TimeInForce},
     errors::InvalidParameter,
@@ -29,6 +32,8 @@ use crate::{
  * - `strategy_id`: Strategy ID for order labeling.
  * - `strategy_type`: Strategy type (must be >= 1000000).
  * - `self_trade_prevention_mode`: Self-trade prevention mode.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
+ * - `timestamp`: Signing timestamp in milliseconds, pinned by idempotent placement; taken from the client clock when unset.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -66,6 +71,10 @@ pub struct OrderSpec<S = Unvalidated> {
     pub strategy_id: Option<u64>,
     pub strategy_type: Option<u32>,
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) timestamp: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -84,7 +93,7 @@ impl OrderSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>, side: OrderSide, order_type: OrderType) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             side,
             order_type,
             time_in_force: None,
@@ -99,6 +108,8 @@ impl OrderSpec<Unvalidated> {
             strategy_id: None,
             strategy_type: None,
             self_trade_prevention_mode: None,
+            recv_window: None,
+            timestamp: None,
             _state: PhantomData,
         }
     }
@@ -218,6 +229,9 @@ impl OrderSpec<Unvalidated> {
     /**
      * Sets the response type.
      *
+     * ACK is the fastest to return but only carries the order identifiers and
+     * transaction time; see `Order` for the fields it guarantees.
+     *
      * # Arguments
      * - `response_type`: Response type for the order (ACK, RESULT, FULL).
      *
@@ -271,6 +285,22 @@ impl OrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the order specification.
      *
@@ -297,6 +327,8 @@ impl OrderSpec<Unvalidated> {
             strategy_id: self.strategy_id,
             strategy_type: self.strategy_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            recv_window: self.recv_window,
+            timestamp: self.timestamp,
             _state: PhantomData::<Validated>,
         })
     }
@@ -373,82 +405,333 @@ impl OrderSpec<Unvalidated> {
             .into());
         }
 
-        match self.order_type {
-            OrderType::Limit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::empty("time_in_force").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::empty("price").into());
-                }
-            }
-            OrderType::Market => {
-                if self.quantity.is_none() && self.quote_order_quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity or quote_order_quantity").into());
-                }
-            }
-            OrderType::StopLoss => {
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::empty("stop_price or trailing_delta").into());
-                }
-            }
-            OrderType::StopLossLimit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::empty("time_in_force").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::empty("price").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::empty("stop_price or trailing_delta").into());
-                }
-            }
-            OrderType::TakeProfit => {
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::empty("stop_price or trailing_delta").into());
-                }
-            }
-            OrderType::TakeProfitLimit => {
-                if self.time_in_force.is_none() {
-                    return Err(InvalidParameter::empty("time_in_force").into());
-                }
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::empty("price").into());
-                }
-                if self.stop_price.is_none() && self.trailing_delta.is_none() {
-                    return Err(InvalidParameter::empty("stop_price or trailing_delta").into());
-                }
-            }
-            OrderType::LimitMaker => {
-                if self.quantity.is_none() {
-                    return Err(InvalidParameter::empty("quantity").into());
-                }
-                if self.price.is_none() {
-                    return Err(InvalidParameter::empty("price").into());
-                }
-            }
-            OrderType::Unknown => {
-                return Err(
-                    InvalidParameter::new("order_type", "must be a valid order type").into(),
-                );
-            }
+        if let Some(time_in_force) = self.time_in_force
+            && !self.order_type.allows_time_in_force(time_in_force)
+        {
+            return Err(InvalidParameter::incompatible(
+                "time_in_force",
+                time_in_force,
+                "order_type",
+                self.order_type,
+            )
+            .into());
+        }
+
+        if matches!(self.order_type, OrderType::Unknown) {
+            return Err(InvalidParameter::new("order_type", "must be a valid order type").into());
         }
 
+        if let Some(field) = self.order_fields().missing_required(self.order_type) {
+            return Err(InvalidParameter::empty(field).into());
+        }
+
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
+
+    /**
+     * Gets which order parameters are set, for the required field check.
+     *
+     * # Returns
+     * - `OrderFields`: Presence of each parameter an order type may require.
+     */
+    fn order_fields(&self) -> OrderFields {
+        OrderFields {
+            time_in_force: self.time_in_force.is_some(),
+            quantity: self.quantity.is_some(),
+            quote_order_quantity: self.quote_order_quantity.is_some(),
+            price: self.price.is_some(),
+            stop_price: self.stop_price.is_some(),
+            trailing_delta: self.trailing_delta.is_some(),
+        }
+    }
+}
+
+impl<S> OrderSpec<S> {
+    /**
+     * Estimates the quote asset balance needed to place this order.
+     *
+     * The notional uses the order's limit price, or `reference_price` for
+     * orders without one, and `quote_order_quantity` as is. The taker fee is
+     * added on top as a conservative estimate, since any order may fill as
+     * taker and fees may be charged in the quote asset.
+     *
+     * # Arguments
+     * - `reference_price`: Expected execution price for orders without a limit price.
+     * - `taker_fee`: Taker commission rate (e.g., 0.001 for 0.1%).
+     *
+     * # Returns
+     * - `Option<Decimal>`: Required quote balance, or None for sell orders and
+     *   orders without a quantity.
+     */
+    pub fn required_quote_balance(
+        &self,
+        reference_price: rust_decimal::Decimal,
+        taker_fee: rust_decimal::Decimal,
+    ) -> Option<rust_decimal::Decimal> {
+        if self.side != OrderSide::Buy {
+            return None;
+        }
+
+        let notional = match (self.quantity, self.quote_order_quantity) {
+            (Some(quantity), _) => quantity * self.price.unwrap_or(reference_price),
+            (None, Some(quote_quantity)) => quote_quantity,
+            (None, None) => return None,
+        };
+
+        Some(notional * (rust_decimal::Decimal::ONE + taker_fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that order specifications normalize the symbol to uppercase.
+     */
+    #[test]
+    fn test_symbol_is_uppercased() {
+        // Act
+        let spec = OrderSpec::new("btcUsdt", OrderSide::Buy, OrderType::Market)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .build()
+            .unwrap();
+
+        // Assert
+        assert_eq!(spec.symbol, "BTCUSDT");
+        assert_eq!(serde_json::to_value(&spec).unwrap()["symbol"], "BTCUSDT");
+    }
+
+    /**
+     * Tests that each self-trade prevention mode serializes to its API value.
+     */
+    #[test]
+    fn test_self_trade_prevention_mode_serialization() {
+        // Arrange
+        let cases = [
+            (SelfTradePreventionMode::NoProtection, "NONE"),
+            (SelfTradePreventionMode::ExpireMaker, "EXPIRE_MAKER"),
+            (SelfTradePreventionMode::ExpireTaker, "EXPIRE_TAKER"),
+            (SelfTradePreventionMode::ExpireBoth, "EXPIRE_BOTH"),
+            (SelfTradePreventionMode::Decrement, "DECREMENT"),
+        ];
+
+        for (mode, expected) in cases {
+            // Act
+            let spec = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+                .with_quantity(rust_decimal::Decimal::ONE)
+                .with_self_trade_prevention_mode(mode)
+                .build()
+                .unwrap();
+            let query = serde_urlencoded::to_string(&spec).unwrap();
+
+            // Assert
+            assert!(query.contains(&format!("selfTradePreventionMode={}", expected)));
+        }
+    }
+
+    /**
+     * Tests that an unset self-trade prevention mode sends no parameter.
+     */
+    #[test]
+    fn test_self_trade_prevention_mode_omitted() {
+        // Act
+        let spec = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .build()
+            .unwrap();
+        let query = serde_urlencoded::to_string(&spec).unwrap();
+
+        // Assert
+        assert!(!query.contains("selfTradePreventionMode"));
+    }
+
+    /**
+     * Tests that a per-order receive window is serialized as recvWindow.
+     */
+    #[test]
+    fn test_with_recv_window_serializes_milliseconds() {
+        // Act
+        let spec = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .with_recv_window(Duration::from_millis(2500))
+            .build()
+            .unwrap();
+        let query = serde_urlencoded::to_string(&spec).unwrap();
+
+        // Assert
+        assert_eq!(spec.recv_window, Some(2500));
+        assert!(query.contains("recvWindow=2500"));
+    }
+
+    /**
+     * Tests that a receive window above 60 seconds is rejected.
+     */
+    #[test]
+    fn test_with_recv_window_rejects_above_limit() {
+        // Act
+        let result = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .with_recv_window(Duration::from_millis(60_001))
+            .build();
+
+        // Assert
+        let error = result.unwrap_err();
+        let parameter = error.downcast_ref::<InvalidParameter>().unwrap();
+        assert_eq!(parameter.param, "recvWindow");
+    }
+
+    /**
+     * Tests that build reports the first missing required field for the order type.
+     */
+    #[test]
+    fn test_build_enforces_required_fields() {
+        // Arrange
+        let cases = [
+            (
+                OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                    .with_quantity(rust_decimal::Decimal::ONE)
+                    .with_price(rust_decimal::Decimal::TEN),
+                "time_in_force",
+            ),
+            (
+                OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                    .with_time_in_force(TimeInForce::GTC)
+                    .with_quantity(rust_decimal::Decimal::ONE),
+                "price",
+            ),
+            (
+                OrderSpec::new("BTCUSDT", OrderSide::Sell, OrderType::StopLoss)
+                    .with_quantity(rust_decimal::Decimal::ONE),
+                "stop_price or trailing_delta",
+            ),
+            (
+                OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market),
+                "quantity or quote_order_quantity",
+            ),
+        ];
+
+        for (spec, expected) in cases {
+            // Act
+            let error = spec.build().unwrap_err();
+
+            // Assert
+            let parameter = error.downcast_ref::<InvalidParameter>().unwrap();
+            assert_eq!(parameter.param, expected);
+            assert_eq!(parameter.reason, "cannot be empty");
+        }
+    }
+
+    /**
+     * Tests that either alternative satisfies a required field.
+     */
+    #[test]
+    fn test_build_accepts_alternative_required_fields() {
+        // Act
+        let stop_loss = OrderSpec::new("BTCUSDT", OrderSide::Sell, OrderType::StopLoss)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .with_trailing_delta(rust_decimal::Decimal::TEN)
+            .build();
+        let market = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quote_order_quantity(rust_decimal::Decimal::TEN)
+            .build();
+
+        // Assert
+        assert!(stop_loss.is_ok());
+        assert!(market.is_ok());
+    }
+
+    /**
+     * Tests that LIMIT_MAKER orders with IOC time in force are rejected.
+     */
+    #[test]
+    fn test_build_rejects_limit_maker_with_ioc() {
+        // Act
+        let result = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::LimitMaker)
+            .with_time_in_force(TimeInForce::IOC)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .with_price(rust_decimal::Decimal::TEN)
+            .build();
+
+        // Assert
+        let error = result.unwrap_err();
+        let parameter = error.downcast_ref::<InvalidParameter>().unwrap();
+        assert_eq!(parameter.param, "time_in_force");
+        assert!(
+            parameter.reason.contains("LimitMaker"),
+            "{}",
+            parameter.reason
+        );
+    }
+
+    /**
+     * Tests that LIMIT orders accept FOK time in force.
+     */
+    #[test]
+    fn test_build_accepts_limit_with_fok() {
+        // Act
+        let result = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::FOK)
+            .with_quantity(rust_decimal::Decimal::ONE)
+            .with_price(rust_decimal::Decimal::TEN)
+            .build();
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    /**
+     * Tests the required quote balance of a LIMIT buy uses its limit price.
+     */
+    #[test]
+    fn test_required_quote_balance_limit() {
+        // Arrange
+        let buy = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(0.5))
+            .with_price(dec!(20000))
+            .build()
+            .unwrap();
+        let sell = OrderSpec::new("BTCUSDT", OrderSide::Sell, OrderType::Limit)
+            .with_time_in_force(TimeInForce::GTC)
+            .with_quantity(dec!(0.5))
+            .with_price(dec!(20000))
+            .build()
+            .unwrap();
+
+        // Act
+        let required = buy.required_quote_balance(dec!(21000), dec!(0.001));
+
+        // Assert
+        assert_eq!(required, Some(dec!(10010)));
+        assert_eq!(sell.required_quote_balance(dec!(21000), dec!(0.001)), None);
+    }
+
+    /**
+     * Tests the required quote balance of MARKET buys by base and quote quantity.
+     */
+    #[test]
+    fn test_required_quote_balance_market() {
+        // Arrange
+        let by_quantity = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quantity(dec!(2))
+            .build()
+            .unwrap();
+        let by_quote_quantity = OrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .with_quote_order_quantity(dec!(500))
+            .build()
+            .unwrap();
+
+        // Act
+        let quantity_required = by_quantity.required_quote_balance(dec!(100), dec!(0.00075));
+        let quote_required = by_quote_quantity.required_quote_balance(dec!(100), dec!(0.00075));
+
+        // Assert
+        assert_eq!(quantity_required, Some(dec!(200.15)));
+        assert_eq!(quote_required, Some(dec!(500.375)));
+    }
 }
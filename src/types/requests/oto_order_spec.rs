@@ -1,13 +1,15 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     enums::{OrderResponseType, OrderSide, OrderType, SelfTradePreventionMode, TimeInForce},
     errors::InvalidParameter,
-    types::requests::{Unvalidated, Validated},
+    types::requests::{OrderLegPreview, OrderLegRole, Unvalidated, Validated},
 };
 
 /**
@@ -41,6 +43,7 @@ use crate::{
  * - `pending_strategy_type`: Strategy type for the pending order.
  * - `response_type`: Response format.
  * - `self_trade_prevention_mode`: Self-trade prevention mode.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -105,6 +108,8 @@ pub struct OtoOrderSpec<S = Unvalidated> {
     pub response_type: Option<OrderResponseType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -138,7 +143,7 @@ impl OtoOrderSpec<Unvalidated> {
         pending_quantity: rust_decimal::Decimal,
     ) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             list_client_order_id: None,
             working_type,
             working_side,
@@ -162,6 +167,7 @@ impl OtoOrderSpec<Unvalidated> {
             pending_strategy_type: None,
             response_type: None,
             self_trade_prevention_mode: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -396,6 +402,22 @@ impl OtoOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the OTO order specification.
      *
@@ -430,6 +452,7 @@ impl OtoOrderSpec<Unvalidated> {
             pending_strategy_type: self.pending_strategy_type,
             response_type: self.response_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -587,6 +610,43 @@ impl OtoOrderSpec<Unvalidated> {
             return Err(InvalidParameter::range("pending_strategy_type", 1000000, u32::MAX).into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
+
+impl<S> OtoOrderSpec<S> {
+    /**
+     * Previews the orders this OTO would place.
+     *
+     * # Returns
+     * - `Vec<OrderLegPreview>`: The working order followed by the pending order.
+     */
+    pub fn preview_legs(&self) -> Vec<OrderLegPreview> {
+        vec![
+            OrderLegPreview {
+                role: OrderLegRole::Working,
+                side: self.working_side,
+                order_type: self.working_type,
+                quantity: self.working_quantity,
+                price: Some(self.working_price),
+                stop_price: None,
+                trailing_delta: None,
+                time_in_force: self.working_time_in_force,
+                client_order_id: self.working_client_order_id.clone(),
+            },
+            OrderLegPreview {
+                role: OrderLegRole::Pending,
+                side: self.pending_side,
+                order_type: self.pending_type,
+                quantity: self.pending_quantity,
+                price: self.pending_price,
+                stop_price: self.pending_stop_price,
+                trailing_delta: self.pending_trailing_delta,
+                time_in_force: self.pending_time_in_force,
+                client_order_id: self.pending_client_order_id.clone(),
+            },
+        ]
+    }
+}
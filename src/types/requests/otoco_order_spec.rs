@@ -1,13 +1,15 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     enums::{OrderResponseType, OrderSide, OrderType, SelfTradePreventionMode, TimeInForce},
     errors::InvalidParameter,
-    types::requests::{Unvalidated, Validated},
+    types::requests::{OrderLegPreview, OrderLegRole, Unvalidated, Validated},
 };
 
 /**
@@ -51,6 +53,7 @@ use crate::{
  * - `pending_below_strategy_type`: Strategy type for the pending below order.
  * - `response_type`: Response format - ACK, RESULT, or FULL.
  * - `self_trade_prevention_mode`: Self-trade prevention mode.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -155,6 +158,8 @@ pub struct OtocoOrderSpec<S = Unvalidated> {
     pub response_type: Option<OrderResponseType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -188,7 +193,7 @@ impl OtocoOrderSpec<Unvalidated> {
         pending_above_type: OrderType,
     ) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             list_client_order_id: None,
             working_type,
             working_side,
@@ -221,6 +226,7 @@ impl OtocoOrderSpec<Unvalidated> {
             pending_below_strategy_type: None,
             response_type: None,
             self_trade_prevention_mode: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -379,6 +385,22 @@ impl OtocoOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the OTOCO order specification.
      *
@@ -423,6 +445,7 @@ impl OtocoOrderSpec<Unvalidated> {
             pending_below_strategy_type: self.pending_below_strategy_type,
             response_type: self.response_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -550,6 +573,115 @@ impl OtocoOrderSpec<Unvalidated> {
             }
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
+
+impl<S> OtocoOrderSpec<S> {
+    /**
+     * Previews the orders this OTOCO would place.
+     *
+     * # Returns
+     * - `Vec<OrderLegPreview>`: The working order followed by the pending above
+     *   order and, if a below type is set, the pending below order.
+     */
+    pub fn preview_legs(&self) -> Vec<OrderLegPreview> {
+        let mut legs = vec![
+            OrderLegPreview {
+                role: OrderLegRole::Working,
+                side: self.working_side,
+                order_type: self.working_type,
+                quantity: self.working_quantity,
+                price: Some(self.working_price),
+                stop_price: None,
+                trailing_delta: None,
+                time_in_force: self.working_time_in_force,
+                client_order_id: self.working_client_order_id.clone(),
+            },
+            OrderLegPreview {
+                role: OrderLegRole::PendingAbove,
+                side: self.pending_side,
+                order_type: self.pending_above_type,
+                quantity: self.pending_quantity,
+                price: self.pending_above_price,
+                stop_price: self.pending_above_stop_price,
+                trailing_delta: self.pending_above_trailing_delta,
+                time_in_force: self.pending_above_time_in_force,
+                client_order_id: self.pending_above_client_order_id.clone(),
+            },
+        ];
+
+        if let Some(below_type) = self.pending_below_type {
+            legs.push(OrderLegPreview {
+                role: OrderLegRole::PendingBelow,
+                side: self.pending_side,
+                order_type: below_type,
+                quantity: self.pending_quantity,
+                price: self.pending_below_price,
+                stop_price: self.pending_below_stop_price,
+                trailing_delta: self.pending_below_trailing_delta,
+                time_in_force: self.pending_below_time_in_force,
+                client_order_id: self.pending_below_client_order_id.clone(),
+            });
+        }
+
+        legs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that an OTOCO previews its working and both pending orders.
+     */
+    #[test]
+    fn test_preview_legs() {
+        // Arrange
+        let spec = OtocoOrderSpec::new(
+            "BTCUSDT",
+            OrderType::Limit,
+            OrderSide::Buy,
+            dec!(100000),
+            dec!(0.5),
+            OrderSide::Sell,
+            dec!(0.5),
+            OrderType::LimitMaker,
+        )
+        .with_working_time_in_force(TimeInForce::GTC)
+        .with_pending_above_price(dec!(110000))
+        .with_pending_below_type(OrderType::StopLoss)
+        .with_pending_below_stop_price(dec!(90000))
+        .build()
+        .unwrap();
+
+        // Act
+        let legs = spec.preview_legs();
+
+        // Assert
+        assert_eq!(legs.len(), 3);
+
+        assert_eq!(legs[0].role, OrderLegRole::Working);
+        assert_eq!(legs[0].side, OrderSide::Buy);
+        assert_eq!(legs[0].order_type, OrderType::Limit);
+        assert_eq!(legs[0].price, Some(dec!(100000)));
+        assert_eq!(legs[0].time_in_force, Some(TimeInForce::GTC));
+
+        assert_eq!(legs[1].role, OrderLegRole::PendingAbove);
+        assert_eq!(legs[1].side, OrderSide::Sell);
+        assert_eq!(legs[1].order_type, OrderType::LimitMaker);
+        assert_eq!(legs[1].price, Some(dec!(110000)));
+
+        assert_eq!(legs[2].role, OrderLegRole::PendingBelow);
+        assert_eq!(legs[2].side, OrderSide::Sell);
+        assert_eq!(legs[2].order_type, OrderType::StopLoss);
+        assert_eq!(legs[2].price, None);
+        assert_eq!(legs[2].stop_price, Some(dec!(90000)));
+        assert_eq!(legs[2].quantity, dec!(0.5));
+    }
+}
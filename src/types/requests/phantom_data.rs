@@ -1,2 +1,4 @@
+#[derive(Debug, Clone, Copy)]
 pub struct Validated;
+#[derive(Debug, Clone, Copy)]
 pub struct Unvalidated;
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -21,6 +23,7 @@ use crate::{
  * - `order_id`: Order ID to filter prevented matches.
  * - `from_prevented_match_id`: Pagination starting point for prevented matches.
  * - `limit`: Maximum number of results to return (default: 500, max: 1000).
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -30,6 +33,7 @@ pub struct PreventedMatchesSpec<S = Unvalidated> {
     pub order_id: Option<u64>,
     pub from_prevented_match_id: Option<u64>,
     pub limit: Option<u32>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -46,11 +50,12 @@ impl PreventedMatchesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             prevented_match_id: None,
             order_id: None,
             from_prevented_match_id: None,
             limit: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -111,6 +116,22 @@ impl PreventedMatchesSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the prevented matches specification.
      *
@@ -129,6 +150,7 @@ impl PreventedMatchesSpec<Unvalidated> {
             order_id: self.order_id,
             from_prevented_match_id: self.from_prevented_match_id,
             limit: self.limit,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -172,6 +194,8 @@ impl PreventedMatchesSpec<Unvalidated> {
             return Err(InvalidParameter::range("limit", 1, 1000).into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -1,8 +1,10 @@
 use anyhow::Context;
 use serde::Serialize;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
@@ -18,6 +20,7 @@ use crate::{
  * - `symbol`: Trading symbol to query the order for (required).
  * - `order_id`: Order ID to query.
  * - `original_client_order_id`: Original client order ID to query.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -26,6 +29,7 @@ pub struct QueryOrderSpec<S = Unvalidated> {
     pub order_id: Option<u64>,
     #[serde(rename = "origClientOrderId")]
     pub original_client_order_id: Option<String>,
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -42,9 +46,10 @@ impl QueryOrderSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             order_id: None,
             original_client_order_id: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -80,6 +85,22 @@ impl QueryOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the query order specification.
      *
@@ -94,6 +115,7 @@ impl QueryOrderSpec<Unvalidated> {
             symbol: self.symbol,
             order_id: self.order_id,
             original_client_order_id: self.original_client_order_id,
+            recv_window: self.recv_window,
             _state: PhantomData,
         })
     }
@@ -111,6 +133,8 @@ impl QueryOrderSpec<Unvalidated> {
             .into());
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
@@ -40,7 +40,7 @@ impl RecentTradesSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             limit: None,
             _state: PhantomData,
         }
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use crate::Result;
+use crate::errors::InvalidParameter;
+
+/**
+ * Largest receive window Binance accepts on signed requests, in milliseconds.
+ */
+pub(crate) const MAX_RECV_WINDOW_MS: u64 = 60_000;
+
+/**
+ * Converts a receive window duration to whole milliseconds.
+ *
+ * # Arguments
+ * - `window`: Receive window duration.
+ *
+ * # Returns
+ * - `u64`: Milliseconds, saturating at `u64::MAX` so oversized values fail validation.
+ */
+pub(crate) fn recv_window_millis(window: Duration) -> u64 {
+    u64::try_from(window.as_millis()).unwrap_or(u64::MAX)
+}
+
+/**
+ * Validates an optional per-request receive window.
+ *
+ * # Arguments
+ * - `recv_window`: Receive window in milliseconds, if overridden.
+ *
+ * # Returns
+ * - `()`: Ok if unset or between 1 and `MAX_RECV_WINDOW_MS`.
+ */
+pub(crate) fn validate_recv_window(recv_window: Option<u64>) -> Result<()> {
+    if let Some(recv_window) = recv_window
+        && !(1..=MAX_RECV_WINDOW_MS).contains(&recv_window)
+    {
+        return Err(InvalidParameter::range("recvWindow", 1, MAX_RECV_WINDOW_MS).into());
+    }
+
+    Ok(())
+}
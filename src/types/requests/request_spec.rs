@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use serde::Serialize;
+
+use crate::Result;
+use crate::types::requests::{
+    AccountInfoSpec, AggregateTradesSpec, AllOrderListsSpec, AllOrdersSpec, AllocationSpec,
+    AmendOrderSpec, AveragePriceSpec, CancelAllOrdersSpec, CancelOrderListSpec, CancelOrderSpec,
+    CancelReplaceSpec, CommissionRatesSpec, ExchangeInfoSpec, HistoricalTradesSpec, KlinesSpec,
+    MyTradesSpec, OcoOrderSpec, OpenOrderListsSpec, OpenOrdersSpec, OrderBookSpec,
+    OrderListStatusSpec, OrderSpec, OtoOrderSpec, OtocoOrderSpec, PreventedMatchesSpec,
+    QueryOrderSpec, RecentTradesSpec, SorOrderSpec, Ticker24HrSpec, TickerBookSpec,
+    TickerPriceSpec, TickerRollingWindowSpec, TickerTradingDaySpec, Validated,
+};
+
+/**
+ * Shared behaviour of validated request specifications.
+ *
+ * Exposes the exact parameters a specification contributes to a request, for
+ * example to write an audit log of every order sent. Authentication parameters
+ * (`timestamp`, `recvWindow`, `signature`, `apiKey`) are added by the client at
+ * send time and are not included.
+ */
+pub trait RequestSpec: Serialize {
+    /**
+     * Gets the request parameters as sent to Binance.
+     *
+     * Parameters are produced by the same URL encoding the REST client uses,
+     * so values match the query string byte for byte once decoded.
+     *
+     * # Returns
+     * - `BTreeMap<String, String>`: Parameter names mapped to their values.
+     */
+    fn to_params(&self) -> Result<BTreeMap<String, String>> {
+        let query = serde_urlencoded::to_string(self).context("Failed to serialize parameters")?;
+        serde_urlencoded::from_str(&query).context("Failed to decode parameters")
+    }
+}
+
+macro_rules! impl_request_spec {
+    ($($spec:ident),* $(,)?) => {
+        $(impl RequestSpec for $spec<Validated> {})*
+    };
+}
+
+impl_request_spec!(
+    AccountInfoSpec,
+    AggregateTradesSpec,
+    AllOrderListsSpec,
+    AllOrdersSpec,
+    AllocationSpec,
+    AmendOrderSpec,
+    AveragePriceSpec,
+    CancelAllOrdersSpec,
+    CancelOrderListSpec,
+    CancelOrderSpec,
+    CancelReplaceSpec,
+    CommissionRatesSpec,
+    ExchangeInfoSpec,
+    HistoricalTradesSpec,
+    KlinesSpec,
+    MyTradesSpec,
+    OcoOrderSpec,
+    OpenOrderListsSpec,
+    OpenOrdersSpec,
+    OrderBookSpec,
+    OrderListStatusSpec,
+    OrderSpec,
+    OtoOrderSpec,
+    OtocoOrderSpec,
+    PreventedMatchesSpec,
+    QueryOrderSpec,
+    RecentTradesSpec,
+    SorOrderSpec,
+    Ticker24HrSpec,
+    TickerBookSpec,
+    TickerPriceSpec,
+    TickerRollingWindowSpec,
+    TickerTradingDaySpec,
+);
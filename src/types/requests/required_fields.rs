@@ -0,0 +1,66 @@
+use crate::enums::{OrderField, OrderType};
+
+/**
+ * Which order parameters are set on a new order.
+ *
+ * # Fields
+ * - `time_in_force`: Whether a time in force is set.
+ * - `quantity`: Whether a base asset quantity is set.
+ * - `quote_order_quantity`: Whether a quote asset quantity is set.
+ * - `price`: Whether a limit price is set.
+ * - `stop_price`: Whether a stop price is set.
+ * - `trailing_delta`: Whether a trailing delta is set.
+ */
+pub(crate) struct OrderFields {
+    pub time_in_force: bool,
+    pub quantity: bool,
+    pub quote_order_quantity: bool,
+    pub price: bool,
+    pub stop_price: bool,
+    pub trailing_delta: bool,
+}
+
+impl OrderFields {
+    /**
+     * Finds the first required parameter of an order type that is not set.
+     *
+     * # Arguments
+     * - `order_type`: Order type whose required fields are checked.
+     *
+     * # Returns
+     * - `Option<String>`: Missing parameter name, with alternatives joined by " or ".
+     */
+    pub fn missing_required(&self, order_type: OrderType) -> Option<String> {
+        order_type
+            .required_fields()
+            .iter()
+            .find(|alternatives| !alternatives.iter().any(|field| self.has_field(*field)))
+            .map(|alternatives| {
+                alternatives
+                    .iter()
+                    .map(OrderField::name)
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            })
+    }
+
+    /**
+     * Checks whether an order parameter is set.
+     *
+     * # Arguments
+     * - `field`: Order parameter to check.
+     *
+     * # Returns
+     * - `bool`: True if the parameter is set.
+     */
+    fn has_field(&self, field: OrderField) -> bool {
+        match field {
+            OrderField::TimeInForce => self.time_in_force,
+            OrderField::Quantity => self.quantity,
+            OrderField::QuoteOrderQuantity => self.quote_order_quantity,
+            OrderField::Price => self.price,
+            OrderField::StopPrice => self.stop_price,
+            OrderField::TrailingDelta => self.trailing_delta,
+        }
+    }
+}
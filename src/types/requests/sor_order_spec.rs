@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use anyhow::Context;
 use serde::Serialize;
 
 use crate::Result;
+use crate::types::requests::recv_window::{recv_window_millis, validate_recv_window};
 use crate::{
     enums::{OrderResponseType, OrderSide, OrderType, SelfTradePreventionMode, TimeInForce},
     errors::InvalidParameter,
@@ -29,6 +31,8 @@ use crate::{
  * - `strategy_id`: Strategy ID for order labeling.
  * - `strategy_type`: Strategy type (must be >= 1000000).
  * - `self_trade_prevention_mode`: Self-trade prevention mode.
+ * - `compute_commission_rates`: Whether the SOR test endpoint returns commission rates.
+ * - `recv_window`: Optional receive window in milliseconds overriding the client default.
  */
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -83,6 +87,10 @@ pub struct SorOrderSpec<S = Unvalidated> {
     pub strategy_type: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_commission_rates: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recv_window: Option<u64>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -101,7 +109,7 @@ impl SorOrderSpec<Unvalidated> {
      */
     pub fn new(symbol: impl Into<String>, side: OrderSide, order_type: OrderType) -> Self {
         Self {
-            symbol: symbol.into(),
+            symbol: symbol.into().to_uppercase(),
             side,
             order_type,
             time_in_force: None,
@@ -116,6 +124,8 @@ impl SorOrderSpec<Unvalidated> {
             strategy_id: None,
             strategy_type: None,
             self_trade_prevention_mode: None,
+            compute_commission_rates: None,
+            recv_window: None,
             _state: PhantomData,
         }
     }
@@ -288,6 +298,39 @@ impl SorOrderSpec<Unvalidated> {
         self
     }
 
+    /**
+     * Requests commission rates in the SOR test order response.
+     *
+     * Only accepted by `test_sor_order`; placing a live SOR order with this
+     * parameter is rejected by Binance.
+     *
+     * # Arguments
+     * - `compute`: Whether to compute commission rates.
+     *
+     * # Returns
+     * - `Self`: Updated SOR order specification.
+     */
+    pub fn with_compute_commission_rates(mut self, compute: bool) -> Self {
+        self.compute_commission_rates = Some(compute);
+        self
+    }
+
+    /**
+     * Sets the receive window for this request.
+     *
+     * Overrides the client-wide receive window. Binance accepts at most 60 seconds.
+     *
+     * # Arguments
+     * - `recv_window`: How long after its timestamp the request stays valid.
+     *
+     * # Returns
+     * - `Self`: Updated specification.
+     */
+    pub fn with_recv_window(mut self, recv_window: Duration) -> Self {
+        self.recv_window = Some(recv_window_millis(recv_window));
+        self
+    }
+
     /**
      * Builds the SOR order specification.
      *
@@ -313,6 +356,8 @@ impl SorOrderSpec<Unvalidated> {
             strategy_id: self.strategy_id,
             strategy_type: self.strategy_type,
             self_trade_prevention_mode: self.self_trade_prevention_mode,
+            compute_commission_rates: self.compute_commission_rates,
+            recv_window: self.recv_window,
             _state: PhantomData::<Validated>,
         })
     }
@@ -465,6 +510,40 @@ impl SorOrderSpec<Unvalidated> {
             }
         }
 
+        validate_recv_window(self.recv_window)?;
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests that computeCommissionRates is serialized only when requested.
+     */
+    #[test]
+    fn test_compute_commission_rates_serialization() {
+        // Arrange
+        let base = || {
+            SorOrderSpec::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+                .with_time_in_force(TimeInForce::GTC)
+                .with_quantity(dec!(0.1))
+                .with_price(dec!(30000))
+        };
+
+        // Act
+        let without = serde_urlencoded::to_string(base().build().unwrap()).unwrap();
+        let with = serde_urlencoded::to_string(
+            base().with_compute_commission_rates(true).build().unwrap(),
+        )
+        .unwrap();
+
+        // Assert
+        assert!(!without.contains("computeCommissionRates"));
+        assert!(with.contains("computeCommissionRates=true"));
+    }
+}
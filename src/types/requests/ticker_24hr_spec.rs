@@ -1,5 +1,6 @@
 use crate::Result;
 use crate::{
+    enums::TickerType,
     errors::InvalidParameter,
     types::requests::{Unvalidated, Validated},
 };
@@ -16,7 +17,7 @@ use std::marker::PhantomData;
  * # Fields
  * - `symbol`: Optional single symbol to query.
  * - `symbols`: Optional array of symbols to query.
- * - `ticker_type`: Optional ticker type ("FULL" or "MINI").
+ * - `ticker_type`: Optional response shape (FULL or MINI); FULL when omitted.
  */
 #[derive(Debug, Clone, Serialize)]
 pub struct Ticker24HrSpec<S = Unvalidated> {
@@ -25,8 +26,7 @@ pub struct Ticker24HrSpec<S = Unvalidated> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub symbols: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
-    // TODO: Create TickerType enum.
-    pub ticker_type: Option<String>,
+    pub ticker_type: Option<TickerType>,
     #[serde(skip)]
     _state: PhantomData<S>,
 }
@@ -57,7 +57,7 @@ impl Ticker24HrSpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
-        self.symbol = Some(symbol.into());
+        self.symbol = Some(symbol.into().to_uppercase());
         self
     }
 
@@ -71,22 +71,25 @@ impl Ticker24HrSpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbols(mut self, symbols: Vec<impl Into<String>>) -> Self {
-        let symbol_strings: Vec<String> = symbols.into_iter().map(|s| s.into()).collect();
+        let symbol_strings: Vec<String> = symbols
+            .into_iter()
+            .map(|s| s.into().to_uppercase())
+            .collect();
         self.symbols = Some(serde_json::to_string(&symbol_strings).unwrap());
         self
     }
 
     /**
-     * Sets the ticker type filter.
+     * Sets the response shape.
      *
      * # Arguments
-     * - `ticker_type`: Ticker type ("FULL" or "MINI").
+     * - `ticker_type`: FULL for complete statistics or MINI for OHLCV only.
      *
      * # Returns
      * - `Self`: Updated specification.
      */
-    pub fn with_ticker_type(mut self, ticker_type: impl Into<String>) -> Self {
-        self.ticker_type = Some(ticker_type.into());
+    pub fn with_type(mut self, ticker_type: TickerType) -> Self {
+        self.ticker_type = Some(ticker_type);
         self
     }
 
@@ -141,19 +144,6 @@ impl Ticker24HrSpec<Unvalidated> {
             }
         }
 
-        if let Some(ref ticker_type) = self.ticker_type {
-            match ticker_type.as_str() {
-                "FULL" | "MINI" => {}
-                _ => {
-                    return Err(InvalidParameter::new(
-                        "ticker_type",
-                        "must be either FULL or MINI",
-                    )
-                    .into());
-                }
-            }
-        }
-
         if self.symbol.is_some() && self.symbols.is_some() {
             return Err(InvalidParameter::mutually_exclusive("symbol", "symbols").into());
         }
@@ -167,3 +157,37 @@ impl Default for Ticker24HrSpec<Unvalidated> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /**
+     * Tests the query string for MINI and FULL single- and multi-symbol requests.
+     */
+    #[test]
+    fn test_type_serialization() {
+        // Arrange
+        let single_mini = Ticker24HrSpec::new()
+            .with_symbol("bnbbtc")
+            .with_type(TickerType::Mini)
+            .build()
+            .unwrap();
+        let multi_full = Ticker24HrSpec::new()
+            .with_symbols(vec!["BTCUSDT", "BNBBTC"])
+            .with_type(TickerType::Full)
+            .build()
+            .unwrap();
+
+        // Act
+        let single_mini = serde_urlencoded::to_string(&single_mini).unwrap();
+        let multi_full = serde_urlencoded::to_string(&multi_full).unwrap();
+
+        // Assert
+        assert_eq!(single_mini, "symbol=BNBBTC&type=MINI");
+        assert_eq!(
+            multi_full,
+            "symbols=%5B%22BTCUSDT%22%2C%22BNBBTC%22%5D&type=FULL"
+        );
+    }
+}
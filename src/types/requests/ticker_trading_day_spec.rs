@@ -62,7 +62,7 @@ impl TickerTradingDaySpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
-        self.symbol = Some(symbol.into());
+        self.symbol = Some(symbol.into().to_uppercase());
         self
     }
 
@@ -76,7 +76,8 @@ impl TickerTradingDaySpec<Unvalidated> {
      * - `Self`: Updated specification.
      */
     pub fn with_symbols(mut self, symbols: Vec<&str>) -> Self {
-        self.symbols = Some(serde_json::to_string(&symbols).unwrap());
+        let symbol_strings: Vec<String> = symbols.iter().map(|s| s.to_uppercase()).collect();
+        self.symbols = Some(serde_json::to_string(&symbol_strings).unwrap());
         self
     }
 
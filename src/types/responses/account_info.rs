@@ -134,4 +134,82 @@ impl AccountInfo {
             })
             .sum()
     }
+
+    /**
+     * Calculates account equity in a quote asset from trading pair prices.
+     *
+     * Each asset is converted with the price of its `{asset}{quote}` pair, or
+     * the inverse of the `{quote}{asset}` pair if only that one is listed.
+     * Assets without either pair contribute nothing.
+     *
+     * # Arguments
+     * - `quote_asset`: Asset to express the equity in.
+     * - `prices`: Last prices keyed by trading symbol (e.g. `BTCUSDT`).
+     *
+     * # Returns
+     * - `Decimal`: Sum of free and locked balances converted to the quote asset.
+     */
+    pub fn equity_in(&self, quote_asset: &str, prices: &HashMap<String, Decimal>) -> Decimal {
+        self.balances
+            .iter()
+            .map(|balance| {
+                let total_balance = balance.free + balance.locked;
+                if balance.asset == quote_asset {
+                    return total_balance;
+                }
+                if let Some(price) = prices.get(&format!("{}{}", balance.asset, quote_asset)) {
+                    return total_balance * price;
+                }
+                match prices.get(&format!("{}{}", quote_asset, balance.asset)) {
+                    Some(price) if !price.is_zero() => total_balance / price,
+                    _ => Decimal::ZERO,
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    const ACCOUNT: &str = r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":123456789,"accountType":"SPOT","balances":[{"asset":"BTC","free":"0.50000000","locked":"0.25000000"},{"asset":"ETH","free":"2.00000000","locked":"0.00000000"},{"asset":"EUR","free":"100.00000000","locked":"0.00000000"},{"asset":"USDT","free":"1000.00000000","locked":"50.00000000"},{"asset":"DUST","free":"7.00000000","locked":"0.00000000"}],"permissions":["SPOT"],"uid":354937868}"#;
+
+    /**
+     * Tests that equity sums direct pairs, inverse pairs and the quote asset itself.
+     */
+    #[test]
+    fn test_equity_in_multi_asset_account() {
+        // Arrange
+        let account: AccountInfo = serde_json::from_str(ACCOUNT).unwrap();
+        let prices = HashMap::from([
+            ("BTCUSDT".to_string(), dec!(60000)),
+            ("ETHUSDT".to_string(), dec!(3000)),
+            ("USDTEUR".to_string(), dec!(0.8)),
+            ("BNBUSDT".to_string(), dec!(500)),
+        ]);
+
+        // Act
+        let equity = account.equity_in("USDT", &prices);
+
+        // Assert
+        assert_eq!(equity, dec!(45000) + dec!(6000) + dec!(125) + dec!(1050));
+    }
+
+    /**
+     * Tests that equity is zero when no balance can be converted.
+     */
+    #[test]
+    fn test_equity_in_without_prices() {
+        // Arrange
+        let account: AccountInfo = serde_json::from_str(ACCOUNT).unwrap();
+
+        // Act
+        let equity = account.equity_in("BNB", &HashMap::new());
+
+        // Assert
+        assert_eq!(equity, Decimal::ZERO);
+    }
 }
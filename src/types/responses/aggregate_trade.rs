@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::OrderSide;
+
 /**
  * Aggregate trade from Binance API.
  *
@@ -39,3 +41,19 @@ pub struct AggregateTrade {
     #[serde(rename = "M")]
     pub is_best_match: bool,
 }
+
+impl AggregateTrade {
+    /**
+     * Gets the side of the taker order that caused the trade.
+     *
+     * # Returns
+     * - `OrderSide`: `Sell` when the buyer was the maker, `Buy` otherwise.
+     */
+    pub fn aggressor_side(&self) -> OrderSide {
+        if self.is_buyer_maker {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        }
+    }
+}
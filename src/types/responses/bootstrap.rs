@@ -0,0 +1,28 @@
+use crate::types::responses::{ExchangeInfo, RateLimit, ServerTime};
+
+/**
+ * Startup state fetched by `bootstrap` on the REST client.
+ *
+ * # Fields
+ * - `exchange_info`: Exchange information with symbol filters and rate limits.
+ * - `server_time`: Server time the clock offset was measured against.
+ * - `clock_offset_ms`: Milliseconds the client clock was shifted to match server time.
+ */
+#[derive(Debug, Clone)]
+pub struct Bootstrap {
+    pub exchange_info: ExchangeInfo,
+    pub server_time: ServerTime,
+    pub clock_offset_ms: i64,
+}
+
+impl Bootstrap {
+    /**
+     * Gets the global rate limits reported in the exchange information.
+     *
+     * # Returns
+     * - `&[RateLimit]`: Rate limits whose request weight limits seeded the client rate limiter.
+     */
+    pub fn rate_limits(&self) -> &[RateLimit] {
+        self.exchange_info.rate_limits()
+    }
+}
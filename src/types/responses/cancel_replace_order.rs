@@ -1,29 +1,371 @@
 use serde::{Deserialize, Serialize};
 
+use crate::Result;
 use crate::enums::CancelReplaceStatus;
+use crate::errors::{ApiError, BinanceError};
 use crate::types::responses::Order;
 
+/**
+ * Error code returned when only one leg of a cancel-replace succeeded.
+ */
+const CANCEL_REPLACE_PARTIALLY_FAILED_CODE: i32 = -2021;
+
+/**
+ * Error code returned when both legs of a cancel-replace failed.
+ */
+const CANCEL_REPLACE_FAILED_CODE: i32 = -2022;
+
 /**
  * Response structure for cancel-replace order operations.
  *
- * Represents the outcome of an atomic cancel-replace operation.
+ * Represents the outcome of an atomic cancel-replace operation. Each leg
+ * carries either the order details or the error that leg failed with.
  *
  * # Fields
  * - `cancel_status`: Result of the cancel operation (SUCCESS, FAILURE, NOT_ATTEMPTED).
  * - `new_order_status`: Result of the new order placement (SUCCESS, FAILURE, NOT_ATTEMPTED).
- * - `cancel_order`: Cancel operation response (order details on success, error on failure, null if not attempted).
- * - `new_order`: New order response (order details on success, error on failure, null if not attempted).
+ * - `cancel_order`: Cancelled order details on success, None otherwise.
+ * - `new_order`: New order details on success, None otherwise.
+ * - `cancel_error`: Error the cancel failed with, None otherwise.
+ * - `new_order_error`: Error the new order placement failed with, None otherwise.
  */
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[serde(from = "RawCancelReplaceOrder", into = "RawCancelReplaceOrder")]
 pub struct CancelReplaceOrder {
-    #[serde(rename = "cancelResult")]
     pub cancel_status: CancelReplaceStatus,
-    #[serde(rename = "newOrderResult")]
     pub new_order_status: CancelReplaceStatus,
-    #[serde(rename = "cancelResponse")]
     pub cancel_order: Option<Order>,
-    #[serde(rename = "newOrderResponse")]
     pub new_order: Option<Order>,
+    pub cancel_error: Option<ApiError>,
+    pub new_order_error: Option<ApiError>,
+}
+
+impl CancelReplaceOrder {
+    /**
+     * Checks whether the cancel leg succeeded.
+     *
+     * # Returns
+     * - `bool`: True if the original order was cancelled.
+     */
+    pub fn cancel_succeeded(&self) -> bool {
+        self.cancel_status == CancelReplaceStatus::Success
+    }
+
+    /**
+     * Checks whether the new order leg succeeded.
+     *
+     * # Returns
+     * - `bool`: True if the new order was placed.
+     */
+    pub fn new_order_succeeded(&self) -> bool {
+        self.new_order_status == CancelReplaceStatus::Success
+    }
+
+    /**
+     * Recovers the per-leg results from a failed cancel-replace.
+     *
+     * Binance reports a partial failure (-2021) and a failure of both legs
+     * (-2022) as an error whose `data` field holds the regular cancel-replace
+     * response. Any other error is returned unchanged.
+     *
+     * # Arguments
+     * - `error`: Error returned by the cancel-replace request.
+     *
+     * # Returns
+     * - `CancelReplaceOrder`: Per-leg results if the error carried them.
+     */
+    pub(crate) fn from_partial_failure(error: anyhow::Error) -> Result<Self> {
+        let data = error
+            .downcast_ref::<BinanceError>()
+            .and_then(BinanceError::api_error)
+            .filter(|api_error| {
+                matches!(
+                    api_error.code,
+                    CANCEL_REPLACE_PARTIALLY_FAILED_CODE | CANCEL_REPLACE_FAILED_CODE
+                )
+            })
+            .and_then(|api_error| api_error.data.clone());
+
+        match data.map(serde_json::from_value) {
+            Some(Ok(cancel_replace)) => Ok(cancel_replace),
+            _ => Err(error),
+        }
+    }
+}
+
+/**
+ * Wire format of a single cancel-replace leg.
+ *
+ * # Variants
+ * - `Order`: Order details returned on success.
+ * - `Error`: Error code and message returned on failure.
+ */
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawLegResponse {
+    Order(Box<Order>),
+    Error { code: i32, msg: String },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawCancelReplaceOrder {
+    #[serde(rename = "cancelResult")]
+    cancel_status: CancelReplaceStatus,
+    #[serde(rename = "newOrderResult")]
+    new_order_status: CancelReplaceStatus,
+    #[serde(rename = "cancelResponse")]
+    cancel_response: Option<RawLegResponse>,
+    #[serde(rename = "newOrderResponse")]
+    new_order_response: Option<RawLegResponse>,
+}
+
+impl RawLegResponse {
+    fn split(leg: Option<Self>) -> (Option<Order>, Option<ApiError>) {
+        match leg {
+            Some(RawLegResponse::Order(order)) => (Some(*order), None),
+            Some(RawLegResponse::Error { code, msg }) => (None, Some(ApiError::new(code, msg))),
+            None => (None, None),
+        }
+    }
+
+    fn join(order: Option<Order>, error: Option<ApiError>) -> Option<Self> {
+        match (order, error) {
+            (Some(order), _) => Some(RawLegResponse::Order(Box::new(order))),
+            (None, Some(error)) => Some(RawLegResponse::Error {
+                code: error.code,
+                msg: error.msg,
+            }),
+            (None, None) => None,
+        }
+    }
+}
+
+impl From<RawCancelReplaceOrder> for CancelReplaceOrder {
+    fn from(raw: RawCancelReplaceOrder) -> Self {
+        let (cancel_order, cancel_error) = RawLegResponse::split(raw.cancel_response);
+        let (new_order, new_order_error) = RawLegResponse::split(raw.new_order_response);
+
+        Self {
+            cancel_status: raw.cancel_status,
+            new_order_status: raw.new_order_status,
+            cancel_order,
+            new_order,
+            cancel_error,
+            new_order_error,
+        }
+    }
+}
+
+impl From<CancelReplaceOrder> for RawCancelReplaceOrder {
+    fn from(cancel_replace: CancelReplaceOrder) -> Self {
+        Self {
+            cancel_status: cancel_replace.cancel_status,
+            new_order_status: cancel_replace.new_order_status,
+            cancel_response: RawLegResponse::join(
+                cancel_replace.cancel_order,
+                cancel_replace.cancel_error,
+            ),
+            new_order_response: RawLegResponse::join(
+                cancel_replace.new_order,
+                cancel_replace.new_order_error,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::enums::{OrderStatus, RateLimitInterval, RateLimitType};
+
+    const PARTIAL_FAILURE_DATA: &str = r#"{"cancelResult":"SUCCESS","newOrderResult":"FAILURE","cancelResponse":{"symbol":"BTCUSDT","origClientOrderId":"DnLo3vTAQcjha43lAZhZ0y","orderId":9,"orderListId":-1,"clientOrderId":"osxN3JXAtJvKvCqGeMWMVR","transactTime":1684804350068,"price":"0.01000000","origQty":"0.000100","executedQty":"0.00000000","origQuoteOrderQty":"0.000000","cummulativeQuoteQty":"0.00000000","status":"CANCELED","timeInForce":"GTC","type":"LIMIT","side":"SELL","selfTradePreventionMode":"NONE"},"newOrderResponse":{"code":-2010,"msg":"Order would immediately match and take."}}"#;
+
+    /**
+     * Tests deserializing a response where the cancel succeeded but the new order was rejected.
+     */
+    #[test]
+    fn test_cancel_succeeded_new_order_rejected() {
+        // Act
+        let cancel_replace: CancelReplaceOrder =
+            serde_json::from_str(PARTIAL_FAILURE_DATA).unwrap();
+
+        // Assert
+        assert!(cancel_replace.cancel_succeeded());
+        assert!(!cancel_replace.new_order_succeeded());
+        assert_eq!(cancel_replace.cancel_order.as_ref().unwrap().order_id, 9);
+        assert!(cancel_replace.cancel_error.is_none());
+        assert!(cancel_replace.new_order.is_none());
+        let new_order_error = cancel_replace.new_order_error.unwrap();
+        assert_eq!(new_order_error.code, -2010);
+        assert!(new_order_error.trading_rejection.is_some());
+    }
+
+    /**
+     * Tests deserializing a response where neither leg was attempted past the failed cancel.
+     */
+    #[test]
+    fn test_cancel_failed_new_order_not_attempted() {
+        // Arrange
+        let json = r#"{"cancelResult":"FAILURE","newOrderResult":"NOT_ATTEMPTED","cancelResponse":{"code":-2011,"msg":"Unknown order sent."},"newOrderResponse":null}"#;
+
+        // Act
+        let cancel_replace: CancelReplaceOrder = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert!(!cancel_replace.cancel_succeeded());
+        assert!(!cancel_replace.new_order_succeeded());
+        assert_eq!(cancel_replace.cancel_error.unwrap().code, -2011);
+        assert!(cancel_replace.new_order.is_none());
+        assert!(cancel_replace.new_order_error.is_none());
+    }
+
+    /**
+     * Tests recovering per-leg results from a -2021 partial failure error.
+     */
+    #[test]
+    fn test_from_partial_failure() {
+        // Arrange
+        let payload = serde_json::json!({
+            "code": -2021,
+            "msg": "Order cancel-replace partially failed.",
+            "data": serde_json::from_str::<serde_json::Value>(PARTIAL_FAILURE_DATA).unwrap(),
+        });
+        let error: anyhow::Error = BinanceError::Api(ApiError::from_json(&payload).unwrap()).into();
+
+        // Act
+        let cancel_replace = CancelReplaceOrder::from_partial_failure(error).unwrap();
+
+        // Assert
+        assert!(cancel_replace.cancel_succeeded());
+        assert_eq!(cancel_replace.new_order_error.unwrap().code, -2010);
+    }
+
+    /**
+     * Tests recovering per-leg results from a -2022 error where both legs failed.
+     */
+    #[test]
+    fn test_from_partial_failure_both_legs_failed() {
+        // Arrange
+        let payload = serde_json::json!({
+            "code": -2022,
+            "msg": "Order cancel-replace failed.",
+            "data": {
+                "cancelResult": "FAILURE",
+                "newOrderResult": "FAILURE",
+                "cancelResponse": { "code": -2011, "msg": "Unknown order sent." },
+                "newOrderResponse": { "code": -2010, "msg": "Order would immediately match and take." }
+            },
+        });
+        let error: anyhow::Error = BinanceError::Api(ApiError::from_json(&payload).unwrap()).into();
+
+        // Act
+        let cancel_replace = CancelReplaceOrder::from_partial_failure(error).unwrap();
+
+        // Assert
+        assert!(!cancel_replace.cancel_succeeded());
+        assert!(!cancel_replace.new_order_succeeded());
+        assert_eq!(cancel_replace.cancel_error.unwrap().code, -2011);
+        assert_eq!(cancel_replace.new_order_error.unwrap().code, -2010);
+    }
+
+    /**
+     * Tests that a response serializes back to the wire format it was read from.
+     */
+    #[test]
+    fn test_serialization_round_trip() {
+        // Arrange
+        let cancel_replace: CancelReplaceOrder =
+            serde_json::from_str(PARTIAL_FAILURE_DATA).unwrap();
+
+        // Act
+        let json = serde_json::to_value(&cancel_replace).unwrap();
+        let round_tripped: CancelReplaceOrder = serde_json::from_value(json.clone()).unwrap();
+
+        // Assert
+        assert_eq!(json["cancelResult"], "SUCCESS");
+        assert_eq!(json["cancelResponse"]["orderId"], 9);
+        assert_eq!(
+            json["newOrderResponse"],
+            serde_json::json!({ "code": -2010, "msg": "Order would immediately match and take." })
+        );
+        assert_eq!(round_tripped.cancel_order, cancel_replace.cancel_order);
+        assert_eq!(
+            round_tripped.new_order_error,
+            cancel_replace.new_order_error
+        );
+    }
+
+    /**
+     * Tests that errors other than a failed cancel-replace are passed through.
+     */
+    #[test]
+    fn test_from_partial_failure_passes_other_errors() {
+        // Arrange
+        let error: anyhow::Error = BinanceError::Api(ApiError::new(-2010, "rejected")).into();
+
+        // Act
+        let result = CancelReplaceOrder::from_partial_failure(error);
+
+        // Assert
+        let error = result.unwrap_err();
+        assert_eq!(
+            error
+                .downcast_ref::<BinanceError>()
+                .and_then(BinanceError::api_code),
+            Some(-2010)
+        );
+    }
+
+    /**
+     * Tests deserializing a FULL response with fills and per-leg rate limits.
+     */
+    #[test]
+    fn test_full_response_with_rate_limits() {
+        // Arrange
+        let json = r#"{
+            "cancelResult": "SUCCESS",
+            "newOrderResult": "SUCCESS",
+            "cancelResponse": {
+                "symbol": "BTCUSDT", "origClientOrderId": "4d96324ff9d44481926157", "orderId": 125690984230,
+                "orderListId": -1, "clientOrderId": "91fe37ce9e69c90d6358c0", "transactTime": 1684804350068,
+                "price": "23450.00000000", "origQty": "0.00847000", "executedQty": "0.00001000",
+                "origQuoteOrderQty": "0.000000", "cummulativeQuoteQty": "0.23450000", "status": "CANCELED",
+                "timeInForce": "GTC", "type": "LIMIT", "side": "SELL", "selfTradePreventionMode": "NONE",
+                "rateLimits": [{"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 50, "count": 1}]
+            },
+            "newOrderResponse": {
+                "symbol": "BTCUSDT", "orderId": 12569099453, "orderListId": -1,
+                "clientOrderId": "bX5wROblo6YeDwa9iTLeyY", "transactTime": 1660801715639,
+                "price": "23416.10000000", "origQty": "0.00847000", "executedQty": "0.00847000",
+                "origQuoteOrderQty": "0.000000", "cummulativeQuoteQty": "198.33521500", "status": "FILLED",
+                "timeInForce": "GTC", "type": "LIMIT", "side": "SELL", "workingTime": 1660801715639,
+                "selfTradePreventionMode": "NONE",
+                "fills": [{"price": "23416.10000000", "qty": "0.00847000", "commission": "0.00000000", "commissionAsset": "USDT", "tradeId": 1650422481}],
+                "rateLimits": [{"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 50, "count": 2}]
+            }
+        }"#;
+
+        // Act
+        let cancel_replace: CancelReplaceOrder = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert!(cancel_replace.cancel_succeeded());
+        assert!(cancel_replace.new_order_succeeded());
+        let cancel_order = cancel_replace.cancel_order.unwrap();
+        assert_eq!(cancel_order.status, Some(OrderStatus::Canceled));
+        let cancel_limits = cancel_order.rate_limits.unwrap();
+        assert_eq!(cancel_limits[0].rate_limit_type, RateLimitType::Orders);
+        assert_eq!(cancel_limits[0].count, Some(1));
+        let new_order = cancel_replace.new_order.unwrap();
+        assert_eq!(new_order.status, Some(OrderStatus::Filled));
+        let fills = new_order.fills.unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].quantity, dec!(0.00847));
+        let new_order_limits = new_order.rate_limits.unwrap();
+        assert_eq!(new_order_limits[0].interval, RateLimitInterval::Second);
+        assert_eq!(new_order_limits[0].count, Some(2));
+    }
 }
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{Permission, RateLimitType, SymbolStatus};
@@ -91,6 +93,49 @@ impl ExchangeInfo {
             .collect()
     }
 
+    /**
+     * Gets all symbols eligible for Smart Order Routing.
+     *
+     * # Returns
+     * - `Vec<&str>`: SOR symbols across all base assets, in the order reported.
+     */
+    pub fn sor_symbols(&self) -> Vec<&str> {
+        self.sors
+            .iter()
+            .flat_map(|sor| sor.symbols.iter().map(String::as_str))
+            .collect()
+    }
+
+    /**
+     * Gets the symbols listed in this snapshot but not in another one.
+     *
+     * Comparing a fresh snapshot against an earlier one yields the newly
+     * listed symbols, whatever their trading status.
+     *
+     * # Arguments
+     * - `other`: Snapshot to compare against, typically an earlier one.
+     *
+     * # Returns
+     * - `Vec<&SymbolInfo>`: Symbols missing from `other`, in the order reported.
+     */
+    pub fn symbols_not_in(&self, other: &ExchangeInfo) -> Vec<&SymbolInfo> {
+        let known: HashSet<&str> = other.symbols.iter().map(|s| s.symbol.as_str()).collect();
+        self.symbols
+            .iter()
+            .filter(|s| !known.contains(s.symbol.as_str()))
+            .collect()
+    }
+
+    /**
+     * Gets all global rate limits enforced by the exchange.
+     *
+     * # Returns
+     * - `&[RateLimit]`: Typed rate limits as reported by the exchange.
+     */
+    pub fn rate_limits(&self) -> &[RateLimit] {
+        &self.rate_limits
+    }
+
     /**
      * Gets rate limit information by type.
      *
@@ -136,3 +181,172 @@ impl ExchangeInfo {
             })
     }
 }
+
+/**
+ * Splits a symbol into its base and quote assets using exchange information.
+ *
+ * Symbols are concatenated asset names with no separator, so the split is
+ * resolved from the exchange's symbol list rather than guessed from common
+ * quote asset suffixes.
+ *
+ * # Arguments
+ * - `symbol`: Trading symbol to split (e.g., "BTCUSDT").
+ * - `exchange_info`: Exchange information listing the symbol.
+ *
+ * # Returns
+ * - `Option<(&str, &str)>`: Base and quote asset, or None if the symbol is not listed.
+ */
+pub fn split_symbol<'a>(
+    symbol: &str,
+    exchange_info: &'a ExchangeInfo,
+) -> Option<(&'a str, &'a str)> {
+    exchange_info
+        .find_symbol(&symbol.to_uppercase())
+        .map(SymbolInfo::base_quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::enums::RateLimitInterval;
+    use crate::types::responses::test_fixtures;
+
+    /**
+     * Tests deserializing the documented rate limit array into typed values.
+     */
+    #[test]
+    fn test_rate_limits_are_typed() {
+        // Arrange
+        let json = r#"{
+            "timezone": "UTC",
+            "serverTime": 1565246363776,
+            "rateLimits": [
+                {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000},
+                {"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 100},
+                {"rateLimitType": "RAW_REQUESTS", "interval": "MINUTE", "intervalNum": 5, "limit": 61000}
+            ],
+            "exchangeFilters": [],
+            "symbols": []
+        }"#;
+
+        // Act
+        let exchange_info: ExchangeInfo = serde_json::from_str(json).unwrap();
+        let rate_limits = exchange_info.rate_limits();
+
+        // Assert
+        assert_eq!(rate_limits.len(), 3);
+        assert_eq!(rate_limits[0].rate_limit_type, RateLimitType::RequestWeight);
+        assert_eq!(rate_limits[0].interval, RateLimitInterval::Minute);
+        assert_eq!(rate_limits[0].limit, 6000);
+        assert_eq!(rate_limits[1].rate_limit_type, RateLimitType::Orders);
+        assert_eq!(rate_limits[1].window(), Some(Duration::from_secs(10)));
+        assert_eq!(rate_limits[2].rate_limit_type, RateLimitType::RawRequests);
+        assert_eq!(rate_limits[2].window(), Some(Duration::from_secs(300)));
+    }
+
+    /**
+     * Builds exchange info listing the given symbols as (symbol, base, quote).
+     */
+    fn exchange_info_with(symbols: &[(&str, &str, &str)]) -> ExchangeInfo {
+        let mut exchange_info =
+            test_fixtures::exchange_info(serde_json::json!([]), serde_json::json!([]));
+        let template = exchange_info.symbols.remove(0);
+        exchange_info.symbols = symbols
+            .iter()
+            .map(|(symbol, base, quote)| SymbolInfo {
+                symbol: symbol.to_string(),
+                base_asset: base.to_string(),
+                quote_asset: quote.to_string(),
+                ..template.clone()
+            })
+            .collect();
+        exchange_info
+    }
+
+    /**
+     * Tests resolving a symbol into its base and quote assets via exchange info.
+     */
+    #[test]
+    fn test_split_symbol() {
+        // Arrange
+        let exchange_info = exchange_info_with(&[
+            ("BTCUSDT", "BTC", "USDT"),
+            ("USDTTRY", "USDT", "TRY"),
+            ("BTCUSDC", "BTC", "USDC"),
+        ]);
+
+        // Act
+        let btc_usdt = split_symbol("BTCUSDT", &exchange_info);
+        let lowercase = split_symbol("usdttry", &exchange_info);
+        let unknown = split_symbol("ETHUSDT", &exchange_info);
+
+        // Assert
+        assert_eq!(btc_usdt, Some(("BTC", "USDT")));
+        assert_eq!(lowercase, Some(("USDT", "TRY")));
+        assert_eq!(unknown, None);
+        assert_eq!(
+            exchange_info.find_symbol("BTCUSDC").unwrap().base_quote(),
+            ("BTC", "USDC")
+        );
+    }
+
+    /**
+     * Tests that a symbol added between two snapshots is reported as new.
+     */
+    #[test]
+    fn test_symbols_not_in_finds_new_listing() {
+        // Arrange
+        let previous =
+            exchange_info_with(&[("BTCUSDT", "BTC", "USDT"), ("ETHUSDT", "ETH", "USDT")]);
+        let current = exchange_info_with(&[
+            ("BTCUSDT", "BTC", "USDT"),
+            ("NEWUSDT", "NEW", "USDT"),
+            ("ETHUSDT", "ETH", "USDT"),
+        ]);
+
+        // Act
+        let listed = current.symbols_not_in(&previous);
+        let delisted = previous.symbols_not_in(&current);
+
+        // Assert
+        assert_eq!(
+            listed.iter().map(|s| s.symbol.as_str()).collect::<Vec<_>>(),
+            ["NEWUSDT"]
+        );
+        assert_eq!(listed[0].base_asset, "NEW");
+        assert!(delisted.is_empty());
+    }
+
+    /**
+     * Tests deserializing the SOR configuration and flattening its symbols.
+     */
+    #[test]
+    fn test_sor_symbols() {
+        // Arrange
+        let json = r#"{
+            "timezone": "UTC",
+            "serverTime": 1565246363776,
+            "rateLimits": [],
+            "exchangeFilters": [],
+            "symbols": [],
+            "sors": [
+                {"baseAsset": "BTC", "symbols": ["BTCUSDT", "BTCUSDC"]},
+                {"baseAsset": "ETH", "symbols": ["ETHUSDT"]}
+            ]
+        }"#;
+
+        // Act
+        let exchange_info: ExchangeInfo = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(exchange_info.sors.len(), 2);
+        assert_eq!(exchange_info.sors[0].base_asset, "BTC");
+        assert_eq!(exchange_info.sors[1].symbols, ["ETHUSDT"]);
+        assert_eq!(
+            exchange_info.sor_symbols(),
+            ["BTCUSDT", "BTCUSDC", "ETHUSDT"]
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::MatchType;
+
 /**
  * Order fill information for executed trades.
  *
@@ -9,7 +11,9 @@ use serde::{Deserialize, Serialize};
  * - `quantity`: Quantity filled in this trade.
  * - `commission`: Commission charged for this trade.
  * - `commission_asset`: Asset in which commission was charged.
- * - `trade_id`: Unique trade identifier.
+ * - `trade_id`: Unique trade identifier (-1 for allocation fills).
+ * - `match_type`: How the fill was matched (present for SOR allocation fills).
+ * - `allocation_id`: Allocation identifier (present for SOR allocation fills).
  */
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,4 +28,76 @@ pub struct Fill {
     pub commission: Decimal,
     pub commission_asset: String,
     pub trade_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub match_type: Option<MatchType>,
+    #[serde(rename = "allocId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_id: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::types::responses::Order;
+
+    /**
+     * Tests deserializing a FULL order response whose fills include allocation details.
+     */
+    #[test]
+    fn test_full_response_allocation_fills() {
+        // Arrange
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 2,
+            "orderListId": -1,
+            "clientOrderId": "sBI1KM6nNtOfj5tccZSKly",
+            "transactTime": 1689149087774,
+            "price": "31000.00000000",
+            "origQty": "0.50000000",
+            "executedQty": "0.50000000",
+            "origQuoteOrderQty": "0.000000",
+            "cummulativeQuoteQty": "14000.00000000",
+            "status": "FILLED",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "workingTime": 1689149087774,
+            "fills": [
+                {
+                    "matchType": "ONE_PARTY_TRADE_REPORT",
+                    "price": "28000.00000000",
+                    "qty": "0.50000000",
+                    "commission": "0.00000000",
+                    "commissionAsset": "BTC",
+                    "tradeId": -1,
+                    "allocId": 0
+                },
+                {
+                    "price": "28000.00000000",
+                    "qty": "0.10000000",
+                    "commission": "0.00010000",
+                    "commissionAsset": "BTC",
+                    "tradeId": 56
+                }
+            ],
+            "workingFloor": "SOR",
+            "selfTradePreventionMode": "NONE",
+            "usedSor": true
+        }"#;
+
+        // Act
+        let order: Order = serde_json::from_str(json).unwrap();
+        let fills = order.fills.unwrap();
+
+        // Assert
+        assert_eq!(fills[0].match_type, Some(MatchType::OnePartyTradeReport));
+        assert_eq!(fills[0].allocation_id, Some(0));
+        assert_eq!(fills[0].trade_id, -1);
+        assert_eq!(fills[0].commission_asset, "BTC");
+        assert_eq!(fills[1].match_type, None);
+        assert_eq!(fills[1].allocation_id, None);
+        assert_eq!(fills[1].commission, dec!(0.0001));
+    }
 }
@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::streams::specs::Interval;
+
 /**
  * Kline (candlestick) data from Binance API.
  *
@@ -50,3 +52,206 @@ pub struct Kline {
     #[serde(alias = "B")]
     pub _unused: String,
 }
+
+/**
+ * Computes the volume-weighted average price over a kline series.
+ *
+ * Each candle contributes its typical price `(high + low + close) / 3`
+ * weighted by its base asset volume.
+ *
+ * # Arguments
+ * - `klines`: Kline series to average over.
+ *
+ * # Returns
+ * - `Option<Decimal>`: VWAP, or None if the series is empty or has no volume.
+ */
+pub fn vwap(klines: &[Kline]) -> Option<Decimal> {
+    let (weighted_sum, total_volume) = klines.iter().fold(
+        (Decimal::ZERO, Decimal::ZERO),
+        |(weighted_sum, total_volume), kline| {
+            let typical_price =
+                (kline.high_price + kline.low_price + kline.close_price) / Decimal::from(3);
+            (
+                weighted_sum + typical_price * kline.volume,
+                total_volume + kline.volume,
+            )
+        },
+    );
+
+    if total_volume.is_zero() {
+        return None;
+    }
+
+    Some(weighted_sum / total_volume)
+}
+
+/**
+ * Checks that a kline series has no missing candles.
+ *
+ * Each candle must open exactly one interval after the previous one. For
+ * `OneMonth`, whose length varies, each candle must open right after the
+ * previous candle's close time.
+ *
+ * # Arguments
+ * - `klines`: Kline series in ascending open time order.
+ * - `interval`: Interval the series was requested with.
+ *
+ * # Returns
+ * - `Result<(), usize>`: Ok if contiguous, otherwise the index of the first
+ *   candle that does not directly follow its predecessor.
+ */
+pub fn assert_contiguous(klines: &[Kline], interval: &Interval) -> std::result::Result<(), usize> {
+    let interval_millis = interval
+        .duration()
+        .map(|duration| duration.as_millis() as u64);
+
+    match klines.windows(2).position(|pair| {
+        let expected_open_time = match interval_millis {
+            Some(millis) => pair[0].open_time + millis,
+            None => pair[0].close_time + 1,
+        };
+        pair[1].open_time != expected_open_time
+    }) {
+        Some(position) => Err(position + 1),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    fn kline(high: Decimal, low: Decimal, close: Decimal, volume: Decimal) -> Kline {
+        Kline {
+            open_time: 0,
+            open_price: close,
+            high_price: high,
+            low_price: low,
+            close_price: close,
+            volume,
+            close_time: 59_999,
+            quote_asset_volume: Decimal::ZERO,
+            number_of_trades: 0,
+            taker_buy_base_asset_volume: Decimal::ZERO,
+            taker_buy_quote_asset_volume: Decimal::ZERO,
+            _unused: "0".to_string(),
+        }
+    }
+
+    /**
+     * Tests VWAP against a hand-computed value over three candles.
+     */
+    #[test]
+    fn test_vwap_three_candles() {
+        // Arrange
+        // Typical prices: 10, 12, 15 with volumes 1, 2, 3.
+        // VWAP = (10 * 1 + 12 * 2 + 15 * 3) / 6 = 79 / 6.
+        let klines = [
+            kline(dec!(11), dec!(9), dec!(10), dec!(1)),
+            kline(dec!(13), dec!(11), dec!(12), dec!(2)),
+            kline(dec!(16), dec!(14), dec!(15), dec!(3)),
+        ];
+
+        // Act
+        let result = vwap(&klines).unwrap();
+
+        // Assert
+        assert_eq!(result, dec!(79) / dec!(6));
+    }
+
+    /**
+     * Tests that a series with a missing candle reports the index after the gap.
+     */
+    #[test]
+    fn test_assert_contiguous_detects_gap() {
+        // Arrange
+        let candle = |minute: u64| Kline {
+            open_time: minute * 60_000,
+            close_time: minute * 60_000 + 59_999,
+            ..kline(dec!(11), dec!(9), dec!(10), dec!(1))
+        };
+        let contiguous: Vec<Kline> = (0..5).map(candle).collect();
+        let with_gap: Vec<Kline> = [0, 1, 2, 4, 5].into_iter().map(candle).collect();
+
+        // Act
+        let contiguous_result = assert_contiguous(&contiguous, &Interval::OneMinute);
+        let gap_result = assert_contiguous(&with_gap, &Interval::OneMinute);
+        let wrong_interval_result = assert_contiguous(&contiguous, &Interval::FiveMinutes);
+
+        // Assert
+        assert_eq!(contiguous_result, Ok(()));
+        assert_eq!(gap_result, Err(3));
+        assert_eq!(wrong_interval_result, Err(1));
+        assert_eq!(assert_contiguous(&[], &Interval::OneMinute), Ok(()));
+    }
+
+    /**
+     * Tests that empty and zero-volume series have no VWAP.
+     */
+    #[test]
+    fn test_vwap_without_volume() {
+        // Arrange
+        let zero_volume = [kline(dec!(11), dec!(9), dec!(10), Decimal::ZERO)];
+
+        // Act & Assert
+        assert_eq!(vwap(&[]), None);
+        assert_eq!(vwap(&zero_volume), None);
+    }
+
+    /**
+     * Tests deserializing a raw REST kline array into the typed struct field by field.
+     */
+    #[test]
+    fn test_deserialize_rest_kline_array() {
+        // Arrange
+        let json = r#"[
+            1499040000000,
+            "0.01634790",
+            "0.80000000",
+            "0.01575800",
+            "0.01577100",
+            "148976.11427815",
+            1499644799999,
+            "2434.19055334",
+            308,
+            "1756.87402397",
+            "28.46694368",
+            "0"
+        ]"#;
+
+        // Act
+        let kline: Kline = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(kline.open_time, 1499040000000);
+        assert_eq!(kline.open_price, dec!(0.01634790));
+        assert_eq!(kline.high_price, dec!(0.80000000));
+        assert_eq!(kline.low_price, dec!(0.01575800));
+        assert_eq!(kline.close_price, dec!(0.01577100));
+        assert_eq!(kline.volume, dec!(148976.11427815));
+        assert_eq!(kline.close_time, 1499644799999);
+        assert_eq!(kline.quote_asset_volume, dec!(2434.19055334));
+        assert_eq!(kline.number_of_trades, 308);
+        assert_eq!(kline.taker_buy_base_asset_volume, dec!(1756.87402397));
+        assert_eq!(kline.taker_buy_quote_asset_volume, dec!(28.46694368));
+    }
+
+    /**
+     * Tests that the REST array and the stream object yield the same typed kline.
+     */
+    #[test]
+    fn test_rest_and_stream_klines_match() {
+        // Arrange
+        let rest = r#"[1499040000000,"0.0010","0.0025","0.0015","0.0020","1000",1499040059999,"1.0000",100,"500","0.500","0"]"#;
+        let stream = r#"{"t":1499040000000,"o":"0.0010","h":"0.0025","l":"0.0015","c":"0.0020","v":"1000","T":1499040059999,"q":"1.0000","n":100,"V":"500","Q":"0.500","B":"0"}"#;
+
+        // Act
+        let from_rest: Kline = serde_json::from_str(rest).unwrap();
+        let from_stream: Kline = serde_json::from_str(stream).unwrap();
+
+        // Assert
+        assert_eq!(from_rest, from_stream);
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * Listen key response for user data streams.
+ *
+ * Compatible with:
+ * - REST API POST /api/v3/userDataStream
+ * - WebSocket API userDataStream.start
+ *
+ * # Fields
+ * - `listen_key`: Key identifying the user data stream.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ListenKey {
+    pub listen_key: String,
+}
@@ -5,6 +5,7 @@ mod allocation;
 mod amended_order;
 mod average_price;
 mod balance;
+mod bootstrap;
 mod cancel_replace_order;
 mod cancelled_order;
 mod commission_rates;
@@ -12,9 +13,12 @@ mod discount_info;
 mod exchange_info;
 mod fill;
 mod kline;
+mod listen_key;
 mod order;
 mod order_book;
 mod order_book_level;
+mod order_commission_rates;
+mod order_count_usage;
 mod order_list;
 mod order_summary;
 mod prevented_match;
@@ -22,6 +26,8 @@ mod rate_limit;
 mod server_time;
 mod symbol_commission_rates;
 mod symbol_info;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 mod test_order;
 mod ticker_book;
 mod ticker_price;
@@ -35,16 +41,20 @@ pub use allocation::Allocation;
 pub use amended_order::AmendedOrder;
 pub use average_price::AveragePrice;
 pub use balance::Balance;
+pub use bootstrap::Bootstrap;
 pub use cancel_replace_order::CancelReplaceOrder;
 pub use cancelled_order::CancelledOrder;
 pub use commission_rates::CommissionRates;
 pub use discount_info::DiscountInfo;
-pub use exchange_info::ExchangeInfo;
+pub use exchange_info::{ExchangeInfo, SorInfo, split_symbol};
 pub use fill::Fill;
-pub use kline::Kline;
+pub use kline::{Kline, assert_contiguous, vwap};
+pub use listen_key::ListenKey;
 pub use order::Order;
 pub use order_book::OrderBook;
 pub use order_book_level::OrderBookLevel;
+pub use order_commission_rates::OrderCommissionRates;
+pub use order_count_usage::OrderCountUsage;
 pub use order_list::OrderList;
 pub use order_summary::OrderSummary;
 pub use prevented_match::PreventedMatch;
@@ -1,7 +1,7 @@
 use crate::enums::{
     OrderSide, OrderStatus, OrderType, SelfTradePreventionMode, TimeInForce, WorkingFloor,
 };
-use crate::types::responses::Fill;
+use crate::types::responses::{Fill, RateLimit};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +9,9 @@ use serde::{Deserialize, Serialize};
  * Complete order information returned by order placement and status queries.
  *
  * Contains all possible fields that may be returned depending on the order type
- * and response type requested (ACK/RESULT/FULL).
+ * and response type requested (ACK/RESULT/FULL). An ACK response only guarantees
+ * `symbol`, `order_id`, `order_list_id`, `client_order_id` and `transaction_time`;
+ * every other field is None.
  *
  * # Fields
  * - `symbol`: Trading symbol.
@@ -43,6 +45,7 @@ use serde::{Deserialize, Serialize};
  * - `used_sor`: Whether Smart Order Routing was used.
  * - `prevented_match_id`: ID for prevented matches (STP).
  * - `prevented_quantity`: Quantity prevented from matching (STP).
+ * - `rate_limits`: Unfilled order count usage after this order, when reported.
  */
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -64,18 +67,23 @@ pub struct Order {
 
     // Core order fields
     #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub price: Option<Decimal>,
     #[serde(rename = "origQty", alias = "qty")]
     #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub original_quantity: Option<Decimal>,
     #[serde(rename = "executedQty")]
     #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub executed_quantity: Option<Decimal>,
     #[serde(rename = "origQuoteOrderQty", alias = "quoteOrderQty")]
     #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub original_quote_order_quantity: Option<Decimal>,
     #[serde(rename = "cummulativeQuoteQty", alias = "cumulativeQuoteQty")]
     #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cumulative_quote_quantity: Option<Decimal>,
 
     pub status: Option<OrderStatus>,
@@ -91,11 +99,11 @@ pub struct Order {
 
     // Conditional fields based on order type and parameters
     #[serde(with = "rust_decimal::serde::str_option")]
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stop_price: Option<Decimal>,
     #[serde(rename = "icebergQty")]
     #[serde(with = "rust_decimal::serde::str_option")]
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iceberg_quantity: Option<Decimal>,
     pub strategy_id: Option<u64>,
     pub strategy_type: Option<u32>,
@@ -106,6 +114,191 @@ pub struct Order {
     pub prevented_match_id: Option<u64>,
     #[serde(rename = "preventedQuantity", alias = "preventedQty")]
     #[serde(with = "rust_decimal::serde::str_option")]
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prevented_quantity: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limits: Option<Vec<RateLimit>>,
+}
+
+impl Order {
+    /**
+     * Checks whether this order counts towards the MAX_NUM_ALGO_ORDERS filter.
+     *
+     * # Returns
+     * - `bool`: True if the order type is a stop loss or take profit type.
+     */
+    pub fn is_algo(&self) -> bool {
+        self.order_type
+            .is_some_and(|order_type| order_type.is_algo())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{RateLimitInterval, RateLimitType};
+
+    /**
+     * Tests that the self-trade prevention mode is echoed back in order responses.
+     */
+    #[test]
+    fn test_self_trade_prevention_mode_echoed() {
+        // Arrange
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 28,
+            "orderListId": -1,
+            "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+            "transactTime": 1507725176595,
+            "price": "0.00000000",
+            "origQty": "10.00000000",
+            "executedQty": "10.00000000",
+            "origQuoteOrderQty": "0.000000",
+            "cummulativeQuoteQty": "10.00000000",
+            "status": "FILLED",
+            "timeInForce": "GTC",
+            "type": "MARKET",
+            "side": "SELL",
+            "workingTime": 1507725176595,
+            "selfTradePreventionMode": "EXPIRE_BOTH"
+        }"#;
+
+        // Act
+        let order: Order = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(
+            order.self_trade_prevention_mode,
+            Some(SelfTradePreventionMode::ExpireBoth)
+        );
+    }
+
+    /**
+     * Tests that order-count rate limits carried by an order response are typed.
+     */
+    #[test]
+    fn test_rate_limits_deserialization() {
+        // Arrange
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 12569099453,
+            "orderListId": -1,
+            "clientOrderId": "4d96324ff9d44481926157ec08158a40",
+            "transactTime": 1660801715639,
+            "price": "23416.10000000",
+            "origQty": "0.00847000",
+            "executedQty": "0.00000000",
+            "origQuoteOrderQty": "0.000000",
+            "cummulativeQuoteQty": "0.00000000",
+            "rateLimits": [
+                {
+                    "rateLimitType": "ORDERS",
+                    "interval": "SECOND",
+                    "intervalNum": 10,
+                    "limit": 50,
+                    "count": 1
+                },
+                {
+                    "rateLimitType": "ORDERS",
+                    "interval": "DAY",
+                    "intervalNum": 1,
+                    "limit": 160000,
+                    "count": 1
+                }
+            ]
+        }"#;
+
+        // Act
+        let order: Order = serde_json::from_str(json).unwrap();
+
+        // Assert
+        let rate_limits = order.rate_limits.unwrap();
+        assert_eq!(rate_limits.len(), 2);
+        assert_eq!(rate_limits[0].rate_limit_type, RateLimitType::Orders);
+        assert_eq!(rate_limits[0].interval, RateLimitInterval::Second);
+        assert_eq!(rate_limits[0].interval_num, 10);
+        assert_eq!(rate_limits[0].limit, 50);
+        assert_eq!(rate_limits[0].count, Some(1));
+        assert_eq!(rate_limits[1].interval, RateLimitInterval::Day);
+        assert_eq!(rate_limits[1].count, Some(1));
+    }
+
+    /**
+     * Tests that prevented match fields are typed when set and None when absent.
+     */
+    #[test]
+    fn test_prevented_fields() {
+        // Arrange
+        let prevented = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 29,
+            "orderListId": -1,
+            "clientOrderId": "stpExpired",
+            "price": "25000.00000000",
+            "origQty": "1.00000000",
+            "executedQty": "0.00000000",
+            "origQuoteOrderQty": "0.00000000",
+            "cummulativeQuoteQty": "0.00000000",
+            "status": "EXPIRED_IN_MATCH",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "workingTime": 1507725176595,
+            "selfTradePreventionMode": "EXPIRE_TAKER",
+            "preventedMatchId": 4,
+            "preventedQuantity": "1.00000000"
+        }"#;
+        let mut absent: serde_json::Value = serde_json::from_str(prevented).unwrap();
+        let fields = absent.as_object_mut().unwrap();
+        fields.remove("preventedMatchId");
+        fields.remove("preventedQuantity");
+
+        // Act
+        let prevented: Order = serde_json::from_str(prevented).unwrap();
+        let absent: Order = serde_json::from_value(absent).unwrap();
+
+        // Assert
+        assert_eq!(prevented.prevented_match_id, Some(4));
+        assert_eq!(prevented.prevented_quantity, Some(rust_decimal::dec!(1)));
+        assert_eq!(absent.prevented_match_id, None);
+        assert_eq!(absent.prevented_quantity, None);
+    }
+
+    /**
+     * Tests that an ACK placement response deserializes with only the
+     * guaranteed fields populated.
+     */
+    #[test]
+    fn test_ack_response() {
+        // Arrange
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 28,
+            "orderListId": -1,
+            "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+            "transactTime": 1507725176595
+        }"#;
+
+        // Act
+        let order: Order = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(order.symbol, "BTCUSDT");
+        assert_eq!(order.order_id, 28);
+        assert_eq!(order.order_list_id, -1);
+        assert_eq!(order.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
+        assert_eq!(order.transaction_time, Some(1507725176595));
+        assert_eq!(order.price, None);
+        assert_eq!(order.original_quantity, None);
+        assert_eq!(order.executed_quantity, None);
+        assert_eq!(order.original_quote_order_quantity, None);
+        assert_eq!(order.cumulative_quote_quantity, None);
+        assert_eq!(order.status, None);
+        assert_eq!(order.time_in_force, None);
+        assert_eq!(order.order_type, None);
+        assert_eq!(order.side, None);
+        assert_eq!(order.working_time, None);
+        assert_eq!(order.self_trade_prevention_mode, None);
+        assert_eq!(order.fills, None);
+    }
 }
@@ -1,5 +1,7 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::OrderSide;
 use crate::types::responses::OrderBookLevel;
 
 /**
@@ -60,4 +62,234 @@ impl OrderBook {
 
         bids_sorted && asks_sorted
     }
+
+    /**
+     * Computes the bid/ask volume imbalance over the top levels of the book.
+     *
+     * The imbalance is `(bid_volume - ask_volume) / (bid_volume + ask_volume)`,
+     * ranging from -1 (only asks) to 1 (only bids).
+     *
+     * # Arguments
+     * - `depth_levels`: Number of levels per side to include.
+     *
+     * # Returns
+     * - `Option<Decimal>`: Imbalance, or None if the included levels hold no volume.
+     */
+    pub fn imbalance(&self, depth_levels: usize) -> Option<Decimal> {
+        let volume = |levels: &[OrderBookLevel]| -> Decimal {
+            levels
+                .iter()
+                .take(depth_levels)
+                .map(|level| level.quantity)
+                .sum()
+        };
+        let bid_volume = volume(&self.bids);
+        let ask_volume = volume(&self.asks);
+        let total_volume = bid_volume + ask_volume;
+
+        if total_volume.is_zero() {
+            return None;
+        }
+        Some((bid_volume - ask_volume) / total_volume)
+    }
+
+    /**
+     * Estimates the fill of a market order by walking the opposite side of the book.
+     *
+     * Slippage is measured against the best price on the side being consumed
+     * and is positive when the average fill is worse than that price.
+     *
+     * # Arguments
+     * - `side`: Side of the market order; BUY consumes asks, SELL consumes bids.
+     * - `quantity`: Base asset quantity to fill.
+     *
+     * # Returns
+     * - `Option<(Decimal, Decimal)>`: Average fill price and slippage in basis points,
+     *   or None if the book is too thin to fill `quantity`.
+     */
+    pub fn market_impact(&self, side: OrderSide, quantity: Decimal) -> Option<(Decimal, Decimal)> {
+        let levels = match side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+            OrderSide::Unknown => return None,
+        };
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+        let best_price = levels.first()?.price;
+
+        let mut remaining = quantity;
+        let mut cost = Decimal::ZERO;
+        for level in levels {
+            let filled = remaining.min(level.quantity);
+            cost += filled * level.price;
+            remaining -= filled;
+            if remaining.is_zero() {
+                break;
+            }
+        }
+        if !remaining.is_zero() {
+            return None;
+        }
+
+        let average_price = cost / quantity;
+        let price_difference = match side {
+            OrderSide::Buy => average_price - best_price,
+            _ => best_price - average_price,
+        };
+        let slippage_bps = price_difference / best_price * Decimal::from(10_000);
+        Some((average_price, slippage_bps))
+    }
+
+    /**
+     * Computes a CRC32 checksum over the top levels of the book.
+     *
+     * Levels are serialized as `bid_price:bid_quantity:ask_price:ask_quantity`
+     * per depth, interleaving bids and asks and skipping a side once it runs
+     * out. Decimals are normalized, so replicas built from sources that format
+     * numbers differently (e.g. `"1.00"` vs `"1"`) produce the same checksum.
+     *
+     * # Arguments
+     * - `levels`: Number of levels per side to include.
+     *
+     * # Returns
+     * - `u32`: CRC32 of the serialized top levels.
+     */
+    pub fn checksum(&self, levels: usize) -> u32 {
+        let mut fields = Vec::with_capacity(levels * 4);
+        for depth in 0..levels {
+            for level in [self.bids.get(depth), self.asks.get(depth)]
+                .into_iter()
+                .flatten()
+            {
+                fields.push(level.price.normalize().to_string());
+                fields.push(level.quantity.normalize().to_string());
+            }
+        }
+
+        crc32fast::hash(fields.join(":").as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests the imbalance over the top levels of a synthetic book.
+     */
+    #[test]
+    fn test_imbalance_over_top_levels() {
+        // Arrange
+        let book: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":1,"bids":[["100","3"],["99","5"],["98","38"]],"asks":[["101","1"],["102","1"],["103","2"]]}"#,
+        )
+        .unwrap();
+
+        // Act
+        let top_two = book.imbalance(2);
+        let all_levels = book.imbalance(10);
+
+        // Assert
+        assert_eq!(top_two, Some(dec!(0.6)));
+        assert_eq!(all_levels, Some(dec!(0.84)));
+    }
+
+    /**
+     * Tests that a book without volume has no imbalance.
+     */
+    #[test]
+    fn test_imbalance_empty_book() {
+        // Arrange
+        let book: OrderBook =
+            serde_json::from_str(r#"{"lastUpdateId":1,"bids":[],"asks":[]}"#).unwrap();
+
+        // Act
+        let imbalance = book.imbalance(5);
+
+        // Assert
+        assert_eq!(imbalance, None);
+        assert_eq!(book.imbalance(0), None);
+    }
+
+    /**
+     * Tests that the checksum is stable for a fixed book and changes with its levels.
+     */
+    #[test]
+    fn test_checksum() {
+        // Arrange
+        let book: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":1,"bids":[["100.00","3"],["99","5"],["98","38"]],"asks":[["101","1.50"],["102","1"]]}"#,
+        )
+        .unwrap();
+        let reformatted: OrderBook = serde_json::from_str(
+            r#"{"lastUpdateId":2,"bids":[["100","3.000"],["99.0","5"],["98","38"]],"asks":[["101.0","1.5"],["102","1"]]}"#,
+        )
+        .unwrap();
+        let mut changed = book.clone();
+        changed.asks[1].quantity = dec!(2);
+        let mut changed_below_top = book.clone();
+        changed_below_top.bids[2].quantity = dec!(1);
+
+        // Act
+        let checksum = book.checksum(2);
+
+        // Assert
+        assert_eq!(checksum, crc32fast::hash(b"100:3:101:1.5:99:5:102:1"));
+        assert_eq!(checksum, reformatted.checksum(2));
+        assert_ne!(checksum, changed.checksum(2));
+        assert_eq!(checksum, changed_below_top.checksum(2));
+    }
+
+    /**
+     * Builds a synthetic book with known depth on both sides.
+     */
+    fn depth_book() -> OrderBook {
+        serde_json::from_str(
+            r#"{"lastUpdateId":1,"bids":[["100","1"],["99","2"],["98","3"]],"asks":[["101","1"],["102","2"],["104","3"]]}"#,
+        )
+        .unwrap()
+    }
+
+    /**
+     * Tests the average fill and slippage of buys and sells walking several levels.
+     */
+    #[test]
+    fn test_market_impact_walks_levels() {
+        // Arrange
+        let book = depth_book();
+
+        // Act
+        let top_of_book_buy = book.market_impact(OrderSide::Buy, dec!(0.5));
+        let buy = book.market_impact(OrderSide::Buy, dec!(2));
+        let sell = book.market_impact(OrderSide::Sell, dec!(4));
+
+        // Assert
+        assert_eq!(top_of_book_buy, Some((dec!(101), dec!(0))));
+        let (buy_price, buy_slippage) = buy.unwrap();
+        assert_eq!(buy_price, dec!(101.5));
+        assert_eq!(buy_slippage.round_dp(4), dec!(49.5050));
+        let (sell_price, sell_slippage) = sell.unwrap();
+        assert_eq!(sell_price, dec!(99));
+        assert_eq!(sell_slippage, dec!(100));
+    }
+
+    /**
+     * Tests that a quantity exceeding the available depth has no estimate.
+     */
+    #[test]
+    fn test_market_impact_thin_book() {
+        // Arrange
+        let book = depth_book();
+        let empty: OrderBook =
+            serde_json::from_str(r#"{"lastUpdateId":1,"bids":[],"asks":[]}"#).unwrap();
+
+        // Act & Assert
+        assert_eq!(book.market_impact(OrderSide::Buy, dec!(6.01)), None);
+        assert!(book.market_impact(OrderSide::Sell, dec!(6)).is_some());
+        assert_eq!(empty.market_impact(OrderSide::Sell, dec!(1)), None);
+        assert_eq!(book.market_impact(OrderSide::Buy, dec!(0)), None);
+    }
 }
@@ -0,0 +1,22 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/**
+ * Commission rates applied to a single test order.
+ *
+ * Returned by the order and SOR test endpoints when commission rates are
+ * requested. Unlike account commission rates, only the side-independent
+ * maker and taker rates are reported.
+ *
+ * # Fields
+ * - `maker`: Maker commission rate.
+ * - `taker`: Taker commission rate.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrderCommissionRates {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub maker: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub taker: Decimal,
+}
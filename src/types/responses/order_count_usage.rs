@@ -0,0 +1,78 @@
+use crate::types::responses::{Order, SymbolInfo};
+
+/**
+ * Open order usage on a symbol relative to its order count filters.
+ *
+ * # Fields
+ * - `symbol`: Trading symbol the usage applies to.
+ * - `open_orders`: Number of unfilled orders open on the symbol.
+ * - `open_algo_orders`: Number of those orders that are algo orders (stop loss / take profit).
+ * - `max_num_orders`: Limit from the MAX_NUM_ORDERS filter, if the symbol has one.
+ * - `max_num_algo_orders`: Limit from the MAX_NUM_ALGO_ORDERS filter, if the symbol has one.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderCountUsage {
+    pub symbol: String,
+    pub open_orders: u32,
+    pub open_algo_orders: u32,
+    pub max_num_orders: Option<u32>,
+    pub max_num_algo_orders: Option<u32>,
+}
+
+impl OrderCountUsage {
+    /**
+     * Counts open orders against the order count filters of a symbol.
+     *
+     * # Arguments
+     * - `symbol_info`: Symbol information providing the filters.
+     * - `open_orders`: Open orders on the symbol.
+     *
+     * # Returns
+     * - `OrderCountUsage`: Counts and limits for the symbol.
+     */
+    pub fn new(symbol_info: &SymbolInfo, open_orders: &[Order]) -> Self {
+        let open_algo_orders = open_orders.iter().filter(|order| order.is_algo()).count();
+
+        Self {
+            symbol: symbol_info.symbol.clone(),
+            open_orders: open_orders.len() as u32,
+            open_algo_orders: open_algo_orders as u32,
+            max_num_orders: symbol_info
+                .max_num_orders_filter()
+                .map(|filter| filter.max_num_orders),
+            max_num_algo_orders: symbol_info
+                .max_num_algo_orders_filter()
+                .map(|filter| filter.max_num_algo_orders),
+        }
+    }
+
+    /**
+     * Gets how many more orders can be opened before MAX_NUM_ORDERS is reached.
+     *
+     * # Returns
+     * - `Option<u32>`: Remaining capacity, or None if the symbol has no such filter.
+     */
+    pub fn remaining_orders(&self) -> Option<u32> {
+        self.max_num_orders
+            .map(|max| max.saturating_sub(self.open_orders))
+    }
+
+    /**
+     * Gets how many more algo orders can be opened before MAX_NUM_ALGO_ORDERS is reached.
+     *
+     * Algo orders also count towards MAX_NUM_ORDERS, so the smaller of both
+     * remaining capacities applies.
+     *
+     * # Returns
+     * - `Option<u32>`: Remaining capacity, or None if the symbol has neither filter.
+     */
+    pub fn remaining_algo_orders(&self) -> Option<u32> {
+        let remaining_algo = self
+            .max_num_algo_orders
+            .map(|max| max.saturating_sub(self.open_algo_orders));
+        match (remaining_algo, self.remaining_orders()) {
+            (Some(algo), Some(total)) => Some(algo.min(total)),
+            (algo, total) => algo.or(total),
+        }
+    }
+}
@@ -34,3 +34,67 @@ pub struct OrderList {
     #[serde(default)]
     pub order_reports: Vec<Order>,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::enums::{OrderSide, OrderStatus, OrderType};
+
+    /**
+     * Tests that a FULL OCO response carries a typed report for both orders.
+     */
+    #[test]
+    fn test_full_oco_response_order_reports() {
+        // Arrange
+        let json = r#"{
+            "orderListId": 1, "contingencyType": "OCO", "listStatusType": "EXEC_STARTED",
+            "listOrderStatus": "EXECUTING", "listClientOrderId": "lH1YDkuQKWiXVXHPSKYEIp",
+            "transactionTime": 1710485608839, "symbol": "LTCBTC",
+            "orders": [
+                {"symbol": "LTCBTC", "orderId": 10, "clientOrderId": "44nZvqpemY7sVYgPYbvPih"},
+                {"symbol": "LTCBTC", "orderId": 11, "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK"}
+            ],
+            "orderReports": [
+                {
+                    "symbol": "LTCBTC", "orderId": 10, "orderListId": 1,
+                    "clientOrderId": "44nZvqpemY7sVYgPYbvPih", "transactTime": 1710485608839,
+                    "price": "1.00000000", "origQty": "5.00000000", "executedQty": "0.00000000",
+                    "origQuoteOrderQty": "0.000000", "cummulativeQuoteQty": "0.00000000",
+                    "status": "NEW", "timeInForce": "GTC", "type": "STOP_LOSS_LIMIT", "side": "SELL",
+                    "stopPrice": "1.00000000", "workingTime": -1, "selfTradePreventionMode": "NONE",
+                    "fills": []
+                },
+                {
+                    "symbol": "LTCBTC", "orderId": 11, "orderListId": 1,
+                    "clientOrderId": "NuMp0nVYnciDiFmVqfpBqK", "transactTime": 1710485608839,
+                    "price": "3.00000000", "origQty": "5.00000000", "executedQty": "0.00000000",
+                    "origQuoteOrderQty": "0.000000", "cummulativeQuoteQty": "0.00000000",
+                    "status": "NEW", "timeInForce": "GTC", "type": "LIMIT_MAKER", "side": "SELL",
+                    "workingTime": 1710485608839, "selfTradePreventionMode": "NONE",
+                    "fills": []
+                }
+            ]
+        }"#;
+
+        // Act
+        let order_list: OrderList = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(order_list.contingency_type, ContingencyType::OCO);
+        assert_eq!(order_list.order_reports.len(), 2);
+        let stop_loss = &order_list.order_reports[0];
+        assert_eq!(stop_loss.status, Some(OrderStatus::New));
+        assert_eq!(stop_loss.order_type, Some(OrderType::StopLossLimit));
+        assert_eq!(stop_loss.price, Some(dec!(1)));
+        assert_eq!(stop_loss.stop_price, Some(dec!(1)));
+        assert_eq!(stop_loss.original_quantity, Some(dec!(5)));
+        let limit_maker = &order_list.order_reports[1];
+        assert_eq!(limit_maker.status, Some(OrderStatus::New));
+        assert_eq!(limit_maker.side, Some(OrderSide::Sell));
+        assert_eq!(limit_maker.price, Some(dec!(3)));
+        assert_eq!(limit_maker.original_quantity, Some(dec!(5)));
+        assert_eq!(limit_maker.fills.as_deref(), Some(&[][..]));
+    }
+}
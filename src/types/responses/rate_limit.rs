@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::enums::{RateLimitInterval, RateLimitType};
@@ -23,3 +25,24 @@ pub struct RateLimit {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<u64>,
 }
+
+impl RateLimit {
+    /**
+     * Gets the length of the window this limit applies to.
+     *
+     * # Returns
+     * - `Option<Duration>`: Window length, or None if the interval is unknown.
+     */
+    pub fn window(&self) -> Option<Duration> {
+        let unit_secs = match self.interval {
+            RateLimitInterval::Second => 1,
+            RateLimitInterval::Minute => 60,
+            RateLimitInterval::Hour => 3_600,
+            RateLimitInterval::Day => 86_400,
+            RateLimitInterval::Unknown => return None,
+        };
+        Some(Duration::from_secs(
+            unit_secs * u64::from(self.interval_num),
+        ))
+    }
+}
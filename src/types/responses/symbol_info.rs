@@ -1,7 +1,13 @@
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-use crate::enums::{OrderType, Permission, SelfTradePreventionMode, SymbolStatus};
-use crate::filters::{LotSizeFilter, MinNotionalFilter, PriceFilter, SymbolFilter};
+use crate::Result;
+use crate::enums::{OrderSide, OrderType, Permission, SelfTradePreventionMode, SymbolStatus};
+use crate::errors::InvalidParameter;
+use crate::filters::{
+    LotSizeFilter, MaxNumAlgoOrdersFilter, MaxNumOrdersFilter, MinNotionalFilter,
+    PercentPriceBySideFilter, PercentPriceFilter, PriceFilter, SymbolFilter, is_step_compliant,
+};
 
 /**
  * Symbol information from exchange.
@@ -66,6 +72,53 @@ pub struct SymbolInfo {
 }
 
 impl SymbolInfo {
+    /**
+     * Gets the base and quote asset names of the symbol.
+     *
+     * # Returns
+     * - `(&str, &str)`: Base asset and quote asset (e.g., `("BTC", "USDT")`).
+     */
+    pub fn base_quote(&self) -> (&str, &str) {
+        (&self.base_asset, &self.quote_asset)
+    }
+
+    /**
+     * Checks whether the symbol can be traded with a permission.
+     *
+     * Binance reports permissions either in `permissions` or, when permission
+     * sets are requested, in `permission_sets`. Both are checked.
+     *
+     * # Arguments
+     * - `permission`: Permission to look for.
+     *
+     * # Returns
+     * - `bool`: True if any permission list contains the permission.
+     */
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+            || self
+                .permission_sets
+                .iter()
+                .flatten()
+                .any(|set| set.contains(&permission))
+    }
+
+    /**
+     * Checks whether orders on this symbol may use a self-trade prevention mode.
+     *
+     * Lets callers validate an STP choice before sending an order that the
+     * exchange would reject.
+     *
+     * # Arguments
+     * - `mode`: Self-trade prevention mode to check.
+     *
+     * # Returns
+     * - `bool`: True if the mode is listed in `allowed_self_trade_prevention_modes`.
+     */
+    pub fn allows_stp_mode(&self, mode: SelfTradePreventionMode) -> bool {
+        self.allowed_self_trade_prevention_modes.contains(&mode)
+    }
+
     /**
      * Gets the PRICE_FILTER for this symbol, if it exists.
      *
@@ -104,4 +157,257 @@ impl SymbolInfo {
             _ => None,
         })
     }
+
+    /**
+     * Gets the PERCENT_PRICE filter for this symbol, if it exists.
+     *
+     * # Returns
+     * - `Option<&PercentPriceFilter>`: The percent price filter if present.
+     */
+    pub fn percent_price_filter(&self) -> Option<&PercentPriceFilter> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::PercentPrice(ppf) => Some(ppf),
+            _ => None,
+        })
+    }
+
+    /**
+     * Gets the PERCENT_PRICE_BY_SIDE filter for this symbol, if it exists.
+     *
+     * # Returns
+     * - `Option<&PercentPriceBySideFilter>`: The percent price by side filter if present.
+     */
+    pub fn percent_price_by_side_filter(&self) -> Option<&PercentPriceBySideFilter> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::PercentPriceBySide(ppbsf) => Some(ppbsf),
+            _ => None,
+        })
+    }
+
+    /**
+     * Gets the MAX_NUM_ORDERS filter for this symbol, if it exists.
+     *
+     * # Returns
+     * - `Option<&MaxNumOrdersFilter>`: The max num orders filter if present.
+     */
+    pub fn max_num_orders_filter(&self) -> Option<&MaxNumOrdersFilter> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MaxNumOrders(mnof) => Some(mnof),
+            _ => None,
+        })
+    }
+
+    /**
+     * Gets the MAX_NUM_ALGO_ORDERS filter for this symbol, if it exists.
+     *
+     * # Returns
+     * - `Option<&MaxNumAlgoOrdersFilter>`: The max num algo orders filter if present.
+     */
+    pub fn max_num_algo_orders_filter(&self) -> Option<&MaxNumAlgoOrdersFilter> {
+        self.filters.iter().find_map(|f| match f {
+            SymbolFilter::MaxNumAlgoOrders(mnaof) => Some(mnaof),
+            _ => None,
+        })
+    }
+
+    /**
+     * Checks whether a price lies on the PRICE_FILTER tick grid.
+     *
+     * A price is compliant when `(price - minPrice) % tickSize == 0`; symbols
+     * without a price filter or with a zero tick size accept any price.
+     *
+     * # Arguments
+     * - `price`: Price to check.
+     *
+     * # Returns
+     * - `bool`: True if the exchange would accept the price's precision unchanged.
+     */
+    pub fn is_price_tick_compliant(&self, price: Decimal) -> bool {
+        self.price_filter()
+            .is_none_or(|filter| is_step_compliant(price, &filter.min_price, &filter.tick_size))
+    }
+
+    /**
+     * Checks whether a quantity lies on the LOT_SIZE step grid.
+     *
+     * A quantity is compliant when `(quantity - minQty) % stepSize == 0`;
+     * symbols without a lot size filter or with a zero step size accept any quantity.
+     *
+     * # Arguments
+     * - `quantity`: Quantity to check.
+     *
+     * # Returns
+     * - `bool`: True if the exchange would accept the quantity's precision unchanged.
+     */
+    pub fn is_quantity_step_compliant(&self, quantity: Decimal) -> bool {
+        self.lot_size_filter()
+            .is_none_or(|filter| is_step_compliant(quantity, &filter.min_qty, &filter.step_size))
+    }
+
+    /**
+     * Validates an order price against the symbol's percent price filters.
+     *
+     * Applies PERCENT_PRICE and, using the bounds for the order side,
+     * PERCENT_PRICE_BY_SIDE, so that orders the exchange would reject with a
+     * filter failure are caught locally.
+     *
+     * # Arguments
+     * - `side`: Side of the order.
+     * - `price`: Limit price of the order.
+     * - `weighted_average_price`: Current average price, e.g. from `GET /api/v3/avgPrice`.
+     *
+     * # Returns
+     * - `()`: Empty result if the price satisfies every percent price filter.
+     */
+    pub fn validate_order(
+        &self,
+        side: OrderSide,
+        price: Decimal,
+        weighted_average_price: Decimal,
+    ) -> Result<()> {
+        if let Some(filter) = self.percent_price_filter() {
+            let (min, max) = filter.price_bounds(weighted_average_price)?;
+            if price < min || price > max {
+                return Err(InvalidParameter::range("price", min, max).into());
+            }
+        }
+
+        if let Some(filter) = self.percent_price_by_side_filter() {
+            let (min, max) = filter.price_bounds(side, weighted_average_price)?;
+            if price < min || price > max {
+                return Err(InvalidParameter::range("price", min, max).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+    use crate::types::responses::test_fixtures;
+
+    /**
+     * Loads the BNBBTC fixture with a PERCENT_PRICE_BY_SIDE filter attached.
+     */
+    fn symbol_info_with_percent_price_by_side() -> SymbolInfo {
+        test_fixtures::symbol_info(serde_json::json!([{
+            "filterType": "PERCENT_PRICE_BY_SIDE",
+            "bidMultiplierUp": "1.2",
+            "bidMultiplierDown": "0.2",
+            "askMultiplierUp": "5",
+            "askMultiplierDown": "0.8",
+            "avgPriceMins": 1
+        }]))
+    }
+
+    /**
+     * Tests that the STP fields deserialize typed and drive the allowed-mode check.
+     */
+    #[test]
+    fn test_stp_modes() {
+        // Act
+        let symbol_info = test_fixtures::symbol_info(serde_json::json!([]));
+
+        // Assert
+        assert_eq!(
+            symbol_info.default_self_trade_prevention_mode,
+            SelfTradePreventionMode::NoProtection
+        );
+        assert_eq!(symbol_info.allowed_self_trade_prevention_modes.len(), 4);
+        assert!(symbol_info.allows_stp_mode(SelfTradePreventionMode::NoProtection));
+        assert!(symbol_info.allows_stp_mode(SelfTradePreventionMode::ExpireBoth));
+        assert!(!symbol_info.allows_stp_mode(SelfTradePreventionMode::Decrement));
+    }
+
+    /**
+     * Tests that a buy above the bid multiplier bound is rejected.
+     */
+    #[test]
+    fn test_validate_order_rejects_buy_above_bid_bound() {
+        // Arrange
+        let symbol_info = symbol_info_with_percent_price_by_side();
+
+        // Act
+        let result = symbol_info.validate_order(OrderSide::Buy, dec!(130), dec!(100));
+
+        // Assert
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("price"), "{error}");
+    }
+
+    /**
+     * Tests that a sell within the ask multiplier bounds is accepted even above the bid bound.
+     */
+    #[test]
+    fn test_validate_order_accepts_sell_within_ask_bounds() {
+        // Arrange
+        let symbol_info = symbol_info_with_percent_price_by_side();
+
+        // Act
+        let result = symbol_info.validate_order(OrderSide::Sell, dec!(130), dec!(100));
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    /**
+     * Builds a synthetic symbol with the given PRICE_FILTER and LOT_SIZE filters.
+     */
+    fn symbol_info_with_steps(tick_size: &str, step_size: &str) -> SymbolInfo {
+        let mut symbol_info = symbol_info_with_percent_price_by_side();
+        symbol_info.filters = vec![
+            serde_json::from_value(serde_json::json!({
+                "filterType": "PRICE_FILTER",
+                "minPrice": "0.01",
+                "maxPrice": "1000.00",
+                "tickSize": tick_size
+            }))
+            .unwrap(),
+            serde_json::from_value(serde_json::json!({
+                "filterType": "LOT_SIZE",
+                "minQty": "0.001",
+                "maxQty": "100.000",
+                "stepSize": step_size
+            }))
+            .unwrap(),
+        ];
+        symbol_info
+    }
+
+    /**
+     * Tests that prices on and off the tick grid are classified correctly.
+     */
+    #[test]
+    fn test_is_price_tick_compliant() {
+        // Arrange
+        let symbol_info = symbol_info_with_steps("0.05", "0.001");
+        let disabled = symbol_info_with_steps("0", "0");
+
+        // Act & Assert
+        assert!(symbol_info.is_price_tick_compliant(dec!(10.06)));
+        assert!(symbol_info.is_price_tick_compliant(dec!(10.01)));
+        assert!(!symbol_info.is_price_tick_compliant(dec!(10.05)));
+        assert!(!symbol_info.is_price_tick_compliant(dec!(10.061)));
+        assert!(disabled.is_price_tick_compliant(dec!(10.061)));
+    }
+
+    /**
+     * Tests that quantities on and off the step grid are classified correctly.
+     */
+    #[test]
+    fn test_is_quantity_step_compliant() {
+        // Arrange
+        let symbol_info = symbol_info_with_steps("0.01", "0.001");
+        let disabled = symbol_info_with_steps("0", "0");
+
+        // Act & Assert
+        assert!(symbol_info.is_quantity_step_compliant(dec!(1.234)));
+        assert!(symbol_info.is_quantity_step_compliant(dec!(0.001)));
+        assert!(!symbol_info.is_quantity_step_compliant(dec!(1.2345)));
+        assert!(disabled.is_quantity_step_compliant(dec!(1.2345)));
+    }
 }
@@ -0,0 +1,43 @@
+use serde_json::Value;
+
+use super::{ExchangeInfo, SymbolInfo};
+
+/**
+ * Exchange info response listing BNBBTC as trading, without filters.
+ */
+pub(crate) const BNBBTC_TRADING: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/fixtures/exchange_info/bnbbtc_trading.json"
+));
+
+/**
+ * Loads the BNBBTC exchange info fixture with the given filters.
+ *
+ * # Arguments
+ * - `filters`: Symbol filters for BNBBTC, as a JSON array of wire objects.
+ * - `exchange_filters`: Exchange-wide filters, as a JSON array of wire objects.
+ *
+ * # Returns
+ * - `ExchangeInfo`: Exchange info with a single BNBBTC symbol.
+ */
+pub(crate) fn exchange_info(filters: Value, exchange_filters: Value) -> ExchangeInfo {
+    let mut json: Value = serde_json::from_str(BNBBTC_TRADING).unwrap();
+    json["symbols"][0]["filters"] = filters;
+    json["exchangeFilters"] = exchange_filters;
+    serde_json::from_value(json).unwrap()
+}
+
+/**
+ * Loads the BNBBTC symbol from the exchange info fixture with the given filters.
+ *
+ * # Arguments
+ * - `filters`: Symbol filters, as a JSON array of wire objects.
+ *
+ * # Returns
+ * - `SymbolInfo`: BNBBTC symbol information.
+ */
+pub(crate) fn symbol_info(filters: Value) -> SymbolInfo {
+    exchange_info(filters, Value::Array(Vec::new()))
+        .symbols
+        .remove(0)
+}
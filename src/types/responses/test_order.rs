@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::responses::{CommissionRates, DiscountInfo};
+use crate::types::responses::{DiscountInfo, OrderCommissionRates};
 
 /**
  * Response from test order endpoints.
  *
  * Test order endpoints validate order parameters but don't place actual orders.
  * By default returns empty response, but can include commission rates if requested.
+ * Shared by the regular and SOR test order endpoints.
  *
  * # Fields
  * - `standard_commission_for_order`: Standard commission rates (if computeCommissionRates=true).
@@ -18,9 +19,63 @@ use crate::types::responses::{CommissionRates, DiscountInfo};
 #[serde(deny_unknown_fields)]
 pub struct TestOrder {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub standard_commission_for_order: Option<CommissionRates>,
+    pub standard_commission_for_order: Option<OrderCommissionRates>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tax_commission_for_order: Option<CommissionRates>,
+    pub tax_commission_for_order: Option<OrderCommissionRates>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub discount: Option<DiscountInfo>,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    /**
+     * Tests deserializing a SOR test order response with commission rates.
+     */
+    #[test]
+    fn test_sor_test_order_with_commission_rates() {
+        // Arrange
+        let json = r#"{
+            "standardCommissionForOrder": {"maker": "0.00000112", "taker": "0.00000114"},
+            "taxCommissionForOrder": {"maker": "0.00000112", "taker": "0.00000114"},
+            "discount": {
+                "enabledForAccount": true,
+                "enabledForSymbol": true,
+                "discountAsset": "BNB",
+                "discount": "0.25000000"
+            }
+        }"#;
+
+        // Act
+        let test_order: TestOrder = serde_json::from_str(json).unwrap();
+
+        // Assert
+        let standard = test_order.standard_commission_for_order.unwrap();
+        assert_eq!(standard.maker, dec!(0.00000112));
+        assert_eq!(standard.taker, dec!(0.00000114));
+        assert_eq!(
+            test_order.tax_commission_for_order.unwrap().taker,
+            dec!(0.00000114)
+        );
+        let discount = test_order.discount.unwrap();
+        assert_eq!(discount.discount_asset.as_deref(), Some("BNB"));
+        assert_eq!(discount.discount, dec!(0.25));
+    }
+
+    /**
+     * Tests deserializing the empty response returned without commission rates.
+     */
+    #[test]
+    fn test_empty_test_order() {
+        // Act
+        let test_order: TestOrder = serde_json::from_str("{}").unwrap();
+
+        // Assert
+        assert!(test_order.standard_commission_for_order.is_none());
+        assert!(test_order.tax_commission_for_order.is_none());
+        assert!(test_order.discount.is_none());
+    }
+}
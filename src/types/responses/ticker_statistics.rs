@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::TickerType;
+
 /**
  * Ticker statistics response variants from Binance API.
  *
@@ -21,6 +23,34 @@ pub enum TickerStatistics {
     Mini(TickerStatisticsMini),
 }
 
+impl TickerStatistics {
+    /**
+     * Gets the response shape these statistics were returned in.
+     *
+     * # Returns
+     * - `TickerType`: FULL or MINI.
+     */
+    pub fn ticker_type(&self) -> TickerType {
+        match self {
+            TickerStatistics::Full(_) => TickerType::Full,
+            TickerStatistics::Mini(_) => TickerType::Mini,
+        }
+    }
+
+    /**
+     * Gets the trading symbol the statistics belong to.
+     *
+     * # Returns
+     * - `&str`: Trading symbol.
+     */
+    pub fn symbol(&self) -> &str {
+        match self {
+            TickerStatistics::Full(full) => &full.symbol,
+            TickerStatistics::Mini(mini) => &mini.symbol,
+        }
+    }
+}
+
 /**
  * Full ticker statistics from Binance API.
  *
@@ -137,6 +167,18 @@ pub struct TickerStatisticsFull {
     pub count: u64,
 }
 
+impl TickerStatisticsFull {
+    /**
+     * Gets the relative price change as a fraction rather than a percentage.
+     *
+     * # Returns
+     * - `Decimal`: Price change ratio (e.g., `-0.0125` for `-1.25` percent).
+     */
+    pub fn price_change_ratio(&self) -> Decimal {
+        self.price_change_percent / Decimal::ONE_HUNDRED
+    }
+}
+
 /**
  * Mini ticker statistics from Binance API.
  *
@@ -180,3 +222,66 @@ pub struct TickerStatisticsMini {
     pub last_id: i64,
     pub count: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::dec;
+
+    use super::*;
+
+    const FULL: &str = r#"{"symbol":"BNBBTC","priceChange":"-94.99999800","priceChangePercent":"-95.960","weightedAvgPrice":"0.29628482","prevClosePrice":"0.10002000","lastPrice":"4.00000200","lastQty":"200.00000000","bidPrice":"4.00000000","bidQty":"100.00000000","askPrice":"4.00000200","askQty":"100.00000000","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+    const MINI: &str = r#"{"symbol":"BNBBTC","openPrice":"99.00000000","highPrice":"100.00000000","lowPrice":"0.10000000","lastPrice":"4.00000200","volume":"8913.30000000","quoteVolume":"15.30000000","openTime":1499783499040,"closeTime":1499869899040,"firstId":28385,"lastId":28460,"count":76}"#;
+
+    /**
+     * Tests that single-symbol FULL and MINI payloads resolve to the matching variant.
+     */
+    #[test]
+    fn test_single_symbol_shapes() {
+        // Act
+        let full: TickerStatistics = serde_json::from_str(FULL).unwrap();
+        let mini: TickerStatistics = serde_json::from_str(MINI).unwrap();
+
+        // Assert
+        assert_eq!(full.ticker_type(), TickerType::Full);
+        assert_eq!(mini.ticker_type(), TickerType::Mini);
+        assert_eq!(full.symbol(), "BNBBTC");
+        let TickerStatistics::Full(full) = full else {
+            panic!("Expected FULL statistics");
+        };
+        assert_eq!(full.bid_price, Some(dec!(4)));
+        let TickerStatistics::Mini(mini) = mini else {
+            panic!("Expected MINI statistics");
+        };
+        assert_eq!(mini.last_price, dec!(4.000002));
+    }
+
+    /**
+     * Tests that multi-symbol FULL and MINI arrays resolve every element to the matching variant.
+     */
+    #[test]
+    fn test_multi_symbol_shapes() {
+        // Arrange
+        let full_json = format!("[{},{}]", FULL, FULL.replace("BNBBTC", "BTCUSDT"));
+        let mini_json = format!("[{},{}]", MINI, MINI.replace("BNBBTC", "BTCUSDT"));
+
+        // Act
+        let full: Vec<TickerStatistics> = serde_json::from_str(&full_json).unwrap();
+        let mini: Vec<TickerStatistics> = serde_json::from_str(&mini_json).unwrap();
+
+        // Assert
+        assert!(
+            full.iter()
+                .all(|stats| stats.ticker_type() == TickerType::Full)
+        );
+        assert!(
+            mini.iter()
+                .all(|stats| stats.ticker_type() == TickerType::Mini)
+        );
+        assert_eq!(
+            mini.iter()
+                .map(TickerStatistics::symbol)
+                .collect::<Vec<_>>(),
+            ["BNBBTC", "BTCUSDT"]
+        );
+    }
+}
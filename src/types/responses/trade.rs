@@ -1,6 +1,8 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::enums::OrderSide;
+
 /**
  * Individual trade from Binance API and WebSocket Streams.
  *
@@ -42,3 +44,62 @@ pub struct Trade {
     #[serde(alias = "M")] // WebSocket Stream alias
     pub is_best_match: bool,
 }
+
+impl Trade {
+    /**
+     * Gets the side of the taker order that caused the trade.
+     *
+     * # Returns
+     * - `OrderSide`: `Sell` when the buyer was the maker, `Buy` otherwise.
+     */
+    pub fn aggressor_side(&self) -> OrderSide {
+        if self.is_buyer_maker {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::events::TradeStreamEvent;
+
+    /**
+     * Tests that a trade where the buyer was the maker was taken by a seller.
+     */
+    #[test]
+    fn test_aggressor_side_buyer_maker() {
+        // Arrange
+        let event: TradeStreamEvent = serde_json::from_str(
+            r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.00100000","q":"100.50000000","T":1672515782136,"m":true,"M":true}"#,
+        )
+        .unwrap();
+
+        // Act
+        let side = event.trade.aggressor_side();
+
+        // Assert
+        assert!(event.trade.is_buyer_maker);
+        assert_eq!(side, OrderSide::Sell);
+    }
+
+    /**
+     * Tests that a trade where the seller was the maker was taken by a buyer.
+     */
+    #[test]
+    fn test_aggressor_side_seller_maker() {
+        // Arrange
+        let trade: Trade = serde_json::from_str(
+            r#"{"id":28457,"price":"4.00000100","qty":"12.00000000","quoteQty":"48.000012","time":1499865549590,"isBuyerMaker":false,"isBestMatch":true}"#,
+        )
+        .unwrap();
+
+        // Act
+        let side = trade.aggressor_side();
+
+        // Assert
+        assert_eq!(side, OrderSide::Buy);
+    }
+}